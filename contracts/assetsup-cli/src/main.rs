@@ -0,0 +1,459 @@
+//! Offline admin tool for the AssetUp contract.
+//!
+//! Transaction subcommands simulate their call against a ledger snapshot
+//! holding the deployed contract and write an unsigned envelope as base64
+//! XDR, complete with footprint, resources, resource fee and authorization
+//! entries. `--snapshot-out` saves the ledger after the call, so a fresh
+//! deployment can be set up one command after another:
+//!
+//! ```text
+//! assetsup init ... --snapshot deployed.json --snapshot-out 1.json
+//! assetsup create-company ... --snapshot 1.json --snapshot-out 2.json
+//! assetsup create-category ... --snapshot 2.json --snapshot-out 3.json
+//! assetsup create-branch ... --snapshot 3.json --snapshot-out 4.json
+//! assetsup register-asset ... --snapshot 4.json
+//! ```
+//!
+//! `get` and `export` read records back out of a ledger snapshot.
+
+use std::{error::Error, fs, path::PathBuf, process::ExitCode};
+
+use assetsup_client::{
+    AssetRecord, AssetUpClient, DEFAULT_FEE, DEFAULT_INSTRUCTION_LEEWAY, Invocation, Snapshot,
+    assetsup::{asset::MAX_BATCH_SIZE, category::DepreciationSettings, types::DepreciationMethod},
+    csv,
+    records::{parse_address, parse_id},
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use soroban_sdk::Vec;
+
+#[derive(Parser)]
+#[command(
+    name = "assetsup",
+    about = "Prepare AssetUp contract transactions offline",
+    after_help = "Envelopes are simulated against --snapshot and carry their \
+                  footprint, resources and authorization entries, ready to \
+                  sign. Authorizations by accounts other than --source must \
+                  also be signed. Native simulation does not meter the Wasm \
+                  VM; --instruction-leeway covers it."
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Set the platform admin of a freshly deployed contract
+    Init {
+        #[command(flatten)]
+        tx: TxArgs,
+        /// Platform admin (G... strkey)
+        #[arg(long)]
+        admin: String,
+    },
+    /// Create a company, signed by the platform admin
+    CreateCompany {
+        #[command(flatten)]
+        tx: TxArgs,
+        /// New company id (64 hex characters)
+        #[arg(long)]
+        id: String,
+        #[arg(long)]
+        name: String,
+        /// Company admin (G... strkey)
+        #[arg(long)]
+        admin: String,
+    },
+    /// Create an asset category in a company
+    CreateCategory {
+        #[command(flatten)]
+        tx: TxArgs,
+        /// Company id (64 hex characters)
+        #[arg(long)]
+        company: String,
+        /// New category id (not 0)
+        #[arg(long)]
+        id: u64,
+        #[arg(long)]
+        name: String,
+        /// Parent category id; 0 for a top-level category
+        #[arg(long, default_value_t = 0)]
+        parent: u64,
+        #[arg(long, value_enum, default_value_t = Depreciation::None)]
+        depreciation: Depreciation,
+        #[arg(long, default_value_t = 0)]
+        useful_life_months: u32,
+        /// Salvage value in basis points of the purchase cost
+        #[arg(long, default_value_t = 0)]
+        salvage_bps: u32,
+    },
+    /// Create a branch under a company
+    CreateBranch {
+        #[command(flatten)]
+        tx: TxArgs,
+        /// Company id (64 hex characters)
+        #[arg(long)]
+        company: String,
+        /// New branch id (64 hex characters)
+        #[arg(long)]
+        id: String,
+        #[arg(long)]
+        name: String,
+        #[arg(long)]
+        location: String,
+        /// Branch admin (G... strkey)
+        #[arg(long)]
+        admin: String,
+    },
+    /// Register assets from a JSON file holding one asset record or an
+    /// array of at most `MAX_BATCH_SIZE` of them
+    RegisterAsset {
+        #[command(flatten)]
+        tx: TxArgs,
+        file: PathBuf,
+        /// Link the registered assets to this branch (registers as a batch)
+        #[arg(long)]
+        branch: Option<String>,
+    },
+    /// Register the assets of a CSV sheet in batches of `MAX_BATCH_SIZE`,
+    /// writing one envelope per line with consecutive sequence numbers.
+    /// Rows without an `id` get one derived from their `tag` column
+    ImportCsv {
+        #[command(flatten)]
+        tx: TxArgs,
+        file: PathBuf,
+        /// Company the assets belong to (64 hex characters)
+        #[arg(long)]
+        company: String,
+        /// Link the registered assets to this branch
+        #[arg(long)]
+        branch: Option<String>,
+    },
+    /// Record the Stellar token that represents an asset
+    Tokenize {
+        #[command(flatten)]
+        tx: TxArgs,
+        /// Asset id (64 hex characters)
+        #[arg(long)]
+        asset: String,
+        /// Token id (64 hex characters)
+        #[arg(long)]
+        token: String,
+    },
+    /// Print an asset or branch stored in a ledger snapshot as JSON
+    Get {
+        /// Ledger snapshot or soroban-sdk test snapshot
+        snapshot: PathBuf,
+        #[command(flatten)]
+        record: RecordArgs,
+    },
+    /// Export every asset or branch stored in a ledger snapshot
+    Export {
+        /// Ledger snapshot or soroban-sdk test snapshot
+        snapshot: PathBuf,
+        /// Which records to export
+        #[arg(long, value_enum)]
+        records: Records,
+        #[arg(long, value_enum, default_value_t = Format::Csv)]
+        format: Format,
+        /// Write the export to this file instead of stdout
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Records {
+    Assets,
+    Branches,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Csv,
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Depreciation {
+    None,
+    StraightLine,
+    DecliningBalance,
+}
+
+impl From<Depreciation> for DepreciationMethod {
+    fn from(d: Depreciation) -> Self {
+        match d {
+            Depreciation::None => DepreciationMethod::None,
+            Depreciation::StraightLine => DepreciationMethod::StraightLine,
+            Depreciation::DecliningBalance => DepreciationMethod::DecliningBalance,
+        }
+    }
+}
+
+#[derive(Args)]
+struct TxArgs {
+    /// Contract id (C... strkey)
+    #[arg(long)]
+    contract: String,
+    /// Transaction source account (G... strkey)
+    #[arg(long)]
+    source: String,
+    /// Sequence number to use: the source account's current sequence plus one
+    #[arg(long)]
+    sequence: i64,
+    /// Inclusion fee in stroops; the simulated resource fee is added to it
+    #[arg(long, default_value_t = DEFAULT_FEE)]
+    fee: u32,
+    /// Ledger snapshot holding the deployed contract, to simulate against
+    #[arg(long)]
+    snapshot: PathBuf,
+    /// Write the ledger after the call to this file, to simulate the next
+    /// command against
+    #[arg(long)]
+    snapshot_out: Option<PathBuf>,
+    /// Instructions added to the simulated count for running the Wasm
+    #[arg(long, default_value_t = DEFAULT_INSTRUCTION_LEEWAY)]
+    instruction_leeway: u32,
+    /// Write the envelope to this file instead of stdout
+    #[arg(long)]
+    out: Option<PathBuf>,
+}
+
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+struct RecordArgs {
+    /// Asset id (64 hex characters)
+    #[arg(long)]
+    asset: Option<String>,
+    /// Branch id (64 hex characters)
+    #[arg(long)]
+    branch: Option<String>,
+}
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// Output of a command and where it should go.
+struct Output {
+    text: String,
+    out: Option<PathBuf>,
+}
+
+/// Simulate `invocation` against `snapshot` and build its envelope,
+/// returning it with the ledger after the call.
+fn simulate(
+    tx: &TxArgs,
+    invocation: Invocation,
+    snapshot: &Snapshot,
+    sequence: i64,
+) -> Result<(String, Snapshot)> {
+    let invocation = invocation
+        .fee(tx.fee)
+        .instruction_leeway(tx.instruction_leeway);
+    let simulation = invocation.simulate(snapshot, &tx.source)?;
+    let xdr = invocation
+        .simulated(&simulation)
+        .to_xdr_base64(&tx.source, sequence)?;
+    Ok((xdr, simulation.snapshot))
+}
+
+fn save_snapshot(tx: &TxArgs, snapshot: &Snapshot) -> Result<()> {
+    if let Some(path) = &tx.snapshot_out {
+        snapshot.write_file(path)?;
+    }
+    Ok(())
+}
+
+fn envelope(
+    tx: &TxArgs,
+    build: impl FnOnce(&AssetUpClient) -> Result<Invocation>,
+) -> Result<Output> {
+    let client = AssetUpClient::new(&tx.contract)?;
+    let snapshot = Snapshot::read_file(&tx.snapshot)?;
+    let (xdr, after) = simulate(tx, build(&client)?, &snapshot, tx.sequence)?;
+    save_snapshot(tx, &after)?;
+    Ok(Output {
+        text: xdr,
+        out: tx.out.clone(),
+    })
+}
+
+fn run(cli: Cli) -> Result<Output> {
+    match cli.command {
+        Command::Init { tx, admin } => {
+            envelope(&tx, |c| Ok(c.initialize(&parse_address(c.env(), &admin)?)))
+        }
+        Command::CreateCompany {
+            tx,
+            id,
+            name,
+            admin,
+        } => envelope(&tx, |c| {
+            let env = c.env();
+            Ok(c.create_company(
+                &parse_id(env, &id)?,
+                &soroban_sdk::String::from_str(env, &name),
+                &parse_address(env, &admin)?,
+            ))
+        }),
+        Command::CreateCategory {
+            tx,
+            company,
+            id,
+            name,
+            parent,
+            depreciation,
+            useful_life_months,
+            salvage_bps,
+        } => envelope(&tx, |c| {
+            let env = c.env();
+            Ok(c.create_category(
+                &parse_id(env, &company)?,
+                &id,
+                &soroban_sdk::String::from_str(env, &name),
+                &parent,
+                &DepreciationSettings {
+                    method: depreciation.into(),
+                    useful_life_months,
+                    salvage_value_bps: salvage_bps,
+                },
+            ))
+        }),
+        Command::CreateBranch {
+            tx,
+            company,
+            id,
+            name,
+            location,
+            admin,
+        } => envelope(&tx, |c| {
+            let env = c.env();
+            Ok(c.create_branch(
+                &parse_id(env, &company)?,
+                &parse_id(env, &id)?,
+                &soroban_sdk::String::from_str(env, &name),
+                &soroban_sdk::String::from_str(env, &location),
+                &parse_address(env, &admin)?,
+            ))
+        }),
+        Command::RegisterAsset { tx, file, branch } => {
+            let json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&file)?)?;
+            let batch = json.is_array() || branch.is_some();
+            let records: std::vec::Vec<AssetRecord> = if json.is_array() {
+                serde_json::from_value(json)?
+            } else {
+                vec![serde_json::from_value(json)?]
+            };
+            if records.len() > MAX_BATCH_SIZE as usize {
+                return Err(format!(
+                    "{} assets exceed the batch limit of {MAX_BATCH_SIZE}; use import-csv to split them",
+                    records.len()
+                )
+                .into());
+            }
+            envelope(&tx, |c| {
+                let env = c.env();
+                let mut assets = Vec::new(env);
+                for r in &records {
+                    assets.push_back(r.to_contract(env)?);
+                }
+                if !batch {
+                    return Ok(c.register_asset(&assets.get_unchecked(0)));
+                }
+                let branch_id = branch.as_deref().map(|b| parse_id(env, b)).transpose()?;
+                Ok(c.register_assets(&assets, &branch_id))
+            })
+        }
+        Command::ImportCsv {
+            tx,
+            file,
+            company,
+            branch,
+        } => {
+            let records = csv::read_assets(&fs::read_to_string(&file)?, &company)?;
+            let client = AssetUpClient::new(&tx.contract)?;
+            let env = client.env();
+            let branch_id = branch.as_deref().map(|b| parse_id(env, b)).transpose()?;
+            // Each batch is simulated against the ledger the previous one left
+            let mut snapshot = Snapshot::read_file(&tx.snapshot)?;
+            let mut envelopes = std::vec::Vec::new();
+            for (i, batch) in records.chunks(MAX_BATCH_SIZE as usize).enumerate() {
+                let mut assets = Vec::new(env);
+                for r in batch {
+                    assets.push_back(r.to_contract(env)?);
+                }
+                let invocation = client.register_assets(&assets, &branch_id);
+                let (xdr, after) = simulate(&tx, invocation, &snapshot, tx.sequence + i as i64)?;
+                envelopes.push(xdr);
+                snapshot = after;
+            }
+            save_snapshot(&tx, &snapshot)?;
+            Ok(Output {
+                text: envelopes.join("\n"),
+                out: tx.out,
+            })
+        }
+        Command::Tokenize { tx, asset, token } => envelope(&tx, |c| {
+            Ok(c.tokenize_asset(&parse_id(c.env(), &asset)?, &parse_id(c.env(), &token)?))
+        }),
+        Command::Get { snapshot, record } => {
+            let snapshot = Snapshot::read_file(&snapshot)?;
+            let text = match (record.asset, record.branch) {
+                (Some(id), _) => serde_json::to_string_pretty(
+                    &snapshot
+                        .asset(&id)
+                        .ok_or(format!("asset {id} not in snapshot"))?,
+                )?,
+                (_, Some(id)) => serde_json::to_string_pretty(
+                    &snapshot
+                        .branch(&id)
+                        .ok_or(format!("branch {id} not in snapshot"))?,
+                )?,
+                (None, None) => unreachable!("clap requires one of --asset and --branch"),
+            };
+            Ok(Output { text, out: None })
+        }
+        Command::Export {
+            snapshot,
+            records,
+            format,
+            out,
+        } => {
+            let snapshot = Snapshot::read_file(&snapshot)?;
+            let text = match (records, format) {
+                (Records::Assets, Format::Csv) => csv::write_assets(&snapshot.assets()),
+                (Records::Branches, Format::Csv) => csv::write_branches(&snapshot.branches()),
+                (Records::Assets, Format::Json) => {
+                    serde_json::to_string_pretty(&snapshot.assets())?
+                }
+                (Records::Branches, Format::Json) => {
+                    serde_json::to_string_pretty(&snapshot.branches())?
+                }
+            };
+            // CSV output already ends in a newline
+            let text = text.strip_suffix('\n').map(str::to_string).unwrap_or(text);
+            Ok(Output { text, out })
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let result = run(Cli::parse()).and_then(|output| match output.out {
+        Some(path) => Ok(fs::write(path, output.text + "\n")?),
+        None => {
+            println!("{}", output.text);
+            Ok(())
+        }
+    });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
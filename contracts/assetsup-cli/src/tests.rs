@@ -0,0 +1,411 @@
+extern crate std;
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use assetsup_client::assetsup::AssetUpContract;
+use clap::Parser;
+use soroban_sdk::{
+    Address, Env,
+    testutils::EnvTestConfig,
+    xdr::{
+        HostFunction, Limits, OperationBody, ReadXdr, ScVal, TransactionEnvelope, TransactionExt,
+    },
+};
+
+use crate::{Cli, MAX_BATCH_SIZE, run};
+
+const CONTRACT: &str = "CA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAXE";
+const ACCOUNT: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+
+/// Contract test snapshot with one registered asset
+const FIXTURE: &str =
+    "../assetsup/test_snapshots/tests/asset/test_register_and_get_asset_success.1.json";
+
+fn hex_seed(seed: u8) -> String {
+    (0..32u8)
+        .map(|i| format!("{:02x}", seed.wrapping_add(i)))
+        .collect()
+}
+
+fn tx_args(command: &str, snapshot: &Path) -> std::vec::Vec<String> {
+    [
+        "assetsup",
+        command,
+        "--contract",
+        CONTRACT,
+        "--source",
+        ACCOUNT,
+        "--sequence",
+        "7",
+        "--snapshot",
+        &snapshot.display().to_string(),
+    ]
+    .map(String::from)
+    .to_vec()
+}
+
+/// Run the CLI and decode the envelope it prints into the invoked function
+/// name and arguments, checking it carries simulation results.
+fn invoke(args: std::vec::Vec<String>) -> (String, std::vec::Vec<ScVal>) {
+    let output = run(Cli::try_parse_from(args).unwrap()).unwrap();
+    let TransactionEnvelope::Tx(envelope) =
+        TransactionEnvelope::from_xdr_base64(&output.text, Limits::none()).unwrap()
+    else {
+        panic!("expected a v1 envelope");
+    };
+    assert_eq!(envelope.tx.seq_num.0, 7);
+    let TransactionExt::V1(data) = &envelope.tx.ext else {
+        panic!("expected Soroban transaction data");
+    };
+    assert!(!data.resources.footprint.read_write.is_empty());
+    assert!(envelope.tx.fee as i64 > data.resource_fee);
+    let OperationBody::InvokeHostFunction(op) = &envelope.tx.operations[0].body else {
+        panic!("expected an invocation");
+    };
+    assert!(!op.auth.is_empty());
+    let HostFunction::InvokeContract(call) = &op.host_function else {
+        panic!("expected a contract call");
+    };
+    (
+        call.function_name.0.to_utf8_string().unwrap(),
+        call.args.to_vec(),
+    )
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("assetsup-cli-{}-{name}", std::process::id()))
+}
+
+fn temp_file(name: &str, contents: &str) -> PathBuf {
+    let path = temp_path(name);
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+/// Ledger holding only the contract, deployed at `CONTRACT`.
+fn deployed(name: &str) -> PathBuf {
+    let mut env = Env::default();
+    env.set_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env.register_at(&Address::from_str(&env, CONTRACT), AssetUpContract, ());
+    let path = temp_path(name);
+    env.to_ledger_snapshot_file(&path);
+    path
+}
+
+/// Run `args` with `--snapshot-out` and return the snapshot it wrote.
+fn chain(mut args: std::vec::Vec<String>, out: &str) -> (String, PathBuf) {
+    let path = temp_path(out);
+    args.extend(["--snapshot-out".into(), path.display().to_string()]);
+    (invoke(args).0, path)
+}
+
+/// Set up a fresh deployment with the CLI alone: `ACCOUNT` administers the
+/// platform and company `hex_seed(200)`, which has category 1 and branch
+/// `hex_seed(100)`. Returns the snapshot after each step.
+fn setup(name: &str) -> std::vec::Vec<PathBuf> {
+    let start = deployed(&format!("{name}-deployed.json"));
+    let steps: [(&str, &[&str], &str); 4] = [
+        ("init", &["--admin", ACCOUNT], "initialize"),
+        (
+            "create-company",
+            &["--id", &hex_seed(200), "--name", "Acme", "--admin", ACCOUNT],
+            "create_company",
+        ),
+        (
+            "create-category",
+            &[
+                "--company",
+                &hex_seed(200),
+                "--id",
+                "1",
+                "--name",
+                "Furniture",
+                "--depreciation",
+                "straight-line",
+                "--useful-life-months",
+                "60",
+            ],
+            "create_category",
+        ),
+        (
+            "create-branch",
+            &[
+                "--company",
+                &hex_seed(200),
+                "--id",
+                &hex_seed(100),
+                "--name",
+                "Depot",
+                "--location",
+                "Lagos",
+                "--admin",
+                ACCOUNT,
+            ],
+            "create_branch",
+        ),
+    ];
+    let mut snapshots = vec![start];
+    for (command, extra, expected) in steps {
+        let mut args = tx_args(command, snapshots.last().unwrap());
+        args.extend(extra.iter().map(|a| a.to_string()));
+        let (function, snapshot) = chain(args, &format!("{name}-{command}.json"));
+        assert_eq!(function, expected);
+        snapshots.push(snapshot);
+    }
+    snapshots
+}
+
+fn cleanup(paths: &[PathBuf]) {
+    for path in paths {
+        fs::remove_file(path).unwrap();
+    }
+}
+
+fn asset_json(seed: u8) -> String {
+    format!(
+        r#"{{
+            "id": "{}",
+            "company_id": "{}",
+            "name": "Forklift",
+            "asset_type": "Physical",
+            "category_id": 1,
+            "branch_id": 0,
+            "department_id": 0,
+            "status": "Active",
+            "purchase_date": 1700000000,
+            "purchase_cost": 2500000,
+            "current_value": 2000000,
+            "currency": {{ "code": "USD", "scale": 2 }},
+            "warranty_expiry": 0,
+            "stellar_token_id": "{}",
+            "owner": "{ACCOUNT}"
+        }}"#,
+        hex_seed(seed),
+        hex_seed(200),
+        "00".repeat(32),
+    )
+}
+
+#[test]
+fn test_setup_flow_with_cli_alone() {
+    let snapshots = setup("flow");
+    let ready = snapshots.last().unwrap();
+    let branch = run(Cli::try_parse_from([
+        "assetsup",
+        "get",
+        &ready.display().to_string(),
+        "--branch",
+        &hex_seed(100),
+    ])
+    .unwrap())
+    .unwrap();
+    let branch: serde_json::Value = serde_json::from_str(&branch.text).unwrap();
+    assert_eq!(branch["name"], "Depot");
+
+    let file = temp_file("flow-asset.json", &asset_json(1));
+    let mut args = tx_args("register-asset", ready);
+    args.push(file.display().to_string());
+    let (function, registered) = chain(args, "flow-registered.json");
+    assert_eq!(function, "register_asset");
+
+    let mut args = tx_args("tokenize", &registered);
+    args.extend(["--asset".into(), hex_seed(1), "--token".into(), hex_seed(2)]);
+    let (function, call_args) = invoke(args);
+    assert_eq!(function, "tokenize_asset");
+    assert_eq!(call_args.len(), 2);
+
+    // Steps must run in order: a company needs an initialized contract
+    let mut args = tx_args("create-company", &snapshots[0]);
+    args.extend(["--id", &hex_seed(200), "--name", "Acme", "--admin", ACCOUNT].map(String::from));
+    let err = run(Cli::try_parse_from(args).unwrap()).err().unwrap();
+    assert!(err.to_string().starts_with("simulation failed"));
+
+    cleanup(&snapshots);
+    cleanup(&[file, registered]);
+}
+
+#[test]
+fn test_register_asset_single_and_batch() {
+    let snapshots = setup("register");
+    let ready = snapshots.last().unwrap();
+    let single = temp_file("single.json", &asset_json(1));
+    let mut args = tx_args("register-asset", ready);
+    args.push(single.display().to_string());
+    let (function, call_args) = invoke(args);
+    assert_eq!(function, "register_asset");
+    assert!(matches!(call_args[..], [ScVal::Map(_)]));
+
+    let batch = temp_file(
+        "batch.json",
+        &format!("[{}, {}]", asset_json(1), asset_json(3)),
+    );
+    let mut args = tx_args("register-asset", ready);
+    args.extend([
+        batch.display().to_string(),
+        "--branch".into(),
+        hex_seed(100),
+    ]);
+    let (function, call_args) = invoke(args);
+    assert_eq!(function, "register_assets");
+    let ScVal::Vec(Some(assets)) = &call_args[0] else {
+        panic!("expected a vector of assets");
+    };
+    assert_eq!(assets.len(), 2);
+    assert!(matches!(call_args[1], ScVal::Bytes(_)));
+
+    // Rejected before an envelope is built, rather than failing on chain
+    let records: std::vec::Vec<String> = (0..=MAX_BATCH_SIZE as u8)
+        .map(|i| asset_json(2 * i + 1))
+        .collect();
+    let oversized = temp_file("oversized.json", &format!("[{}]", records.join(",")));
+    let mut args = tx_args("register-asset", ready);
+    args.push(oversized.display().to_string());
+    let err = run(Cli::try_parse_from(args).unwrap()).err().unwrap();
+    assert!(err.to_string().contains("batch limit"));
+
+    cleanup(&snapshots);
+    cleanup(&[single, batch, oversized]);
+}
+
+#[test]
+fn test_get_reads_snapshot() {
+    let output =
+        run(Cli::try_parse_from(["assetsup", "get", FIXTURE, "--asset", &hex_seed(1)]).unwrap())
+            .unwrap();
+    let asset: serde_json::Value = serde_json::from_str(&output.text).unwrap();
+    assert_eq!(asset["name"], "Laptop A");
+    assert_eq!(asset["department_id"], 20);
+
+    let output =
+        run(Cli::try_parse_from(["assetsup", "get", FIXTURE, "--branch", &hex_seed(100)]).unwrap())
+            .unwrap();
+    let branch: serde_json::Value = serde_json::from_str(&output.text).unwrap();
+    assert_eq!(branch["name"], "Main Branch");
+
+    let missing =
+        run(Cli::try_parse_from(["assetsup", "get", FIXTURE, "--asset", &hex_seed(9)]).unwrap());
+    assert!(missing.is_err());
+    // Exactly one of --asset and --branch
+    assert!(Cli::try_parse_from(["assetsup", "get", FIXTURE]).is_err());
+}
+
+#[test]
+fn test_import_csv_batches_rows() {
+    let mut sheet = String::from(
+        "tag,name,asset_type,category_id,purchase_date,purchase_cost,currency_code,currency_scale,owner\n",
+    );
+    for i in 0..MAX_BATCH_SIZE * 2 + 1 {
+        sheet += &format!("T-{i},Chair,Physical,1,1700000000,5000,USD,2,{ACCOUNT}\n");
+    }
+    let snapshots = setup("import");
+    let file = temp_file("assets.csv", &sheet);
+    let imported = temp_path("imported.json");
+    let mut args = tx_args("import-csv", snapshots.last().unwrap());
+    args.extend([
+        file.display().to_string(),
+        "--company".into(),
+        hex_seed(200),
+        "--snapshot-out".into(),
+        imported.display().to_string(),
+    ]);
+    let output = run(Cli::try_parse_from(args).unwrap()).unwrap();
+
+    let batches: std::vec::Vec<(i64, usize)> = output
+        .text
+        .lines()
+        .map(|line| {
+            let TransactionEnvelope::Tx(envelope) =
+                TransactionEnvelope::from_xdr_base64(line, Limits::none()).unwrap()
+            else {
+                panic!("expected a v1 envelope");
+            };
+            let OperationBody::InvokeHostFunction(op) = &envelope.tx.operations[0].body else {
+                panic!("expected an invocation");
+            };
+            let HostFunction::InvokeContract(call) = &op.host_function else {
+                panic!("expected a contract call");
+            };
+            assert_eq!(
+                call.function_name.0.to_utf8_string().unwrap(),
+                "register_assets"
+            );
+            let ScVal::Vec(Some(assets)) = &call.args[0] else {
+                panic!("expected a vector of assets");
+            };
+            (envelope.tx.seq_num.0, assets.len())
+        })
+        .collect();
+    let full = MAX_BATCH_SIZE as usize;
+    assert_eq!(batches, [(7, full), (8, full), (9, 1)]);
+
+    // Later batches were simulated on top of the earlier ones
+    let exported = run(Cli::try_parse_from([
+        "assetsup",
+        "export",
+        &imported.display().to_string(),
+        "--records",
+        "assets",
+    ])
+    .unwrap())
+    .unwrap();
+    assert_eq!(exported.text.lines().count(), 1 + 2 * full + 1);
+
+    cleanup(&snapshots);
+    cleanup(&[file, imported]);
+}
+
+#[test]
+fn test_export_snapshot() {
+    let export = |records: &str, format: &str| {
+        run(Cli::try_parse_from([
+            "assetsup",
+            "export",
+            FIXTURE,
+            "--records",
+            records,
+            "--format",
+            format,
+        ])
+        .unwrap())
+        .unwrap()
+        .text
+    };
+
+    let assets = export("assets", "csv");
+    let lines: std::vec::Vec<&str> = assets.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("id,company_id,name,"));
+    assert!(lines[1].starts_with(&format!("{},", hex_seed(1))));
+    assert!(lines[1].contains(",Laptop A,"));
+
+    let branches: serde_json::Value = serde_json::from_str(&export("branches", "json")).unwrap();
+    assert_eq!(branches[0]["name"], "Main Branch");
+    assert_eq!(branches.as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn test_rejects_malformed_input() {
+    let snapshot = deployed("malformed.json");
+    let mut args = tx_args("tokenize", &snapshot);
+    args.extend([
+        "--asset".into(),
+        "xyz".into(),
+        "--token".into(),
+        hex_seed(2),
+    ]);
+    assert!(run(Cli::try_parse_from(args).unwrap()).is_err());
+
+    let mut args = tx_args("init", &snapshot);
+    args[3] = ACCOUNT.into();
+    args.extend(["--admin".into(), ACCOUNT.into()]);
+    assert!(run(Cli::try_parse_from(args).unwrap()).is_err());
+
+    // Simulation needs a snapshot
+    assert!(Cli::try_parse_from(["assetsup", "init", "--admin", ACCOUNT]).is_err());
+    cleanup(&[snapshot]);
+}
@@ -0,0 +1,36 @@
+use soroban_sdk::{Address, Env, Val, Vec};
+
+use crate::{Error, invocation::Invocation, records};
+
+/// Entry point for building invocations against one deployed contract.
+///
+/// Holds a local, ledger-less `Env` that backs the contract types passed to
+/// the builders; build arguments with `client.env()`.
+pub struct AssetUpClient {
+    env: Env,
+    contract: Address,
+}
+
+impl AssetUpClient {
+    /// `contract_id` is the contract's `C...` strkey.
+    pub fn new(contract_id: &str) -> Result<Self, Error> {
+        if !contract_id.starts_with('C') {
+            return Err(Error::InvalidAddress(contract_id.into()));
+        }
+        let env = Env::default();
+        let contract = records::parse_address(&env, contract_id)?;
+        Ok(AssetUpClient { env, contract })
+    }
+
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+
+    pub fn contract(&self) -> &Address {
+        &self.contract
+    }
+
+    pub(crate) fn invocation(&self, function: &'static str, args: Vec<Val>) -> Invocation {
+        Invocation::new(&self.env, &self.contract, function, args)
+    }
+}
@@ -0,0 +1,270 @@
+//! CSV form of asset and branch records, for moving registers between the
+//! contract and spreadsheets.
+//!
+//! Exports use one column per record field, with the currency split into
+//! `currency_code` and `currency_scale`. Imports read the same columns by
+//! header name, in any order; unknown columns are ignored.
+
+use assetsup::types::{AssetStatus, AssetType};
+use sha2::{Digest, Sha256};
+
+use crate::{AssetRecord, BranchRecord, CurrencyRecord, Error};
+
+pub const ASSET_COLUMNS: [&str; 16] = [
+    "id",
+    "company_id",
+    "name",
+    "asset_type",
+    "category_id",
+    "branch_id",
+    "department_id",
+    "status",
+    "purchase_date",
+    "purchase_cost",
+    "current_value",
+    "currency_code",
+    "currency_scale",
+    "warranty_expiry",
+    "stellar_token_id",
+    "owner",
+];
+
+pub const BRANCH_COLUMNS: [&str; 5] = ["id", "company_id", "name", "location", "admin"];
+
+/// Id of the asset with spreadsheet key `tag` in a company: the SHA-256 of
+/// the company id and the tag, so re-importing a sheet yields the same ids.
+pub fn asset_id(company_id: &str, tag: &str) -> Result<String, Error> {
+    let mut company = [0u8; 32];
+    hex::decode_to_slice(company_id, &mut company)
+        .map_err(|_| Error::InvalidId(company_id.into()))?;
+    let digest = Sha256::new()
+        .chain_update(b"assetsup:asset:")
+        .chain_update(company)
+        .chain_update(tag.trim().as_bytes())
+        .finalize();
+    Ok(hex::encode(digest))
+}
+
+pub fn write_assets(records: &[AssetRecord]) -> String {
+    let rows = records.iter().map(|a| {
+        vec![
+            a.id.clone(),
+            a.company_id.clone(),
+            a.name.clone(),
+            format!("{:?}", a.asset_type),
+            a.category_id.to_string(),
+            a.branch_id.to_string(),
+            a.department_id.to_string(),
+            format!("{:?}", a.status),
+            a.purchase_date.to_string(),
+            a.purchase_cost.to_string(),
+            a.current_value.to_string(),
+            a.currency.code.clone(),
+            a.currency.scale.to_string(),
+            a.warranty_expiry.to_string(),
+            a.stellar_token_id.clone(),
+            a.owner.clone(),
+        ]
+    });
+    write(&ASSET_COLUMNS, rows)
+}
+
+pub fn write_branches(records: &[BranchRecord]) -> String {
+    let rows = records.iter().map(|b| {
+        vec![
+            b.id.clone(),
+            b.company_id.clone(),
+            b.name.clone(),
+            b.location.clone(),
+            b.admin.clone(),
+        ]
+    });
+    write(&BRANCH_COLUMNS, rows)
+}
+
+/// Assets of company `company_id` listed in a CSV sheet, ready to register.
+///
+/// Each row needs `name`, `asset_type`, `category_id`, `purchase_date`,
+/// `purchase_cost`, `currency_code`, `currency_scale` and `owner`, plus
+/// either an explicit
+/// `id` or a `tag` to derive one from with [`asset_id`]. Optional columns
+/// default to zero, except `status` (`Active`) and `current_value`
+/// (`purchase_cost`). `company_id`, `branch_id` and `stellar_token_id`
+/// columns are not read: assets are registered untokenized under
+/// `company_id`.
+pub fn read_assets(text: &str, company_id: &str) -> Result<Vec<AssetRecord>, Error> {
+    let mut rows = parse(text)?.into_iter();
+    let Some((_, header)) = rows.next() else {
+        return Ok(Vec::new());
+    };
+    let header: Vec<String> = header.iter().map(|h| h.trim().to_lowercase()).collect();
+    rows.map(|(line, fields)| {
+        let row = Row {
+            line,
+            header: &header,
+            fields: &fields,
+        };
+        let id = match row.get("id") {
+            Some(id) => id.to_lowercase(),
+            None => asset_id(company_id, row.required("tag")?)?,
+        };
+        let purchase_cost = row.parse("purchase_cost")?;
+        Ok(AssetRecord {
+            id,
+            company_id: company_id.to_lowercase(),
+            name: row.required("name")?.into(),
+            asset_type: row.asset_type()?,
+            category_id: row.parse("category_id")?,
+            branch_id: 0,
+            department_id: row.parse_or("department_id", 0)?,
+            status: row.status()?,
+            purchase_date: row.parse("purchase_date")?,
+            purchase_cost,
+            current_value: row.parse_or("current_value", purchase_cost)?,
+            currency: CurrencyRecord {
+                code: row.required("currency_code")?.to_uppercase(),
+                scale: row.parse("currency_scale")?,
+            },
+            warranty_expiry: row.parse_or("warranty_expiry", 0)?,
+            stellar_token_id: "00".repeat(32),
+            owner: row.required("owner")?.into(),
+        })
+    })
+    .collect()
+}
+
+/// One data row of a sheet, with its fields looked up by header name.
+struct Row<'a> {
+    line: usize,
+    header: &'a [String],
+    fields: &'a [String],
+}
+
+impl Row<'_> {
+    fn error(&self, message: String) -> Error {
+        Error::Csv {
+            line: self.line,
+            message,
+        }
+    }
+
+    /// Trimmed value of `column`, or `None` when absent or blank.
+    fn get(&self, column: &str) -> Option<&str> {
+        let i = self.header.iter().position(|h| h == column)?;
+        let value = self.fields.get(i)?.trim();
+        (!value.is_empty()).then_some(value)
+    }
+
+    fn required(&self, column: &str) -> Result<&str, Error> {
+        self.get(column)
+            .ok_or_else(|| self.error(format!("missing {column}")))
+    }
+
+    fn parse<T: std::str::FromStr>(&self, column: &str) -> Result<T, Error> {
+        let value = self.required(column)?;
+        value
+            .parse()
+            .map_err(|_| self.error(format!("invalid {column}: {value}")))
+    }
+
+    fn parse_or<T: std::str::FromStr>(&self, column: &str, default: T) -> Result<T, Error> {
+        match self.get(column) {
+            Some(_) => self.parse(column),
+            None => Ok(default),
+        }
+    }
+
+    fn asset_type(&self) -> Result<AssetType, Error> {
+        let value = self.required("asset_type")?;
+        match value.to_lowercase().as_str() {
+            "physical" => Ok(AssetType::Physical),
+            "digital" => Ok(AssetType::Digital),
+            _ => Err(self.error(format!("invalid asset_type: {value}"))),
+        }
+    }
+
+    fn status(&self) -> Result<AssetStatus, Error> {
+        let Some(value) = self.get("status") else {
+            return Ok(AssetStatus::Active);
+        };
+        match value.to_lowercase().replace([' ', '_'], "").as_str() {
+            "active" => Ok(AssetStatus::Active),
+            "inmaintenance" => Ok(AssetStatus::InMaintenance),
+            "intransit" => Ok(AssetStatus::InTransit),
+            "disposed" => Ok(AssetStatus::Disposed),
+            _ => Err(self.error(format!("invalid status: {value}"))),
+        }
+    }
+}
+
+fn write(header: &[&str], rows: impl Iterator<Item = Vec<String>>) -> String {
+    let mut out = header.join(",");
+    out.push('\n');
+    for row in rows {
+        let fields: Vec<String> = row.iter().map(|f| quote(f)).collect();
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.into()
+    }
+}
+
+/// Records of an RFC 4180 document, each tagged with the line it starts
+/// on. Blank lines are skipped.
+fn parse(text: &str) -> Result<Vec<(usize, Vec<String>)>, Error> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut line = 1;
+    let mut start = 1;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\n' {
+            line += 1;
+        }
+        match c {
+            '"' if quoted => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    quoted = false;
+                }
+            }
+            '"' if field.is_empty() => quoted = true,
+            _ if quoted => field.push(c),
+            ',' => fields.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                fields.push(std::mem::take(&mut field));
+                if fields.len() > 1 || !fields[0].is_empty() {
+                    records.push((start, std::mem::take(&mut fields)));
+                }
+                fields.clear();
+                start = line;
+            }
+            _ => field.push(c),
+        }
+    }
+    if quoted {
+        return Err(Error::Csv {
+            line: start,
+            message: "unterminated quoted field".into(),
+        });
+    }
+    fields.push(field);
+    if fields.len() > 1 || !fields[0].is_empty() {
+        records.push((start, fields));
+    }
+    Ok(records)
+}
@@ -0,0 +1,38 @@
+use core::fmt;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    // Strkey is malformed or of the wrong kind
+    InvalidAddress(String),
+    // Id is not 32 bytes of hex
+    InvalidId(String),
+    // Currency code is not a valid symbol
+    InvalidSymbol(String),
+    // Argument cannot be represented as XDR
+    Conversion,
+    // XDR encoding failed
+    Xdr,
+    // Ledger snapshot cannot be read or parsed
+    Snapshot(String),
+    // CSV sheet is malformed or has an invalid row
+    Csv { line: usize, message: String },
+    // Invocation failed or cannot run against the snapshot
+    Simulation(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidAddress(s) => write!(f, "invalid address: {s}"),
+            Error::InvalidId(s) => write!(f, "invalid 32-byte hex id: {s}"),
+            Error::InvalidSymbol(s) => write!(f, "invalid symbol: {s}"),
+            Error::Conversion => write!(f, "argument cannot be converted to XDR"),
+            Error::Xdr => write!(f, "XDR encoding failed"),
+            Error::Snapshot(s) => write!(f, "unreadable snapshot: {s}"),
+            Error::Csv { line, message } => write!(f, "CSV line {line}: {message}"),
+            Error::Simulation(s) => write!(f, "simulation failed: {s}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
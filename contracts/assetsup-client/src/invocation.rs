@@ -0,0 +1,181 @@
+use soroban_sdk::{
+    Address, Env, TryFromVal, Val, Vec,
+    xdr::{
+        self, HostFunction, InvokeContractArgs, InvokeHostFunctionOp, Limits, Memo, MuxedAccount,
+        Operation, OperationBody, Preconditions, ScAddress, ScSymbol, ScVal, SequenceNumber,
+        SorobanAuthorizationEntry, SorobanTransactionData, TimeBounds, TimePoint, Transaction,
+        TransactionEnvelope, TransactionExt, TransactionV1Envelope, Uint256, WriteXdr,
+    },
+};
+
+use crate::{
+    Error, Snapshot, records,
+    simulation::{self, DEFAULT_INSTRUCTION_LEEWAY, Simulation},
+};
+
+/// Inclusion fee used when none is set: the network's minimum. Simulated
+/// envelopes add the resource fee on top.
+pub const DEFAULT_FEE: u32 = 100;
+
+/// One contract call, ready to be wrapped in a transaction.
+#[derive(Clone)]
+pub struct Invocation {
+    env: Env,
+    contract: Address,
+    function: &'static str,
+    args: Vec<Val>,
+    fee: u32,
+    time_bounds: Option<(u64, u64)>,
+    instruction_leeway: u32,
+    simulated: Option<(
+        SorobanTransactionData,
+        std::vec::Vec<SorobanAuthorizationEntry>,
+    )>,
+}
+
+impl Invocation {
+    pub(crate) fn new(
+        env: &Env,
+        contract: &Address,
+        function: &'static str,
+        args: Vec<Val>,
+    ) -> Self {
+        Invocation {
+            env: env.clone(),
+            contract: contract.clone(),
+            function,
+            args,
+            fee: DEFAULT_FEE,
+            time_bounds: None,
+            instruction_leeway: DEFAULT_INSTRUCTION_LEEWAY,
+            simulated: None,
+        }
+    }
+
+    pub fn function(&self) -> &'static str {
+        self.function
+    }
+
+    /// Inclusion fee in stroops.
+    pub fn fee(mut self, fee: u32) -> Self {
+        self.fee = fee;
+        self
+    }
+
+    /// Restrict the transaction to ledger close times within
+    /// `min..=max` (unix seconds; `max` of 0 means unbounded).
+    pub fn time_bounds(mut self, min: u64, max: u64) -> Self {
+        self.time_bounds = Some((min, max));
+        self
+    }
+
+    /// Instructions `simulate` adds for running the contract's Wasm.
+    pub fn instruction_leeway(mut self, instructions: u32) -> Self {
+        self.instruction_leeway = instructions;
+        self
+    }
+
+    /// Run the call as `source_account` against `snapshot`, recording the
+    /// ledger entries it touches, its resources and the authorizations it
+    /// needs. The contract must be deployed in the snapshot.
+    pub fn simulate(&self, snapshot: &Snapshot, source_account: &str) -> Result<Simulation, Error> {
+        let source = xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(
+            self.source_key(source_account)?,
+        ));
+        simulation::run(
+            snapshot,
+            source,
+            &self.invoke_args()?,
+            self.instruction_leeway,
+        )
+    }
+
+    /// Attach the footprint, resources and authorization entries of a
+    /// simulation of this call.
+    pub fn simulated(mut self, simulation: &Simulation) -> Self {
+        self.simulated = Some((simulation.transaction_data.clone(), simulation.auth.clone()));
+        self
+    }
+
+    fn source_key(&self, source_account: &str) -> Result<Uint256, Error> {
+        match ScVal::from(&records::parse_address(&self.env, source_account)?) {
+            ScVal::Address(ScAddress::Account(xdr::AccountId(
+                xdr::PublicKey::PublicKeyTypeEd25519(key),
+            ))) => Ok(key),
+            _ => Err(Error::InvalidAddress(source_account.into())),
+        }
+    }
+
+    pub fn invoke_args(&self) -> Result<InvokeContractArgs, Error> {
+        let contract_address = match ScVal::from(&self.contract) {
+            ScVal::Address(a) => a,
+            _ => return Err(Error::Conversion),
+        };
+        let args = self
+            .args
+            .iter()
+            .map(|v| ScVal::try_from_val(&self.env, &v).map_err(|_| Error::Conversion))
+            .collect::<Result<std::vec::Vec<_>, _>>()?;
+        Ok(InvokeContractArgs {
+            contract_address,
+            function_name: ScSymbol(self.function.try_into().map_err(|_| Error::Xdr)?),
+            args: args.try_into().map_err(|_| Error::Xdr)?,
+        })
+    }
+
+    /// Unsigned envelope from `source_account` (a `G...` strkey) using
+    /// `sequence`, which must be the account's current sequence plus one.
+    /// Without an attached simulation it has no Soroban resource data or
+    /// authorization entries and cannot be submitted as is.
+    pub fn envelope(
+        &self,
+        source_account: &str,
+        sequence: i64,
+    ) -> Result<TransactionEnvelope, Error> {
+        let source = MuxedAccount::Ed25519(self.source_key(source_account)?);
+        let (ext, auth, fee) = match &self.simulated {
+            Some((data, auth)) => (
+                TransactionExt::V1(data.clone()),
+                auth.clone().try_into().map_err(|_| Error::Xdr)?,
+                u32::try_from(data.resource_fee)
+                    .ok()
+                    .and_then(|f| f.checked_add(self.fee))
+                    .ok_or(Error::Conversion)?,
+            ),
+            None => (TransactionExt::V0, Default::default(), self.fee),
+        };
+        let op = Operation {
+            source_account: None,
+            body: OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+                host_function: HostFunction::InvokeContract(self.invoke_args()?),
+                auth,
+            }),
+        };
+        let cond = match self.time_bounds {
+            Some((min, max)) => Preconditions::Time(TimeBounds {
+                min_time: TimePoint(min),
+                max_time: TimePoint(max),
+            }),
+            None => Preconditions::None,
+        };
+        Ok(TransactionEnvelope::Tx(TransactionV1Envelope {
+            tx: Transaction {
+                source_account: source,
+                fee,
+                seq_num: SequenceNumber(sequence),
+                cond,
+                memo: Memo::None,
+                operations: [op].try_into().map_err(|_| Error::Xdr)?,
+                ext,
+            },
+            signatures: Default::default(),
+        }))
+    }
+
+    /// Base64 XDR of `envelope`, as accepted by Stellar Lab and the CLI.
+    pub fn to_xdr_base64(&self, source_account: &str, sequence: i64) -> Result<String, Error> {
+        self.envelope(source_account, sequence)?
+            .to_xdr_base64(Limits::none())
+            .map_err(|_| Error::Xdr)
+    }
+}
@@ -0,0 +1,44 @@
+//! Off-chain client for the AssetUp contract.
+//!
+//! Builds unsigned `InvokeHostFunction` transactions for every contract
+//! method without network access, and converts assets and branches to and
+//! from serde-friendly records.
+//!
+//! ```ignore
+//! let client = AssetUpClient::new("CA...")?;
+//! let asset = record.to_contract(client.env())?;
+//! let call = client.register_asset(&asset);
+//! let simulation = call.simulate(&snapshot, "GA...")?;
+//! let xdr = call.simulated(&simulation).to_xdr_base64("GA...", 42)?;
+//! ```
+//!
+//! `Invocation::simulate` runs a call against a ledger `Snapshot` with the
+//! contract compiled into this crate, recording the footprint, resources,
+//! resource fee and authorization entries the envelope needs, and the ledger
+//! state after the call. Native execution does not meter the Wasm VM, so an
+//! instruction leeway is added to the measured count. `Snapshot` also reads
+//! assets and branches back out of a ledger, and `csv` converts records to
+//! and from spreadsheet rows.
+
+#![allow(clippy::too_many_arguments)]
+
+mod client;
+pub mod csv;
+mod error;
+mod invocation;
+mod methods;
+pub mod records;
+mod simulation;
+mod snapshot;
+
+pub use assetsup;
+pub use client::AssetUpClient;
+pub use error::Error;
+pub use invocation::{DEFAULT_FEE, Invocation};
+pub use methods::METHODS;
+pub use records::{AssetRecord, BranchRecord, CurrencyRecord};
+pub use simulation::{DEFAULT_INSTRUCTION_LEEWAY, Simulation};
+pub use snapshot::Snapshot;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,242 @@
+use assetsup::{
+    AssetUpContractArgs, asset, category, cost_center, currency, document, location, oracle,
+    reservation,
+};
+use soroban_sdk::{Address, Bytes, BytesN, IntoVal, String, Symbol, Vec, vec};
+
+use crate::{AssetUpClient, invocation::Invocation};
+
+/// Declares one typed builder per contract method. Each builder also calls
+/// the contract's generated `AssetUpContractArgs`, so a signature that
+/// drifts from the contract fails to compile.
+macro_rules! methods {
+    ($($name:ident($($arg:ident: $ty:ty),* $(,)?);)*) => {
+        impl AssetUpClient {
+            $(
+                #[doc = concat!("Invocation of `", stringify!($name), "`.")]
+                pub fn $name(&self, $($arg: &$ty),*) -> Invocation {
+                    let _ = AssetUpContractArgs::$name($($arg),*);
+                    let env = self.env();
+                    self.invocation(stringify!($name), vec![env $(, $arg.clone().into_val(env))*])
+                }
+            )*
+        }
+
+        /// Names of the contract methods the client can build, in contract
+        /// order.
+        pub const METHODS: &[&str] = &[$(stringify!($name)),*];
+    };
+}
+
+methods! {
+    initialize(admin: Address);
+    get_admin();
+    create_company(company_id: BytesN<32>, name: String, admin: Address);
+    set_company_admin(company_id: BytesN<32>, admin: Address);
+    get_company(company_id: BytesN<32>);
+    get_company_branches(company_id: BytesN<32>);
+    register_asset(asset: asset::Asset);
+    register_assets(assets: Vec<asset::Asset>, branch_id: Option<BytesN<32>>);
+    get_asset(asset_id: BytesN<32>);
+    transfer_asset(asset_id: BytesN<32>, new_owner: Address);
+    dispose_asset(asset_id: BytesN<32>);
+    create_branch(
+        company_id: BytesN<32>,
+        id: BytesN<32>,
+        name: String,
+        location: String,
+        admin: Address,
+    );
+    add_asset_to_branch(branch_id: BytesN<32>, asset_id: BytesN<32>);
+    get_branch_assets(branch_id: BytesN<32>, start: u32, limit: u32);
+    get_branch(branch_id: BytesN<32>);
+    create_department(branch_id: BytesN<32>, department_id: u64, name: String, head: Address);
+    set_department_head(company_id: BytesN<32>, department_id: u64, head: Address);
+    assign_asset_to_department(asset_id: BytesN<32>, department_id: u64);
+    get_department(company_id: BytesN<32>, department_id: u64);
+    get_branch_departments(branch_id: BytesN<32>);
+    get_department_assets(company_id: BytesN<32>, department_id: u64, start: u32, limit: u32);
+    create_category(
+        company_id: BytesN<32>,
+        category_id: u64,
+        name: String,
+        parent_id: u64,
+        depreciation: category::DepreciationSettings,
+    );
+    set_category_depreciation(
+        company_id: BytesN<32>,
+        category_id: u64,
+        depreciation: category::DepreciationSettings,
+    );
+    get_category(company_id: BytesN<32>, category_id: u64);
+    get_subcategories(company_id: BytesN<32>, parent_id: u64);
+    get_assets_by_category(company_id: BytesN<32>, category_id: u64, start: u32, limit: u32);
+    tokenize_asset(asset_id: BytesN<32>, token_id: BytesN<32>);
+    register_lien(
+        lien_id: BytesN<32>,
+        asset_id: BytesN<32>,
+        lender: Address,
+        amount: i128,
+        currency: currency::Currency,
+        priority: u32,
+        expiry: u64,
+    );
+    release_lien(lien_id: BytesN<32>);
+    get_lien(lien_id: BytesN<32>);
+    get_liens(asset_id: BytesN<32>);
+    create_policy(
+        policy_id: BytesN<32>,
+        asset_id: BytesN<32>,
+        insurer: Address,
+        policy_hash: BytesN<32>,
+        coverage_amount: i128,
+        premium: i128,
+        currency: currency::Currency,
+        payout_token: Address,
+        start_date: u64,
+        end_date: u64,
+    );
+    cancel_policy(policy_id: BytesN<32>);
+    get_policy(policy_id: BytesN<32>);
+    get_asset_policies(asset_id: BytesN<32>);
+    file_claim(
+        claim_id: BytesN<32>,
+        policy_id: BytesN<32>,
+        amount: i128,
+        evidence_hash: BytesN<32>,
+    );
+    decide_claim(claim_id: BytesN<32>, approve: bool, approved_amount: i128);
+    settle_claim(claim_id: BytesN<32>);
+    get_claim(claim_id: BytesN<32>);
+    get_asset_claims(asset_id: BytesN<32>);
+    attach_document(
+        asset_id: BytesN<32>,
+        hash: Bytes,
+        hash_type: document::HashType,
+        kind: document::DocumentKind,
+        uploader: Address,
+    );
+    verify_document(asset_id: BytesN<32>, hash: Bytes);
+    get_document(asset_id: BytesN<32>, hash: Bytes);
+    get_asset_documents(asset_id: BytesN<32>);
+    bind_tag(asset_id: BytesN<32>, tag_hash: BytesN<32>);
+    retag_asset(asset_id: BytesN<32>, new_tag_hash: BytesN<32>);
+    get_asset_by_tag(tag_hash: BytesN<32>);
+    get_tag_binding(tag_hash: BytesN<32>);
+    get_asset_tag(asset_id: BytesN<32>);
+    create_inventory_item(branch_id: BytesN<32>, sku: String, name: String);
+    receive_stock(branch_id: BytesN<32>, sku: String, quantity: u64, reason: String);
+    issue_stock(branch_id: BytesN<32>, sku: String, quantity: u64, reason: String);
+    adjust_stock(branch_id: BytesN<32>, sku: String, delta: i64, reason: String);
+    transfer_stock(
+        from_branch_id: BytesN<32>,
+        to_branch_id: BytesN<32>,
+        sku: String,
+        quantity: u64,
+        reason: String,
+    );
+    get_inventory_item(branch_id: BytesN<32>, sku: String);
+    get_branch_inventory(branch_id: BytesN<32>);
+    get_stock_movements(branch_id: BytesN<32>, sku: String, start: u32, limit: u32);
+    set_branch_budget(branch_id: BytesN<32>, budget: currency::Money);
+    get_branch_budget(branch_id: BytesN<32>);
+    create_purchase_order(
+        po_id: BytesN<32>,
+        branch_id: BytesN<32>,
+        requester: Address,
+        supplier: Address,
+        item_name: String,
+        category_id: u64,
+        quantity: u32,
+        unit_cost: i128,
+        currency: currency::Currency,
+        payment_token: Address,
+    );
+    decide_purchase_order(po_id: BytesN<32>, approve: bool);
+    cancel_purchase_order(po_id: BytesN<32>);
+    receive_purchase_order(po_id: BytesN<32>, asset_ids: Vec<BytesN<32>>);
+    rate_purchase_order(po_id: BytesN<32>, delivery_rating: u32, quality_rating: u32);
+    get_purchase_order(po_id: BytesN<32>);
+    get_branch_purchase_orders(branch_id: BytesN<32>);
+    register_supplier(
+        company_id: BytesN<32>,
+        supplier: Address,
+        name: String,
+        tax_id_hash: BytesN<32>,
+    );
+    set_supplier_verified(company_id: BytesN<32>, supplier: Address, verified: bool);
+    get_supplier(company_id: BytesN<32>, supplier: Address);
+    get_company_suppliers(company_id: BytesN<32>);
+    set_asset_supplier(asset_id: BytesN<32>, supplier: Address);
+    get_asset_supplier(asset_id: BytesN<32>);
+    get_supplier_assets(company_id: BytesN<32>, supplier: Address, start: u32, limit: u32);
+    create_vendor_contract(
+        contract_id: BytesN<32>,
+        company_id: BytesN<32>,
+        vendor: Address,
+        document_hash: BytesN<32>,
+        covered_assets: Vec<BytesN<32>>,
+        sla_response_secs: u64,
+        start_date: u64,
+        end_date: u64,
+        auto_renew: bool,
+    );
+    renew_vendor_contract(contract_id: BytesN<32>, new_end_date: u64, document_hash: BytesN<32>);
+    terminate_vendor_contract(contract_id: BytesN<32>);
+    get_vendor_contract(contract_id: BytesN<32>);
+    get_company_vendor_contracts(company_id: BytesN<32>);
+    get_expiring_vendor_contracts(company_id: BytesN<32>, window: u64);
+    create_shipment(
+        shipment_id: BytesN<32>,
+        asset_ids: Vec<BytesN<32>>,
+        origin_branch: BytesN<32>,
+        destination_branch: BytesN<32>,
+        carrier: Address,
+    );
+    handoff_shipment(shipment_id: BytesN<32>, to: Address);
+    confirm_delivery(shipment_id: BytesN<32>);
+    cancel_shipment(shipment_id: BytesN<32>);
+    get_shipment(shipment_id: BytesN<32>);
+    get_asset_shipment(asset_id: BytesN<32>);
+    record_location(
+        asset_id: BytesN<32>,
+        building: String,
+        room: String,
+        geo: location::GeoData,
+        reporter: Address,
+    );
+    get_current_location(asset_id: BytesN<32>);
+    get_location_history(asset_id: BytesN<32>, start: u32, limit: u32);
+    create_cost_center(company_id: BytesN<32>, cost_center_id: u64, name: String);
+    get_cost_center(company_id: BytesN<32>, cost_center_id: u64);
+    get_company_cost_centers(company_id: BytesN<32>);
+    set_asset_allocation(asset_id: BytesN<32>, allocations: Vec<cost_center::Allocation>);
+    get_asset_allocation(asset_id: BytesN<32>);
+    get_cost_center_share(company_id: BytesN<32>, cost_center_id: u64);
+    get_cost_center_shares(company_id: BytesN<32>);
+    set_reporting_currency(branch_id: BytesN<32>, currency: currency::Currency);
+    get_reporting_currency(branch_id: BytesN<32>);
+    set_fx_feeder(feeder: Address);
+    set_fx_rate(source: Address, base: Symbol, quote: Symbol, rate: i128);
+    get_fx_rate(base: Symbol, quote: Symbol);
+    convert_amount(amount: i128, from: currency::Currency, to: currency::Currency);
+    get_branch_value(branch_id: BytesN<32>);
+    get_company_value(company_id: BytesN<32>, currency: currency::Currency);
+    set_category_price_feed(company_id: BytesN<32>, category_id: u64, feed: oracle::PriceFeed);
+    get_category_price_feed(company_id: BytesN<32>, category_id: u64);
+    revalue_asset(asset_id: BytesN<32>);
+    get_branch_stats(branch_id: BytesN<32>);
+    get_global_stats();
+    get_assets_by_owner(owner: Address, start: u32, limit: u32);
+    count_assets_by_owner(owner: Address);
+    set_reservation_approval(branch_id: BytesN<32>, required: bool);
+    get_reservation_approval(branch_id: BytesN<32>);
+    reserve(asset_id: BytesN<32>, from: u64, to: u64, requester: Address);
+    decide_reservation(reservation_id: u64, approve: bool);
+    cancel_reservation(reservation_id: u64);
+    check_out(asset_id: BytesN<32>, holder: Address);
+    check_in(asset_id: BytesN<32>);
+    force_check_in(asset_id: BytesN<32>, branch_id: BytesN<32>);
+    get_reservation(reservation_id: u64);
+    get_reservations(asset_id: BytesN<32>, window: reservation::Window);
+}
@@ -0,0 +1,168 @@
+use assetsup::{
+    asset::Asset,
+    branch::Branch,
+    currency::Currency,
+    types::{AssetStatus, AssetType},
+};
+use serde::{Deserialize, Serialize};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, TryFromVal, xdr::ScVal};
+use stellar_strkey::Strkey;
+
+use crate::Error;
+
+/// Off-chain form of `asset::Asset`. Ids are hex, addresses strkeys.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AssetRecord {
+    pub id: std::string::String,
+    pub company_id: std::string::String,
+    pub name: std::string::String,
+    #[serde(with = "AssetTypeDef")]
+    pub asset_type: AssetType,
+    pub category_id: u64,
+    pub branch_id: u64,
+    pub department_id: u64,
+    #[serde(with = "AssetStatusDef")]
+    pub status: AssetStatus,
+    pub purchase_date: u64,
+    pub purchase_cost: i128,
+    pub current_value: i128,
+    pub currency: CurrencyRecord,
+    pub warranty_expiry: u64,
+    pub stellar_token_id: std::string::String,
+    pub owner: std::string::String,
+}
+
+/// Off-chain form of `branch::Branch`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BranchRecord {
+    pub id: std::string::String,
+    pub company_id: std::string::String,
+    pub name: std::string::String,
+    pub location: std::string::String,
+    pub admin: std::string::String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CurrencyRecord {
+    pub code: std::string::String,
+    pub scale: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "AssetType")]
+enum AssetTypeDef {
+    Physical,
+    Digital,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "AssetStatus")]
+enum AssetStatusDef {
+    Active,
+    InMaintenance,
+    InTransit,
+    Disposed,
+}
+
+impl AssetRecord {
+    pub fn from_contract(a: &Asset) -> Self {
+        AssetRecord {
+            id: hex::encode(a.id.to_array()),
+            company_id: hex::encode(a.company_id.to_array()),
+            name: a.name.to_string(),
+            asset_type: a.asset_type.clone(),
+            category_id: a.category_id,
+            branch_id: a.branch_id,
+            department_id: a.department_id,
+            status: a.status.clone(),
+            purchase_date: a.purchase_date,
+            purchase_cost: a.purchase_cost,
+            current_value: a.current_value,
+            currency: CurrencyRecord::from_contract(&a.currency),
+            warranty_expiry: a.warranty_expiry,
+            stellar_token_id: hex::encode(a.stellar_token_id.to_array()),
+            owner: a.owner.to_string().to_string(),
+        }
+    }
+
+    pub fn to_contract(&self, env: &Env) -> Result<Asset, Error> {
+        Ok(Asset {
+            id: parse_id(env, &self.id)?,
+            company_id: parse_id(env, &self.company_id)?,
+            name: String::from_str(env, &self.name),
+            asset_type: self.asset_type.clone(),
+            category_id: self.category_id,
+            branch_id: self.branch_id,
+            department_id: self.department_id,
+            status: self.status.clone(),
+            purchase_date: self.purchase_date,
+            purchase_cost: self.purchase_cost,
+            current_value: self.current_value,
+            currency: self.currency.to_contract(env)?,
+            warranty_expiry: self.warranty_expiry,
+            stellar_token_id: parse_id(env, &self.stellar_token_id)?,
+            owner: parse_address(env, &self.owner)?,
+        })
+    }
+}
+
+impl BranchRecord {
+    pub fn from_contract(b: &Branch) -> Self {
+        BranchRecord {
+            id: hex::encode(b.id.to_array()),
+            company_id: hex::encode(b.company_id.to_array()),
+            name: b.name.to_string(),
+            location: b.location.to_string(),
+            admin: b.admin.to_string().to_string(),
+        }
+    }
+
+    pub fn to_contract(&self, env: &Env) -> Result<Branch, Error> {
+        Ok(Branch {
+            id: parse_id(env, &self.id)?,
+            company_id: parse_id(env, &self.company_id)?,
+            name: String::from_str(env, &self.name),
+            location: String::from_str(env, &self.location),
+            admin: parse_address(env, &self.admin)?,
+        })
+    }
+}
+
+impl CurrencyRecord {
+    pub fn from_contract(c: &Currency) -> Self {
+        CurrencyRecord {
+            code: c.code.to_string(),
+            scale: c.scale,
+        }
+    }
+
+    pub fn to_contract(&self, env: &Env) -> Result<Currency, Error> {
+        Ok(Currency {
+            code: Symbol::try_from_val(env, &self.code.as_str())
+                .map_err(|_| Error::InvalidSymbol(self.code.clone()))?,
+            scale: self.scale,
+        })
+    }
+}
+
+/// Parse 64 hex characters into a 32-byte id.
+pub fn parse_id(env: &Env, s: &str) -> Result<BytesN<32>, Error> {
+    let mut bytes = [0u8; 32];
+    hex::decode_to_slice(s, &mut bytes).map_err(|_| Error::InvalidId(s.into()))?;
+    Ok(BytesN::from_array(env, &bytes))
+}
+
+/// Parse a `G...` account or `C...` contract strkey.
+pub fn parse_address(env: &Env, s: &str) -> Result<Address, Error> {
+    let invalid = || Error::InvalidAddress(s.into());
+    let sc = match Strkey::from_string(s).map_err(|_| invalid())? {
+        Strkey::PublicKeyEd25519(key) => {
+            soroban_sdk::xdr::ScAddress::Account(soroban_sdk::xdr::AccountId(
+                soroban_sdk::xdr::PublicKey::PublicKeyTypeEd25519(soroban_sdk::xdr::Uint256(key.0)),
+            ))
+        }
+        Strkey::Contract(c) => soroban_sdk::xdr::ScAddress::Contract(soroban_sdk::xdr::Hash(c.0)),
+        _ => return Err(invalid()),
+    };
+    Address::try_from_val(env, &ScVal::Address(sc)).map_err(|_| invalid())
+}
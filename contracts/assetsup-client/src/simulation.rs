@@ -0,0 +1,225 @@
+use soroban_env_host::{InvocationResources, fees::FeeConfiguration, storage::AccessType};
+use soroban_ledger_snapshot::LedgerSnapshot;
+use soroban_sdk::{
+    Address, Env, Symbol, TryFromVal, Val, Vec,
+    testutils::EnvTestConfig,
+    xdr::{
+        AccountId, ContractDataDurability, ContractExecutable, ExtensionPoint, InvokeContractArgs,
+        LedgerEntry, LedgerEntryData, LedgerFootprint, LedgerKey, LedgerKeyContractCode,
+        LedgerKeyContractData, Limits, ScVal, SorobanAddressCredentials, SorobanAuthorizationEntry,
+        SorobanCredentials, SorobanResources, SorobanTransactionData, WriteXdr,
+    },
+};
+
+use crate::{Error, Snapshot};
+
+/// Instructions added to the metered count by default. Simulation runs the
+/// contract natively, so instantiating and running its Wasm is not metered.
+pub const DEFAULT_INSTRUCTION_LEEWAY: u32 = 25_000_000;
+
+/// Result of running an invocation against a ledger snapshot.
+pub struct Simulation {
+    /// Footprint, resources and resource fee for the transaction.
+    pub transaction_data: SorobanTransactionData,
+    /// Authorization entries the call needs. Entries for the source account
+    /// use its signature on the transaction; the rest carry the signer's
+    /// nonce and must be signed before submission.
+    pub auth: std::vec::Vec<SorobanAuthorizationEntry>,
+    /// Ledger state after the call, to simulate the next one against.
+    pub snapshot: Snapshot,
+}
+
+impl Simulation {
+    /// Resource fee in stroops, to be paid on top of the inclusion fee.
+    pub fn resource_fee(&self) -> i64 {
+        self.transaction_data.resource_fee
+    }
+}
+
+/// Pubnet rates of 2024-12-11, the ones soroban-sdk's `CostEstimate::fee`
+/// charges.
+fn pubnet_fees() -> (FeeConfiguration, i64, i64) {
+    let config = FeeConfiguration {
+        fee_per_instruction_increment: 25,
+        fee_per_read_entry: 6250,
+        fee_per_write_entry: 10000,
+        fee_per_read_1kb: 1786,
+        fee_per_write_1kb: 12000,
+        fee_per_historical_1kb: 16235,
+        fee_per_contract_event_1kb: 10000,
+        fee_per_transaction_size_1kb: 1624,
+    };
+    (config, 2103, 4206)
+}
+
+fn failed(e: impl std::fmt::Debug) -> Error {
+    Error::Simulation(format!("{e:?}"))
+}
+
+/// Run `args` as `source` against `snapshot` with the contract compiled into
+/// this crate standing in for the deployed Wasm.
+pub(crate) fn run(
+    snapshot: &Snapshot,
+    source: AccountId,
+    args: &InvokeContractArgs,
+    instruction_leeway: u32,
+) -> Result<Simulation, Error> {
+    let ledger = snapshot.ledger();
+    let instance_key = LedgerKey::ContractData(LedgerKeyContractData {
+        contract: args.contract_address.clone(),
+        key: ScVal::LedgerKeyContractInstance,
+        durability: ContractDataDurability::Persistent,
+    });
+    let find = |key: &LedgerKey| {
+        ledger
+            .ledger_entries
+            .iter()
+            .find(|(k, _)| k.as_ref() == key)
+            .map(|(_, entry)| entry.clone())
+    };
+    let instance = find(&instance_key)
+        .ok_or_else(|| Error::Simulation("contract is not deployed in the snapshot".into()))?;
+    let code_key = match &instance.0.data {
+        LedgerEntryData::ContractData(d) => match &d.val {
+            ScVal::ContractInstance(i) => match &i.executable {
+                ContractExecutable::Wasm(hash) => {
+                    LedgerKey::ContractCode(LedgerKeyContractCode { hash: hash.clone() })
+                }
+                ContractExecutable::StellarAsset => {
+                    return Err(Error::Simulation("contract is a Stellar asset".into()));
+                }
+            },
+            _ => return Err(Error::Simulation("malformed contract instance".into())),
+        },
+        _ => return Err(Error::Simulation("malformed contract instance".into())),
+    };
+    // The Wasm is not loaded, but the network reads it
+    let code_bytes = match find(&code_key) {
+        Some((entry, _)) => entry.to_xdr(Limits::none()).map_err(|_| Error::Xdr)?.len() as u32,
+        None => 0,
+    };
+
+    let mut env = Env::from_ledger_snapshot(ledger.clone());
+    env.set_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    let host = env.host();
+    host.set_source_account(source).map_err(failed)?;
+    env.mock_all_auths();
+
+    let contract = Address::try_from_val(&env, &ScVal::Address(args.contract_address.clone()))
+        .map_err(|_| Error::Conversion)?;
+    env.register_at(&contract, assetsup::AssetUpContract, ());
+    // Registration wrote a native instance; only the call goes in the footprint
+    host.with_mut_storage(|s| {
+        s.footprint = Default::default();
+        Ok(())
+    })
+    .map_err(failed)?;
+
+    let mut call_args = Vec::<Val>::new(&env);
+    for arg in args.args.iter() {
+        call_args.push_back(Val::try_from_val(&env, arg).map_err(|_| Error::Conversion)?);
+    }
+    let function = Symbol::try_from_val(&env, &args.function_name.to_utf8_string_lossy().as_str())
+        .map_err(|_| Error::Conversion)?;
+    match env.try_invoke_contract::<Val, soroban_sdk::Error>(&contract, &function, call_args) {
+        Ok(Ok(_)) => {}
+        Ok(Err(e)) => return Err(failed(e)),
+        Err(Ok(e)) => return Err(failed(e)),
+        Err(Err(e)) => return Err(failed(e)),
+    }
+
+    let budget = host.budget_cloned();
+    let accessed = host
+        .with_mut_storage(|s| {
+            Ok(s.footprint
+                .0
+                .iter(&budget)?
+                .map(|(k, access)| (k.as_ref().clone(), *access))
+                .collect::<std::vec::Vec<_>>())
+        })
+        .map_err(failed)?;
+    let (mut read_only, mut read_write) = (vec![code_key], vec![]);
+    for (key, access) in accessed {
+        match (&key, access) {
+            (LedgerKey::ContractCode(_), _) => {}
+            (_, AccessType::ReadOnly) => read_only.push(key),
+            (_, AccessType::ReadWrite) => read_write.push(key),
+        }
+    }
+    read_only.sort();
+    read_write.sort();
+
+    let metered = env.cost_estimate().resources();
+    let resources = InvocationResources {
+        instructions: metered.instructions + instruction_leeway as i64,
+        read_entries: metered.read_entries + 1,
+        read_bytes: metered.read_bytes + code_bytes,
+        ..metered
+    };
+    let (config, persistent_rent, temporary_rent) = pubnet_fees();
+    let fee = resources.estimate_fees(&config, persistent_rent, temporary_rent);
+
+    let auth = host
+        .get_recorded_auth_payloads()
+        .map_err(failed)?
+        .into_iter()
+        .map(|p| SorobanAuthorizationEntry {
+            credentials: match (p.address, p.nonce) {
+                (Some(address), Some(nonce)) => {
+                    SorobanCredentials::Address(SorobanAddressCredentials {
+                        address,
+                        nonce,
+                        signature_expiration_ledger: 0,
+                        signature: ScVal::Void,
+                    })
+                }
+                _ => SorobanCredentials::SourceAccount,
+            },
+            root_invocation: p.invocation,
+        })
+        .collect();
+
+    let transaction_data = SorobanTransactionData {
+        ext: ExtensionPoint::V0,
+        resources: SorobanResources {
+            footprint: LedgerFootprint {
+                read_only: read_only.try_into().map_err(|_| Error::Xdr)?,
+                read_write: read_write.try_into().map_err(|_| Error::Xdr)?,
+            },
+            instructions: u32::try_from(resources.instructions).unwrap_or(u32::MAX),
+            read_bytes: resources.read_bytes,
+            write_bytes: resources.write_bytes,
+        },
+        resource_fee: fee.total,
+    };
+
+    Ok(Simulation {
+        transaction_data,
+        auth,
+        snapshot: Snapshot::from_ledger(after(&env, ledger, &instance_key, &instance)),
+    })
+}
+
+/// State of `env` after the call, with the contract instance put back to
+/// the deployed one and the native stand-in's code entry left out.
+fn after(
+    env: &Env,
+    before: &LedgerSnapshot,
+    instance_key: &LedgerKey,
+    instance: &(Box<LedgerEntry>, Option<u32>),
+) -> LedgerSnapshot {
+    let mut ledger = env.to_ledger_snapshot();
+    let kept = |key: &LedgerKey| {
+        !matches!(key, LedgerKey::ContractCode(_))
+            || before.ledger_entries.iter().any(|(k, _)| k.as_ref() == key)
+    };
+    ledger.ledger_entries.retain(|(k, _)| kept(k));
+    for (key, entry) in ledger.ledger_entries.iter_mut() {
+        if key.as_ref() == instance_key {
+            *entry = instance.clone();
+        }
+    }
+    ledger
+}
@@ -0,0 +1,132 @@
+use std::path::Path;
+
+use assetsup::{asset, branch};
+use soroban_ledger_snapshot::LedgerSnapshot;
+use soroban_sdk::{
+    Env, TryFromVal, Val,
+    xdr::{ContractDataDurability, LedgerEntryData, ScVal},
+};
+
+use crate::{AssetRecord, BranchRecord, Error};
+
+/// Contract records decoded from a ledger snapshot, either a bare
+/// `LedgerSnapshot` or a soroban-sdk test snapshot that wraps one under
+/// `ledger`.
+pub struct Snapshot {
+    env: Env,
+    ledger: LedgerSnapshot,
+    entries: Vec<(ScVal, ScVal)>,
+}
+
+impl Snapshot {
+    pub fn read_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let read_err =
+            |e: &dyn std::fmt::Display| Error::Snapshot(format!("{}: {e}", path.display()));
+        let text = std::fs::read_to_string(path).map_err(|e| read_err(&e))?;
+        Self::from_json(&text).map_err(|e| match e {
+            Error::Snapshot(msg) => read_err(&msg),
+            e => e,
+        })
+    }
+
+    pub fn from_json(text: &str) -> Result<Self, Error> {
+        let mut json: serde_json::Value =
+            serde_json::from_str(text).map_err(|e| Error::Snapshot(e.to_string()))?;
+        if let Some(ledger) = json.get_mut("ledger") {
+            json = ledger.take();
+        }
+        let ledger: LedgerSnapshot =
+            serde_json::from_value(json).map_err(|e| Error::Snapshot(e.to_string()))?;
+        Ok(Self::from_ledger(ledger))
+    }
+
+    pub fn from_ledger(ledger: LedgerSnapshot) -> Self {
+        let entries = ledger
+            .ledger_entries
+            .iter()
+            .filter_map(|(_, (entry, _))| match &entry.data {
+                LedgerEntryData::ContractData(d)
+                    if d.durability == ContractDataDurability::Persistent =>
+                {
+                    Some((d.key.clone(), d.val.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+        Snapshot {
+            env: Env::default(),
+            ledger,
+            entries,
+        }
+    }
+
+    /// Ledger the records were decoded from.
+    pub fn ledger(&self) -> &LedgerSnapshot {
+        &self.ledger
+    }
+
+    /// Write the ledger as a bare `LedgerSnapshot`.
+    pub fn write_file(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        self.ledger
+            .write_file(path)
+            .map_err(|e| Error::Snapshot(format!("{}: {e}", path.display())))
+    }
+
+    /// Values stored under `variant` keys holding `ids` 32-byte ids, such as
+    /// `Asset(id)` or `Branch(company_id, id)`, decoded as `V`.
+    ///
+    /// Keys are matched on their XDR shape first: decoding a value of the
+    /// wrong shape aborts inside the host instead of returning an error.
+    fn records<V: TryFromVal<Env, Val>>(
+        &self,
+        variant: &str,
+        ids: usize,
+    ) -> impl Iterator<Item = V> + '_ {
+        let variant = variant.to_string();
+        self.entries.iter().filter_map(move |(key, val)| {
+            let ScVal::Vec(Some(parts)) = key else {
+                return None;
+            };
+            let [ScVal::Symbol(name), fields @ ..] = parts.as_slice() else {
+                return None;
+            };
+            let is_id = |field: &ScVal| matches!(field, ScVal::Bytes(b) if b.len() == 32);
+            if name.0.as_slice() != variant.as_bytes()
+                || fields.len() != ids
+                || !fields.iter().all(is_id)
+            {
+                return None;
+            }
+            let val = Val::try_from_val(&self.env, val).ok()?;
+            V::try_from_val(&self.env, &val).ok()
+        })
+    }
+
+    pub fn assets(&self) -> Vec<AssetRecord> {
+        self.records::<asset::Entry>("Asset", 1)
+            .map(|e| AssetRecord::from_contract(&e.asset))
+            .collect()
+    }
+
+    pub fn branches(&self) -> Vec<BranchRecord> {
+        self.records::<branch::Branch>("Branch", 2)
+            .map(|b| BranchRecord::from_contract(&b))
+            .collect()
+    }
+
+    /// Asset with the given hex id.
+    pub fn asset(&self, id: &str) -> Option<AssetRecord> {
+        self.assets()
+            .into_iter()
+            .find(|a| a.id.eq_ignore_ascii_case(id))
+    }
+
+    /// Branch with the given hex id.
+    pub fn branch(&self, id: &str) -> Option<BranchRecord> {
+        self.branches()
+            .into_iter()
+            .find(|b| b.id.eq_ignore_ascii_case(id))
+    }
+}
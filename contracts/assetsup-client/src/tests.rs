@@ -0,0 +1,396 @@
+extern crate std;
+
+use assetsup::{
+    AssetUpContract, AssetUpContractClient,
+    types::{AssetStatus, AssetType},
+};
+use soroban_sdk::{
+    Address, Env, String, Symbol, TryFromVal, Val, Vec,
+    testutils::Address as _,
+    xdr::{
+        HostFunction, LedgerKey, Limits, MuxedAccount, OperationBody, ReadXdr, ScAddress, ScVal,
+        SorobanCredentials, TransactionEnvelope, TransactionExt,
+    },
+};
+
+use crate::{AssetRecord, AssetUpClient, BranchRecord, Error, METHODS, Snapshot, csv, records};
+
+const CONTRACT: &str = "CA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAXE";
+const ACCOUNT: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+
+fn asset_json() -> &'static str {
+    r#"{
+        "id": "0101010101010101010101010101010101010101010101010101010101010101",
+        "company_id": "0202020202020202020202020202020202020202020202020202020202020202",
+        "name": "Forklift",
+        "asset_type": "Physical",
+        "category_id": 1,
+        "branch_id": 3,
+        "department_id": 0,
+        "status": "Active",
+        "purchase_date": 1700000000,
+        "purchase_cost": 2500000,
+        "current_value": 2000000,
+        "currency": { "code": "USD", "scale": 2 },
+        "warranty_expiry": 1800000000,
+        "stellar_token_id": "0000000000000000000000000000000000000000000000000000000000000000",
+        "owner": "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H"
+    }"#
+}
+
+#[test]
+fn test_builders_cover_every_contract_method() {
+    let source = include_str!("../../assetsup/src/lib.rs");
+    let body = &source[source.find("impl AssetUpContract").unwrap()..];
+    let contract_methods: std::vec::Vec<&str> = body
+        .lines()
+        .filter_map(|l| l.strip_prefix("    pub fn "))
+        .map(|l| &l[..l.find(['(', '<']).unwrap()])
+        .collect();
+    assert_eq!(METHODS, contract_methods.as_slice());
+}
+
+#[test]
+fn test_asset_record_round_trip() {
+    let env = Env::default();
+    let record: AssetRecord = serde_json::from_str(asset_json()).unwrap();
+    assert_eq!(record.asset_type, AssetType::Physical);
+    assert_eq!(record.status, AssetStatus::Active);
+
+    let asset = record.to_contract(&env).unwrap();
+    assert_eq!(asset.name, String::from_str(&env, "Forklift"));
+    assert_eq!(asset.owner.to_string(), String::from_str(&env, ACCOUNT));
+    assert_eq!(AssetRecord::from_contract(&asset), record);
+
+    let json = serde_json::to_string(&record).unwrap();
+    assert_eq!(serde_json::from_str::<AssetRecord>(&json).unwrap(), record);
+}
+
+#[test]
+fn test_record_conversion_errors() {
+    let env = Env::default();
+    let mut record: AssetRecord = serde_json::from_str(asset_json()).unwrap();
+    record.id = "abc".into();
+    assert_eq!(
+        record.to_contract(&env),
+        Err(Error::InvalidId("abc".into()))
+    );
+
+    let branch = BranchRecord {
+        id: "03".repeat(32),
+        company_id: "02".repeat(32),
+        name: "Depot".into(),
+        location: "Lagos".into(),
+        admin: "GNOTAKEY".into(),
+    };
+    assert_eq!(
+        branch.to_contract(&env),
+        Err(Error::InvalidAddress("GNOTAKEY".into()))
+    );
+    assert!(AssetUpClient::new(ACCOUNT).is_err());
+}
+
+#[test]
+fn test_envelope_decodes_to_invocation() {
+    let client = AssetUpClient::new(CONTRACT).unwrap();
+    let record: AssetRecord = serde_json::from_str(asset_json()).unwrap();
+    let asset = record.to_contract(client.env()).unwrap();
+
+    let xdr = client
+        .register_asset(&asset)
+        .fee(1_000_000)
+        .time_bounds(0, 1_900_000_000)
+        .to_xdr_base64(ACCOUNT, 42)
+        .unwrap();
+
+    let TransactionEnvelope::Tx(envelope) =
+        TransactionEnvelope::from_xdr_base64(&xdr, Limits::none()).unwrap()
+    else {
+        panic!("expected a v1 envelope");
+    };
+    assert!(envelope.signatures.is_empty());
+    assert_eq!(envelope.tx.fee, 1_000_000);
+    assert_eq!(envelope.tx.seq_num.0, 42);
+    assert!(matches!(
+        envelope.tx.source_account,
+        MuxedAccount::Ed25519(_)
+    ));
+
+    let OperationBody::InvokeHostFunction(op) = &envelope.tx.operations[0].body else {
+        panic!("expected an invocation");
+    };
+    let HostFunction::InvokeContract(args) = &op.host_function else {
+        panic!("expected a contract call");
+    };
+    assert_eq!(
+        args.function_name.0.to_utf8_string().unwrap(),
+        "register_asset"
+    );
+    assert!(matches!(args.contract_address, ScAddress::Contract(_)));
+    assert_eq!(args.args.len(), 1);
+    assert!(matches!(args.args[0], ScVal::Map(_)));
+}
+
+#[test]
+fn test_invocations_execute_against_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AssetUpContract, ());
+    let contract = AssetUpContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    let client = AssetUpClient::new(&contract_id.to_string().to_string()).unwrap();
+    let admin_arg = records::parse_address(client.env(), &admin.to_string().to_string()).unwrap();
+    let company_id = records::parse_id(client.env(), &"07".repeat(32)).unwrap();
+
+    for invocation in [
+        client.initialize(&admin_arg),
+        client.create_company(
+            &company_id,
+            &String::from_str(client.env(), "Acme"),
+            &admin_arg,
+        ),
+    ] {
+        let invoke = invocation.invoke_args().unwrap();
+        let mut args = Vec::<Val>::new(&env);
+        for arg in invoke.args.iter() {
+            args.push_back(Val::try_from_val(&env, arg).unwrap());
+        }
+        let function = Symbol::new(&env, invocation.function());
+        env.invoke_contract::<Val>(&contract_id, &function, args);
+    }
+
+    assert_eq!(contract.get_admin(), admin);
+    let company = contract.get_company(&records::parse_id(&env, &"07".repeat(32)).unwrap());
+    assert_eq!(company.name, String::from_str(&env, "Acme"));
+}
+
+#[test]
+fn test_simulation_records_footprint_resources_and_auth() {
+    let env = Env::default();
+    env.register_at(
+        &records::parse_address(&env, CONTRACT).unwrap(),
+        AssetUpContract,
+        (),
+    );
+    let deployed = Snapshot::from_ledger(env.to_ledger_snapshot());
+
+    let client = AssetUpClient::new(CONTRACT).unwrap();
+    let admin = records::parse_address(client.env(), ACCOUNT).unwrap();
+    let init = client.initialize(&admin);
+    let simulation = init.simulate(&deployed, ACCOUNT).unwrap();
+    let resources = &simulation.transaction_data.resources;
+    assert!(
+        resources
+            .footprint
+            .read_only
+            .iter()
+            .any(|k| matches!(k, LedgerKey::ContractCode(_)))
+    );
+    assert_eq!(resources.footprint.read_write.len(), 1);
+    assert!(resources.instructions > crate::DEFAULT_INSTRUCTION_LEEWAY);
+    assert!(resources.write_bytes > 0 && simulation.resource_fee() > 0);
+    // The admin signs as the transaction source
+    assert_eq!(simulation.auth.len(), 1);
+    assert_eq!(
+        simulation.auth[0].credentials,
+        SorobanCredentials::SourceAccount
+    );
+
+    let xdr = init
+        .clone()
+        .simulated(&simulation)
+        .to_xdr_base64(ACCOUNT, 1)
+        .unwrap();
+    let TransactionEnvelope::Tx(envelope) =
+        TransactionEnvelope::from_xdr_base64(&xdr, Limits::none()).unwrap()
+    else {
+        panic!("expected a v1 envelope");
+    };
+    assert_eq!(
+        envelope.tx.fee as i64,
+        crate::DEFAULT_FEE as i64 + simulation.resource_fee()
+    );
+    assert_eq!(
+        envelope.tx.ext,
+        TransactionExt::V1(simulation.transaction_data.clone())
+    );
+    let OperationBody::InvokeHostFunction(op) = &envelope.tx.operations[0].body else {
+        panic!("expected an invocation");
+    };
+    assert_eq!(op.auth.as_slice(), simulation.auth.as_slice());
+
+    // Signers other than the source get address credentials with a nonce
+    let other = stellar_strkey::ed25519::PublicKey([7; 32]).to_string();
+    let company = client
+        .create_company(
+            &records::parse_id(client.env(), &"07".repeat(32)).unwrap(),
+            &String::from_str(client.env(), "Acme"),
+            &admin,
+        )
+        .simulate(&simulation.snapshot, &other)
+        .unwrap();
+    let SorobanCredentials::Address(credentials) = &company.auth[0].credentials else {
+        panic!("expected address credentials");
+    };
+    assert_eq!(credentials.address, ScAddress::from(&admin));
+
+    // The state carries over, and contract errors are reported
+    assert!(matches!(
+        init.simulate(&simulation.snapshot, ACCOUNT),
+        Err(Error::Simulation(_))
+    ));
+    let empty = Snapshot::from_ledger(Default::default());
+    assert!(matches!(
+        init.simulate(&empty, ACCOUNT),
+        Err(Error::Simulation(_))
+    ));
+}
+
+#[test]
+fn test_snapshot_decodes_assets_and_branches() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AssetUpContract, ());
+    let contract = AssetUpContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    contract.initialize(&admin);
+
+    let mut record: AssetRecord = serde_json::from_str(asset_json()).unwrap();
+    record.owner = admin.to_string().to_string();
+    let asset = record.to_contract(&env).unwrap();
+    contract.create_company(&asset.company_id, &String::from_str(&env, "Acme"), &admin);
+    contract.create_category(
+        &asset.company_id,
+        &asset.category_id,
+        &String::from_str(&env, "Vehicles"),
+        &0,
+        &assetsup::category::DepreciationSettings {
+            method: assetsup::types::DepreciationMethod::None,
+            useful_life_months: 0,
+            salvage_value_bps: 0,
+        },
+    );
+    contract.register_asset(&asset);
+    let branch = BranchRecord {
+        id: "03".repeat(32),
+        company_id: record.company_id.clone(),
+        name: "Depot".into(),
+        location: "Lagos".into(),
+        admin: record.owner.clone(),
+    };
+    let b = branch.to_contract(&env).unwrap();
+    contract.create_branch(&b.company_id, &b.id, &b.name, &b.location, &b.admin);
+
+    let json = serde_json::to_string(&env.to_ledger_snapshot()).unwrap();
+    let snapshot = Snapshot::from_json(&json).unwrap();
+    assert_eq!(snapshot.assets(), std::slice::from_ref(&record));
+    assert_eq!(snapshot.branches(), std::slice::from_ref(&branch));
+    assert_eq!(snapshot.asset(&record.id.to_uppercase()), Some(record));
+    assert_eq!(snapshot.branch(&branch.id), Some(branch));
+    assert_eq!(snapshot.asset(&"09".repeat(32)), None);
+
+    // Test snapshots wrap the ledger under `ledger`
+    let wrapped = format!(r#"{{"generators": {{}}, "ledger": {json}}}"#);
+    assert_eq!(Snapshot::from_json(&wrapped).unwrap().branches().len(), 1);
+    assert!(matches!(Snapshot::from_json("[]"), Err(Error::Snapshot(_))));
+}
+
+#[test]
+fn test_csv_round_trips_records() {
+    let mut record: AssetRecord = serde_json::from_str(asset_json()).unwrap();
+    record.name = "Forklift, \"yard\" 2".into();
+    let sheet = csv::write_assets(std::slice::from_ref(&record));
+    assert!(sheet.starts_with("id,company_id,name,asset_type,"));
+    assert!(sheet.contains(",\"Forklift, \"\"yard\"\" 2\",Physical,"));
+
+    // Branch and token ids are assigned on chain, not imported
+    let imported = csv::read_assets(&sheet, &record.company_id).unwrap();
+    record.branch_id = 0;
+    assert_eq!(imported, vec![record]);
+
+    let branch = BranchRecord {
+        id: "03".repeat(32),
+        company_id: "02".repeat(32),
+        name: "Depot".into(),
+        location: "Lagos\nIkeja".into(),
+        admin: ACCOUNT.into(),
+    };
+    assert_eq!(
+        csv::write_branches(&[branch]),
+        format!(
+            "id,company_id,name,location,admin\n{},{},Depot,\"Lagos\nIkeja\",{ACCOUNT}\n",
+            "03".repeat(32),
+            "02".repeat(32)
+        )
+    );
+}
+
+#[test]
+fn test_csv_import_derives_ids_and_defaults() {
+    let company = "02".repeat(32);
+    let sheet = format!(
+        "\u{feff}Tag,Name,Asset_Type,Category_Id,Purchase_Date,Purchase_Cost,Currency_Code,Currency_Scale,Owner,Notes\r\n\
+         FL-1,Forklift,physical,4,1700000000,2500000,usd,2,{ACCOUNT},yard\r\n\
+         \r\n\
+         FL-2,Forklift,Physical,4,1700000000,2500000,USD,2,{ACCOUNT},\r\n"
+    );
+    let assets = csv::read_assets(&sheet, &company).unwrap();
+    assert_eq!(assets.len(), 2);
+    assert_eq!(assets[0].id, csv::asset_id(&company, "FL-1").unwrap());
+    assert_ne!(assets[0].id, assets[1].id);
+    assert_eq!(assets[0].status, AssetStatus::Active);
+    assert_eq!(assets[0].asset_type, AssetType::Physical);
+    assert_eq!(assets[0].current_value, 2500000);
+    assert_eq!(assets[0].currency.code, "USD");
+    assert_eq!(assets[0].category_id, 4);
+    assert_eq!(assets[0].company_id, company);
+
+    // Same tag, same company: same id on every import
+    assert_eq!(csv::read_assets(&sheet, &company).unwrap(), assets);
+    let other = csv::asset_id(&"05".repeat(32), "FL-1").unwrap();
+    assert_ne!(other, assets[0].id);
+}
+
+#[test]
+fn test_csv_import_rejects_bad_rows() {
+    let company = "02".repeat(32);
+    let header = "tag,name,asset_type,category_id,purchase_date,purchase_cost,currency_code,\
+                  currency_scale,owner";
+    let row = |cost: &str| format!("FL-1,Forklift,Physical,1,1700000000,{cost},USD,2,{ACCOUNT}");
+
+    let sheet = format!("{header}\n{}\n{}\n", row("1"), row("lots"));
+    assert_eq!(
+        csv::read_assets(&sheet, &company),
+        Err(Error::Csv {
+            line: 3,
+            message: "invalid purchase_cost: lots".into()
+        })
+    );
+    let sheet = format!("{header}\n{}\n", row(""));
+    assert!(matches!(
+        csv::read_assets(&sheet, &company),
+        Err(Error::Csv { line: 2, .. })
+    ));
+    // Without a category column, rather than filing under the root category
+    let sheet = format!(
+        "tag,name,asset_type,purchase_date,purchase_cost,currency_code,currency_scale,owner\n\
+         FL-1,Forklift,Physical,1700000000,1,USD,2,{ACCOUNT}\n"
+    );
+    assert_eq!(
+        csv::read_assets(&sheet, &company),
+        Err(Error::Csv {
+            line: 2,
+            message: "missing category_id".into()
+        })
+    );
+    let sheet = format!("{header}\n\"FL-1,Forklift\n");
+    assert!(matches!(
+        csv::read_assets(&sheet, &company),
+        Err(Error::Csv { line: 2, .. })
+    ));
+    assert!(matches!(
+        csv::read_assets(&format!("{header}\n{}\n", row("1")), "xyz"),
+        Err(Error::InvalidId(_))
+    ));
+}
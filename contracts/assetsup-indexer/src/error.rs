@@ -0,0 +1,25 @@
+use core::fmt;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    // Input is not valid JSON, base64 or XDR
+    Parse(String),
+    // Event of a known kind whose payload does not match it
+    Malformed(&'static str),
+    // Event sequence skips ahead of the next expected number
+    Gap { expected: u64, found: u64 },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(s) => write!(f, "unparseable input: {s}"),
+            Error::Malformed(kind) => write!(f, "malformed {kind} event"),
+            Error::Gap { expected, found } => {
+                write!(f, "missing events {expected}..{found}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,179 @@
+use assetsup::events as names;
+use soroban_sdk::xdr::{ScMap, ScVal};
+
+use crate::{Error, xdr};
+
+/// Contract event with ids as hex and addresses as strkeys.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Event {
+    CompanyCreated {
+        company_id: String,
+        name: String,
+        admin: String,
+    },
+    CompanyAdminChanged {
+        company_id: String,
+        from: String,
+        to: String,
+    },
+    BranchCreated {
+        branch_id: String,
+        company_id: String,
+        name: String,
+        admin: String,
+    },
+    AssetRegistered {
+        asset_id: String,
+        company_id: String,
+        name: String,
+        asset_type: String,
+        status: String,
+        owner: String,
+        purchase_cost: i128,
+        current_value: i128,
+        currency: String,
+        currency_scale: u32,
+        /// Hex token id, all zeros when registered untokenized
+        stellar_token_id: String,
+    },
+    AssetTransferred {
+        asset_id: String,
+        from: String,
+        to: String,
+    },
+    AssetStatusChanged {
+        asset_id: String,
+        from: String,
+        to: String,
+    },
+    AssetTokenized {
+        asset_id: String,
+        token_id: String,
+    },
+    AssetRevalued {
+        asset_id: String,
+        current_value: i128,
+    },
+    AssetLinked {
+        asset_id: String,
+        branch_id: String,
+    },
+    AssetUnlinked {
+        asset_id: String,
+        branch_id: String,
+    },
+}
+
+/// Decoded event with its position in the contract's event sequence.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Record {
+    pub seq: u64,
+    /// Ledger the event was published in, when the source reports it.
+    pub ledger: Option<u32>,
+    pub event: Event,
+}
+
+impl Record {
+    /// Decode an event from its topics and data. Events this crate does not
+    /// know, such as token transfers, decode to `None`.
+    pub fn decode(
+        topics: &[ScVal],
+        data: &ScVal,
+        ledger: Option<u32>,
+    ) -> Result<Option<Self>, Error> {
+        let Some(name) = topics.first().and_then(xdr::symbol) else {
+            return Ok(None);
+        };
+        let kind: &'static str = match name.as_str() {
+            names::COMPANY_CREATED => names::COMPANY_CREATED,
+            names::COMPANY_ADMIN_CHANGED => names::COMPANY_ADMIN_CHANGED,
+            names::BRANCH_CREATED => names::BRANCH_CREATED,
+            names::ASSET_REGISTERED => names::ASSET_REGISTERED,
+            names::ASSET_TRANSFERRED => names::ASSET_TRANSFERRED,
+            names::ASSET_STATUS_CHANGED => names::ASSET_STATUS_CHANGED,
+            names::ASSET_TOKENIZED => names::ASSET_TOKENIZED,
+            names::ASSET_REVALUED => names::ASSET_REVALUED,
+            names::ASSET_LINKED => names::ASSET_LINKED,
+            names::ASSET_UNLINKED => names::ASSET_UNLINKED,
+            _ => return Ok(None),
+        };
+        let ScVal::Map(Some(map)) = data else {
+            return Err(Error::Malformed(kind));
+        };
+        let (seq, event) = decode_payload(kind, map).ok_or(Error::Malformed(kind))?;
+        Ok(Some(Record { seq, ledger, event }))
+    }
+}
+
+fn decode_payload(kind: &str, m: &ScMap) -> Option<(u64, Event)> {
+    let get = |name: &str| xdr::field(m, name);
+    let id = |name: &str| get(name).and_then(xdr::id);
+    let address = |name: &str| get(name).and_then(xdr::address);
+    let string = |name: &str| get(name).and_then(xdr::string);
+    let variant = |name: &str| get(name).and_then(xdr::variant);
+
+    let event = match kind {
+        names::COMPANY_CREATED => Event::CompanyCreated {
+            company_id: id("company_id")?,
+            name: string("name")?,
+            admin: address("admin")?,
+        },
+        names::COMPANY_ADMIN_CHANGED => Event::CompanyAdminChanged {
+            company_id: id("company_id")?,
+            from: address("from")?,
+            to: address("to")?,
+        },
+        names::BRANCH_CREATED => Event::BranchCreated {
+            branch_id: id("branch_id")?,
+            company_id: id("company_id")?,
+            name: string("name")?,
+            admin: address("admin")?,
+        },
+        names::ASSET_REGISTERED => {
+            let ScVal::Map(Some(currency)) = get("currency")? else {
+                return None;
+            };
+            Event::AssetRegistered {
+                asset_id: id("asset_id")?,
+                company_id: id("company_id")?,
+                name: string("name")?,
+                asset_type: variant("asset_type")?,
+                status: variant("status")?,
+                owner: address("owner")?,
+                purchase_cost: get("purchase_cost").and_then(xdr::i128)?,
+                current_value: get("current_value").and_then(xdr::i128)?,
+                currency: xdr::field(currency, "code").and_then(xdr::symbol)?,
+                currency_scale: xdr::field(currency, "scale").and_then(xdr::u32)?,
+                stellar_token_id: id("stellar_token_id")?,
+            }
+        }
+        names::ASSET_TRANSFERRED => Event::AssetTransferred {
+            asset_id: id("asset_id")?,
+            from: address("from")?,
+            to: address("to")?,
+        },
+        names::ASSET_STATUS_CHANGED => Event::AssetStatusChanged {
+            asset_id: id("asset_id")?,
+            from: variant("from")?,
+            to: variant("to")?,
+        },
+        names::ASSET_TOKENIZED => Event::AssetTokenized {
+            asset_id: id("asset_id")?,
+            token_id: id("token_id")?,
+        },
+        names::ASSET_REVALUED => Event::AssetRevalued {
+            asset_id: id("asset_id")?,
+            current_value: get("current_value").and_then(xdr::i128)?,
+        },
+        names::ASSET_LINKED => Event::AssetLinked {
+            asset_id: id("asset_id")?,
+            branch_id: id("branch_id")?,
+        },
+        names::ASSET_UNLINKED => Event::AssetUnlinked {
+            asset_id: id("asset_id")?,
+            branch_id: id("branch_id")?,
+        },
+        _ => return None,
+    };
+    Some((get("seq").and_then(xdr::u64)?, event))
+}
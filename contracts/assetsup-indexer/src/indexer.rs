@@ -0,0 +1,91 @@
+use std::ops::Range;
+
+use crate::{Error, Record, State};
+
+/// Outcome of applying one record.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Applied {
+    Applied,
+    /// Already applied; replays are harmless
+    Duplicate,
+}
+
+/// Applies records strictly in sequence order.
+#[derive(Clone, Debug, Default)]
+pub struct Indexer {
+    state: State,
+    next_seq: u64,
+}
+
+impl Indexer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the state from scratch out of records in any order, with
+    /// repeats allowed. Fails on the first missing sequence number.
+    pub fn replay(records: impl IntoIterator<Item = Record>) -> Result<Self, Error> {
+        let mut indexer = Self::new();
+        indexer.ingest(records)?;
+        Ok(indexer)
+    }
+
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Sequence number of the next record to apply.
+    pub fn next_seq(&self) -> u64 {
+        self.next_seq
+    }
+
+    pub fn apply(&mut self, record: &Record) -> Result<Applied, Error> {
+        if record.seq < self.next_seq {
+            return Ok(Applied::Duplicate);
+        }
+        if record.seq > self.next_seq {
+            return Err(Error::Gap {
+                expected: self.next_seq,
+                found: record.seq,
+            });
+        }
+        self.state.apply(&record.event);
+        self.next_seq += 1;
+        Ok(Applied::Applied)
+    }
+
+    /// Sort a batch by sequence and apply it; returns how many records were
+    /// new. Records before a gap stay applied.
+    pub fn ingest(&mut self, records: impl IntoIterator<Item = Record>) -> Result<usize, Error> {
+        let mut records: Vec<Record> = records.into_iter().collect();
+        records.sort_by_key(|r| r.seq);
+        let mut applied = 0;
+        for record in &records {
+            if self.apply(record)? == Applied::Applied {
+                applied += 1;
+            }
+        }
+        Ok(applied)
+    }
+
+    pub fn to_sql(&self) -> String {
+        self.state.to_sql(self.next_seq)
+    }
+}
+
+/// Sequence ranges missing from `seqs`, counting from `from`, to refetch
+/// before ingesting.
+pub fn gaps(from: u64, seqs: impl IntoIterator<Item = u64>) -> Vec<Range<u64>> {
+    let mut seqs: Vec<u64> = seqs.into_iter().filter(|s| *s >= from).collect();
+    seqs.sort_unstable();
+    seqs.dedup();
+    let mut missing = Vec::new();
+    let mut next = from;
+    for seq in seqs {
+        if seq > next {
+            missing.push(next..seq);
+        }
+        next = seq + 1;
+    }
+    missing
+}
@@ -0,0 +1,23 @@
+//! Indexer for AssetUp contract events.
+//!
+//! Decodes raw event XDR, either `ContractEvent`s or RPC `getEvents`
+//! output, into typed records and projects them into a local state that
+//! exports as a SQLite script. Every contract event carries a gapless
+//! sequence number: the indexer refuses to skip one, ignores repeats and
+//! replays any set of events to the same state regardless of arrival order.
+
+mod error;
+mod event;
+mod indexer;
+mod source;
+mod state;
+mod xdr;
+
+pub use error::Error;
+pub use event::{Event, Record};
+pub use indexer::{Applied, Indexer, gaps};
+pub use source::{RawEvent, decode_contract_event, read_rpc_events};
+pub use state::{AssetRow, BranchRow, CompanyRow, State};
+
+#[cfg(test)]
+mod tests;
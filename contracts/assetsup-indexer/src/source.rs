@@ -0,0 +1,100 @@
+use soroban_sdk::xdr::{
+    ContractEvent, ContractEventBody, ContractEventType, Limits, ReadXdr, ScVal,
+};
+use stellar_strkey::Contract;
+
+use crate::{Error, Record};
+
+/// Event as published, before decoding.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RawEvent {
+    /// `C...` strkey of the publishing contract
+    pub contract_id: String,
+    pub ledger: Option<u32>,
+    pub topics: Vec<ScVal>,
+    pub data: ScVal,
+}
+
+impl RawEvent {
+    pub fn decode(&self) -> Result<Option<Record>, Error> {
+        Record::decode(&self.topics, &self.data, self.ledger)
+    }
+}
+
+fn parse_err(e: impl std::fmt::Display) -> Error {
+    Error::Parse(e.to_string())
+}
+
+/// Decode a base64 `ContractEvent`, as found in transaction meta. Returns
+/// `None` for system and diagnostic events.
+pub fn decode_contract_event(xdr: &str) -> Result<Option<RawEvent>, Error> {
+    let event = ContractEvent::from_xdr_base64(xdr, Limits::none()).map_err(parse_err)?;
+    let (ContractEventType::Contract, Some(contract)) = (&event.type_, &event.contract_id) else {
+        return Ok(None);
+    };
+    let ContractEventBody::V0(body) = event.body;
+    Ok(Some(RawEvent {
+        contract_id: Contract(contract.0).to_string(),
+        ledger: None,
+        topics: body.topics.to_vec(),
+        data: body.data,
+    }))
+}
+
+/// Events of `contract_id` from RPC `getEvents` output: the full JSON-RPC
+/// response, its `result`, or the bare `events` array. Events from failed
+/// calls are dropped.
+pub fn read_rpc_events(json: &str, contract_id: &str) -> Result<Vec<RawEvent>, Error> {
+    let mut value: serde_json::Value = serde_json::from_str(json).map_err(parse_err)?;
+    if let Some(result) = value.get_mut("result") {
+        value = result.take();
+    }
+    if let Some(events) = value.get_mut("events") {
+        value = events.take();
+    }
+    let serde_json::Value::Array(events) = value else {
+        return Err(Error::Parse("no events array".into()));
+    };
+
+    let scval = |v: &serde_json::Value| -> Result<ScVal, Error> {
+        // Older RPC versions wrap each value as {"xdr": ...}
+        let xdr = v
+            .as_str()
+            .or_else(|| v.get("xdr").and_then(|x| x.as_str()))
+            .ok_or_else(|| Error::Parse("event value is not base64 XDR".into()))?;
+        ScVal::from_xdr_base64(xdr, Limits::none()).map_err(parse_err)
+    };
+
+    let mut raw = Vec::new();
+    for e in &events {
+        if e.get("contractId").and_then(|c| c.as_str()) != Some(contract_id)
+            || e.get("type")
+                .and_then(|t| t.as_str())
+                .is_some_and(|t| t != "contract")
+            || e.get("inSuccessfulContractCall") == Some(&serde_json::Value::Bool(false))
+        {
+            continue;
+        }
+        let topics = e
+            .get("topic")
+            .and_then(|t| t.as_array())
+            .ok_or_else(|| Error::Parse("event without topics".into()))?
+            .iter()
+            .map(scval)
+            .collect::<Result<_, _>>()?;
+        let data = scval(
+            e.get("value")
+                .ok_or_else(|| Error::Parse("event without value".into()))?,
+        )?;
+        raw.push(RawEvent {
+            contract_id: contract_id.into(),
+            ledger: e
+                .get("ledger")
+                .and_then(|l| l.as_u64())
+                .and_then(|l| u32::try_from(l).ok()),
+            topics,
+            data,
+        });
+    }
+    Ok(raw)
+}
@@ -0,0 +1,261 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use crate::Event;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompanyRow {
+    pub name: String,
+    pub admin: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BranchRow {
+    pub company_id: String,
+    pub name: String,
+    pub admin: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetRow {
+    pub company_id: String,
+    pub name: String,
+    pub asset_type: String,
+    pub status: String,
+    pub owner: String,
+    pub purchase_cost: i128,
+    pub current_value: i128,
+    pub currency: String,
+    pub currency_scale: u32,
+    /// Hex token id, `None` until tokenized
+    pub token_id: Option<String>,
+}
+
+/// Projection of the contract's events, keyed by hex id. Ordered maps keep
+/// iteration, and so the SQL export, deterministic.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct State {
+    pub companies: BTreeMap<String, CompanyRow>,
+    pub branches: BTreeMap<String, BranchRow>,
+    pub assets: BTreeMap<String, AssetRow>,
+    /// (asset_id, branch_id) links
+    pub asset_branches: BTreeSet<(String, String)>,
+}
+
+const SCHEMA: &str = "\
+CREATE TABLE IF NOT EXISTS companies (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    admin TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS branches (
+    id TEXT PRIMARY KEY,
+    company_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    admin TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS assets (
+    id TEXT PRIMARY KEY,
+    company_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    asset_type TEXT NOT NULL,
+    status TEXT NOT NULL,
+    owner TEXT NOT NULL,
+    purchase_cost INTEGER NOT NULL,
+    current_value INTEGER NOT NULL,
+    currency TEXT NOT NULL,
+    currency_scale INTEGER NOT NULL,
+    token_id TEXT
+);
+CREATE TABLE IF NOT EXISTS asset_branches (
+    asset_id TEXT NOT NULL,
+    branch_id TEXT NOT NULL,
+    PRIMARY KEY (asset_id, branch_id)
+);
+CREATE TABLE IF NOT EXISTS indexer_cursor (
+    next_seq INTEGER NOT NULL
+);
+";
+
+fn text(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+/// Amounts beyond SQLite's 64-bit integers are stored as text.
+fn amount(n: i128) -> String {
+    match i64::try_from(n) {
+        Ok(n) => n.to_string(),
+        Err(_) => text(&n.to_string()),
+    }
+}
+
+/// The contract stores a zero id for untokenized assets.
+fn is_token(id: &str) -> bool {
+    id.bytes().any(|b| b != b'0')
+}
+
+impl State {
+    pub(crate) fn apply(&mut self, event: &Event) {
+        match event.clone() {
+            Event::CompanyCreated {
+                company_id,
+                name,
+                admin,
+            } => {
+                self.companies
+                    .insert(company_id, CompanyRow { name, admin });
+            }
+            Event::CompanyAdminChanged { company_id, to, .. } => {
+                if let Some(c) = self.companies.get_mut(&company_id) {
+                    c.admin = to;
+                }
+            }
+            Event::BranchCreated {
+                branch_id,
+                company_id,
+                name,
+                admin,
+            } => {
+                self.branches.insert(
+                    branch_id,
+                    BranchRow {
+                        company_id,
+                        name,
+                        admin,
+                    },
+                );
+            }
+            Event::AssetRegistered {
+                asset_id,
+                company_id,
+                name,
+                asset_type,
+                status,
+                owner,
+                purchase_cost,
+                current_value,
+                currency,
+                currency_scale,
+                stellar_token_id,
+            } => {
+                self.assets.insert(
+                    asset_id,
+                    AssetRow {
+                        company_id,
+                        name,
+                        asset_type,
+                        status,
+                        owner,
+                        purchase_cost,
+                        current_value,
+                        currency,
+                        currency_scale,
+                        token_id: is_token(&stellar_token_id).then_some(stellar_token_id),
+                    },
+                );
+            }
+            Event::AssetTransferred { asset_id, to, .. } => {
+                if let Some(a) = self.assets.get_mut(&asset_id) {
+                    a.owner = to;
+                }
+            }
+            Event::AssetStatusChanged { asset_id, to, .. } => {
+                if let Some(a) = self.assets.get_mut(&asset_id) {
+                    a.status = to;
+                }
+            }
+            Event::AssetTokenized { asset_id, token_id } => {
+                if let Some(a) = self.assets.get_mut(&asset_id) {
+                    a.token_id = Some(token_id);
+                }
+            }
+            Event::AssetRevalued {
+                asset_id,
+                current_value,
+            } => {
+                if let Some(a) = self.assets.get_mut(&asset_id) {
+                    a.current_value = current_value;
+                }
+            }
+            Event::AssetLinked {
+                asset_id,
+                branch_id,
+            } => {
+                self.asset_branches.insert((asset_id, branch_id));
+            }
+            Event::AssetUnlinked {
+                asset_id,
+                branch_id,
+            } => {
+                self.asset_branches.remove(&(asset_id, branch_id));
+            }
+        }
+    }
+
+    /// SQLite script that creates the tables if needed and replaces their
+    /// contents with this state, in one transaction.
+    pub fn to_sql(&self, next_seq: u64) -> String {
+        let mut sql = String::from(SCHEMA);
+        sql.push_str("BEGIN;\n");
+        for table in [
+            "companies",
+            "branches",
+            "assets",
+            "asset_branches",
+            "indexer_cursor",
+        ] {
+            writeln!(sql, "DELETE FROM {table};").unwrap();
+        }
+        for (id, c) in &self.companies {
+            writeln!(
+                sql,
+                "INSERT INTO companies VALUES ({}, {}, {});",
+                text(id),
+                text(&c.name),
+                text(&c.admin)
+            )
+            .unwrap();
+        }
+        for (id, b) in &self.branches {
+            writeln!(
+                sql,
+                "INSERT INTO branches VALUES ({}, {}, {}, {});",
+                text(id),
+                text(&b.company_id),
+                text(&b.name),
+                text(&b.admin)
+            )
+            .unwrap();
+        }
+        for (id, a) in &self.assets {
+            writeln!(
+                sql,
+                "INSERT INTO assets VALUES ({}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {});",
+                text(id),
+                text(&a.company_id),
+                text(&a.name),
+                text(&a.asset_type),
+                text(&a.status),
+                text(&a.owner),
+                amount(a.purchase_cost),
+                amount(a.current_value),
+                text(&a.currency),
+                a.currency_scale,
+                a.token_id.as_deref().map_or("NULL".into(), text)
+            )
+            .unwrap();
+        }
+        for (asset_id, branch_id) in &self.asset_branches {
+            writeln!(
+                sql,
+                "INSERT INTO asset_branches VALUES ({}, {});",
+                text(asset_id),
+                text(branch_id)
+            )
+            .unwrap();
+        }
+        writeln!(sql, "INSERT INTO indexer_cursor VALUES ({next_seq});").unwrap();
+        sql.push_str("COMMIT;\n");
+        sql
+    }
+}
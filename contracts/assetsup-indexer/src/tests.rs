@@ -0,0 +1,360 @@
+extern crate std;
+
+use assetsup::{
+    AssetUpContract, AssetUpContractClient,
+    asset::Asset,
+    category::DepreciationSettings,
+    currency::Currency,
+    types::{AssetStatus, AssetType, DepreciationMethod},
+};
+use soroban_sdk::{
+    Address, BytesN, Env, String, TryFromVal, Vec, symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    xdr::{
+        ContractEvent, ContractEventBody, ContractEventType, ContractEventV0, ExtensionPoint, Hash,
+        Limits, ScVal, WriteXdr,
+    },
+};
+
+use crate::{Applied, Error, Event, Indexer, Record, decode_contract_event, gaps, read_rpc_events};
+
+const FIXTURE_PATH: &str = "fixtures/get_events.json";
+const FIXTURE: &str = include_str!("../fixtures/get_events.json");
+
+fn id(seed: u8) -> std::string::String {
+    hex::encode([seed; 32])
+}
+
+/// Drive the contract through a short history and render its events the
+/// way RPC `getEvents` returns them.
+fn scenario_events() -> (std::string::String, std::string::String) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AssetUpContract, ());
+    let client = AssetUpContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let successor = Address::generate(&env);
+    let company_id = BytesN::from_array(&env, &[0xC0; 32]);
+    let branch_id = BytesN::from_array(&env, &[0xB0; 32]);
+
+    let mut events = std::vec::Vec::new();
+    let mut record = |env: &Env| {
+        let ledger = env.ledger().sequence();
+        for (i, (contract, topics, data)) in env.events().all().iter().enumerate() {
+            let topics: std::vec::Vec<serde_json::Value> = topics
+                .iter()
+                .map(|t| ScVal::try_from_val(env, &t).unwrap())
+                .map(|t| t.to_xdr_base64(Limits::none()).unwrap().into())
+                .collect();
+            let value = ScVal::try_from_val(env, &data).unwrap();
+            events.push(serde_json::json!({
+                "type": "contract",
+                "ledger": ledger,
+                "contractId": contract.to_string().to_string(),
+                "id": format!("{:019}-{:010}", (ledger as u64) << 32, i),
+                "topic": topics,
+                "value": value.to_xdr_base64(Limits::none()).unwrap(),
+                "inSuccessfulContractCall": true,
+            }));
+        }
+        env.ledger().with_mut(|l| l.sequence_number += 1);
+    };
+
+    client.initialize(&admin);
+    client.create_company(&company_id, &String::from_str(&env, "Acme"), &admin);
+    record(&env);
+    client.create_category(
+        &company_id,
+        &1,
+        &String::from_str(&env, "Vehicles"),
+        &0,
+        &DepreciationSettings {
+            method: DepreciationMethod::None,
+            useful_life_months: 0,
+            salvage_value_bps: 0,
+        },
+    );
+    client.create_branch(
+        &company_id,
+        &branch_id,
+        &String::from_str(&env, "O'Hare Depot"),
+        &String::from_str(&env, "Chicago"),
+        &admin,
+    );
+    record(&env);
+
+    let mut assets = Vec::new(&env);
+    for (seed, name) in [(0x01, "Van"), (0x02, "Forklift")] {
+        assets.push_back(Asset {
+            id: BytesN::from_array(&env, &[seed; 32]),
+            company_id: company_id.clone(),
+            name: String::from_str(&env, name),
+            asset_type: AssetType::Physical,
+            category_id: 1,
+            branch_id: 0,
+            department_id: 0,
+            status: AssetStatus::Active,
+            purchase_date: 1_700_000_000,
+            purchase_cost: 3_000_000,
+            current_value: 2_500_000,
+            currency: Currency {
+                code: symbol_short!("USD"),
+                scale: 2,
+            },
+            warranty_expiry: 0,
+            stellar_token_id: BytesN::from_array(&env, &[0; 32]),
+            owner: owner.clone(),
+        });
+    }
+    client.register_assets(&assets, &Some(branch_id.clone()));
+    record(&env);
+
+    let van = BytesN::from_array(&env, &[0x01; 32]);
+    client.transfer_asset(&van, &buyer);
+    record(&env);
+    client.tokenize_asset(&van, &BytesN::from_array(&env, &[0x7E; 32]));
+    record(&env);
+    client.dispose_asset(&BytesN::from_array(&env, &[0x02; 32]));
+    record(&env);
+
+    // Registered with its token already minted
+    let mut trailer = assets.get_unchecked(0);
+    trailer.id = BytesN::from_array(&env, &[0x03; 32]);
+    trailer.name = String::from_str(&env, "Trailer");
+    trailer.stellar_token_id = BytesN::from_array(&env, &[0x7F; 32]);
+    client.register_asset(&trailer);
+    record(&env);
+    client.set_company_admin(&company_id, &successor);
+    record(&env);
+
+    let response = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "result": { "events": events, "latestLedger": env.ledger().sequence() },
+    });
+    (
+        contract_id.to_string().to_string(),
+        serde_json::to_string_pretty(&response).unwrap() + "\n",
+    )
+}
+
+fn fixture_contract() -> std::string::String {
+    let json: serde_json::Value = serde_json::from_str(FIXTURE).unwrap();
+    json["result"]["events"][0]["contractId"]
+        .as_str()
+        .unwrap()
+        .into()
+}
+
+fn fixture_records() -> std::vec::Vec<Record> {
+    read_rpc_events(FIXTURE, &fixture_contract())
+        .unwrap()
+        .iter()
+        .filter_map(|e| e.decode().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_fixture_matches_contract_events() {
+    let (_, generated) = scenario_events();
+    if std::env::var_os("ASSETSUP_UPDATE_FIXTURES").is_some() {
+        std::fs::write(FIXTURE_PATH, &generated).unwrap();
+        return;
+    }
+    assert_eq!(
+        generated, FIXTURE,
+        "contract events changed; rerun with ASSETSUP_UPDATE_FIXTURES=1"
+    );
+}
+
+#[test]
+fn test_decodes_fixture_events() {
+    let records = fixture_records();
+    let seqs: std::vec::Vec<u64> = records.iter().map(|r| r.seq).collect();
+    assert_eq!(
+        seqs,
+        (0..records.len() as u64).collect::<std::vec::Vec<_>>()
+    );
+    assert_eq!(records[0].ledger, Some(0));
+
+    assert!(matches!(
+        &records[0].event,
+        Event::CompanyCreated { company_id, name, .. } if *company_id == id(0xC0) && name == "Acme"
+    ));
+    assert!(matches!(
+        &records[1].event,
+        Event::BranchCreated { branch_id, .. } if *branch_id == id(0xB0)
+    ));
+    let Event::AssetRegistered {
+        asset_id,
+        purchase_cost,
+        currency,
+        currency_scale,
+        status,
+        ..
+    } = &records[2].event
+    else {
+        panic!("expected a registration, got {:?}", records[2].event);
+    };
+    assert_eq!(
+        (
+            asset_id.as_str(),
+            *purchase_cost,
+            currency.as_str(),
+            *currency_scale,
+            status.as_str()
+        ),
+        (id(0x01).as_str(), 3_000_000, "USD", 2, "Active")
+    );
+    assert!(matches!(&records[3].event, Event::AssetLinked { .. }));
+    assert!(records.iter().any(|r| matches!(
+        &r.event,
+        Event::AssetStatusChanged { to, .. } if to == "Disposed"
+    )));
+    assert!(matches!(
+        &records.last().unwrap().event,
+        Event::CompanyAdminChanged { company_id, from, to }
+            if *company_id == id(0xC0) && from != to
+    ));
+}
+
+#[test]
+fn test_projects_state() {
+    let indexer = Indexer::replay(fixture_records()).unwrap();
+    let state = indexer.state();
+    assert_eq!(indexer.next_seq(), fixture_records().len() as u64);
+
+    assert_eq!(state.companies[&id(0xC0)].name, "Acme");
+    assert_eq!(state.branches[&id(0xB0)].company_id, id(0xC0));
+    let van = &state.assets[&id(0x01)];
+    assert_eq!(van.token_id, Some(id(0x7E)));
+    assert_eq!(van.status, "Active");
+    assert_ne!(van.owner, state.assets[&id(0x02)].owner);
+    assert_eq!(state.assets[&id(0x02)].status, "Disposed");
+    assert!(state.asset_branches.contains(&(id(0x02), id(0xB0))));
+    assert_eq!(state.assets[&id(0x02)].token_id, None);
+    // Tokenized at registration, with no separate tokenize event
+    assert_eq!(state.assets[&id(0x03)].token_id, Some(id(0x7F)));
+    assert!(indexer.to_sql().contains(&format!("'{}');", id(0x7F))));
+
+    let Some(Event::CompanyAdminChanged { to, .. }) = fixture_records().pop().map(|r| r.event)
+    else {
+        panic!("expected the admin change last");
+    };
+    assert_eq!(state.companies[&id(0xC0)].admin, to);
+
+    let sql = indexer.to_sql();
+    assert!(sql.contains("CREATE TABLE IF NOT EXISTS assets"));
+    assert!(sql.contains("'O''Hare Depot'"));
+    assert!(sql.contains(&format!(
+        "INSERT INTO indexer_cursor VALUES ({});",
+        indexer.next_seq()
+    )));
+    assert!(sql.trim_end().ends_with("COMMIT;"));
+}
+
+#[test]
+fn test_replay_is_deterministic() {
+    let records = fixture_records();
+    let expected = Indexer::replay(records.clone()).unwrap().to_sql();
+
+    // Reversed, with every record delivered twice
+    let mut shuffled = records.clone();
+    shuffled.reverse();
+    shuffled.extend(records.iter().cloned());
+    assert_eq!(Indexer::replay(shuffled).unwrap().to_sql(), expected);
+
+    // Incremental ingestion in overlapping batches
+    let mut indexer = Indexer::new();
+    assert_eq!(indexer.ingest(records[..4].to_vec()).unwrap(), 4);
+    assert_eq!(
+        indexer.ingest(records[2..].to_vec()).unwrap(),
+        records.len() - 4
+    );
+    assert_eq!(indexer.apply(&records[0]), Ok(Applied::Duplicate));
+    assert_eq!(indexer.to_sql(), expected);
+}
+
+#[test]
+fn test_detects_gaps() {
+    let mut records = fixture_records();
+    records.remove(3);
+    records.remove(3);
+
+    let seqs: std::vec::Vec<u64> = records.iter().map(|r| r.seq).collect();
+    let missing = gaps(0, seqs.clone());
+    assert_eq!(missing.len(), 1);
+    assert_eq!(missing[0], 3..5);
+    assert_eq!(gaps(4, seqs.clone())[0], 4..5);
+    assert!(gaps(5, seqs).is_empty());
+
+    let mut indexer = Indexer::new();
+    assert_eq!(
+        indexer.ingest(records),
+        Err(Error::Gap {
+            expected: 3,
+            found: 5
+        })
+    );
+    // Records before the gap were kept
+    assert_eq!(indexer.next_seq(), 3);
+}
+
+#[test]
+fn test_filters_foreign_and_unknown_events() {
+    let mut json: serde_json::Value = serde_json::from_str(FIXTURE).unwrap();
+    let events = json["result"]["events"].as_array_mut().unwrap();
+    let mut foreign = events[0].clone();
+    foreign["contractId"] = "CA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAXE".into();
+    let mut failed = events[0].clone();
+    failed["inSuccessfulContractCall"] = false.into();
+    let mut unknown = events[0].clone();
+    unknown["topic"][0] = ScVal::Symbol("transfer".try_into().unwrap())
+        .to_xdr_base64(Limits::none())
+        .unwrap()
+        .into();
+    events.extend([foreign, failed, unknown]);
+
+    let raw = read_rpc_events(&json.to_string(), &fixture_contract()).unwrap();
+    assert_eq!(raw.len(), fixture_records().len() + 1);
+    assert_eq!(raw.last().unwrap().decode(), Ok(None));
+
+    // The bare events array is accepted too
+    let bare = json["result"]["events"].to_string();
+    assert_eq!(
+        read_rpc_events(&bare, &fixture_contract()).unwrap().len(),
+        raw.len()
+    );
+    assert!(read_rpc_events("{}", &fixture_contract()).is_err());
+}
+
+#[test]
+fn test_decodes_contract_event_xdr() {
+    let raw = &read_rpc_events(FIXTURE, &fixture_contract()).unwrap()[1];
+    let mut contract = [0u8; 32];
+    contract.copy_from_slice(
+        &stellar_strkey::Contract::from_string(&raw.contract_id)
+            .unwrap()
+            .0,
+    );
+    let event = ContractEvent {
+        ext: ExtensionPoint::V0,
+        contract_id: Some(Hash(contract)),
+        type_: ContractEventType::Contract,
+        body: ContractEventBody::V0(ContractEventV0 {
+            topics: raw.topics.clone().try_into().unwrap(),
+            data: raw.data.clone(),
+        }),
+    };
+    let xdr = event.to_xdr_base64(Limits::none()).unwrap();
+    let decoded = decode_contract_event(&xdr).unwrap().unwrap();
+    assert_eq!(decoded.contract_id, raw.contract_id);
+    assert_eq!(decoded.decode().unwrap().unwrap().seq, 1);
+
+    // Malformed payload of a known event
+    let mut broken = decoded.clone();
+    broken.data = ScVal::U32(7);
+    assert_eq!(broken.decode(), Err(Error::Malformed("branch_created")));
+}
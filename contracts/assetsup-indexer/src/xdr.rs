@@ -0,0 +1,73 @@
+//! Readers for the XDR shapes of contract types: structs are maps keyed by
+//! field name, unit enum variants are single-symbol vectors.
+
+use soroban_sdk::xdr::{ScAddress, ScMap, ScVal};
+use stellar_strkey::{Contract, ed25519::PublicKey};
+
+pub(crate) fn field<'a>(map: &'a ScMap, name: &str) -> Option<&'a ScVal> {
+    map.iter()
+        .find(|e| matches!(&e.key, ScVal::Symbol(s) if s.0.as_slice() == name.as_bytes()))
+        .map(|e| &e.val)
+}
+
+pub(crate) fn symbol(v: &ScVal) -> Option<String> {
+    match v {
+        ScVal::Symbol(s) => s.0.to_utf8_string().ok(),
+        _ => None,
+    }
+}
+
+pub(crate) fn variant(v: &ScVal) -> Option<String> {
+    match v {
+        ScVal::Vec(Some(items)) if items.len() == 1 => symbol(&items[0]),
+        _ => None,
+    }
+}
+
+pub(crate) fn string(v: &ScVal) -> Option<String> {
+    match v {
+        ScVal::String(s) => s.0.to_utf8_string().ok(),
+        _ => None,
+    }
+}
+
+/// 32-byte id as lowercase hex.
+pub(crate) fn id(v: &ScVal) -> Option<String> {
+    match v {
+        ScVal::Bytes(b) if b.len() == 32 => Some(hex::encode(b.as_slice())),
+        _ => None,
+    }
+}
+
+/// Address as a `G...` or `C...` strkey.
+pub(crate) fn address(v: &ScVal) -> Option<String> {
+    match v {
+        ScVal::Address(ScAddress::Account(a)) => {
+            let soroban_sdk::xdr::PublicKey::PublicKeyTypeEd25519(key) = &a.0;
+            Some(PublicKey(key.0).to_string())
+        }
+        ScVal::Address(ScAddress::Contract(h)) => Some(Contract(h.0).to_string()),
+        _ => None,
+    }
+}
+
+pub(crate) fn u32(v: &ScVal) -> Option<u32> {
+    match v {
+        ScVal::U32(n) => Some(*n),
+        _ => None,
+    }
+}
+
+pub(crate) fn u64(v: &ScVal) -> Option<u64> {
+    match v {
+        ScVal::U64(n) => Some(*n),
+        _ => None,
+    }
+}
+
+pub(crate) fn i128(v: &ScVal) -> Option<i128> {
+    match v {
+        ScVal::I128(parts) => Some(((parts.hi as i128) << 64) | parts.lo as i128),
+        _ => None,
+    }
+}
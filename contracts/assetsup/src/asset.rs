@@ -1,10 +1,33 @@
-use soroban_sdk::{Address, BytesN, String, contracttype};
+use soroban_sdk::{Address, BytesN, Env, Map, String, Vec, contracttype};
 
-use crate::types::{AssetStatus, AssetType};
+use crate::{
+    category, currency, department,
+    error::Error,
+    events,
+    index::{self, List, Slot},
+    stats,
+    types::{AssetStatus, AssetType},
+};
+
+/// Largest batch accepted by `register_assets`, and the most assets one
+/// purchase order receipt registers.
+///
+/// Soroban caps a transaction at `MAX_WRITE_ENTRIES` ledger writes. A batch
+/// writes one entry per asset, plus the list tails it appends to, the
+/// stats, the event sequence and one auth nonce per signer. Assets of one
+/// owner and category linked to a branch measure 8 shared writes, and up to
+/// 3 more when appends fill list pages. Batches spread over more owners,
+/// categories or departments fit fewer assets; `register_assets` plans its
+/// writes and rejects those with `BatchTooLarge`.
+pub const MAX_BATCH_SIZE: u32 = 14;
+
+/// Network limit on ledger entries written by one transaction.
+pub const MAX_WRITE_ENTRIES: u32 = 25;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
+    // asset_id -> Entry; the owning company is on the record
     Asset(BytesN<32>),
 }
 
@@ -12,18 +35,283 @@ pub enum DataKey {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Asset {
     pub id: BytesN<32>,
+    pub company_id: BytesN<32>,
     pub name: String,
     pub asset_type: AssetType,
-    pub category: String,
+    pub category_id: u64,
     pub branch_id: u64,
     pub department_id: u64,
     pub status: AssetStatus,
     pub purchase_date: u64,
     pub purchase_cost: i128,
     pub current_value: i128,
+    // Denomination of purchase_cost, current_value and amounts of liens,
+    // policies and claims on the asset
+    pub currency: currency::Currency,
     pub warranty_expiry: u64,
     pub stellar_token_id: BytesN<32>,
     pub owner: Address,
 }
 
-// Note: Contract methods implemented in lib.rs
+/// Outcome of registering one asset in a batch
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RegistrationStatus {
+    Registered,
+    AlreadyExists,
+    InvalidName,
+    CompanyNotFound,
+    CategoryNotFound,
+    DepartmentNotFound,
+    CompanyMismatch,
+    InvalidCurrency,
+    NegativeCost,
+    InvalidCurrentValue,
+    WarrantyBeforePurchase,
+    ZeroId,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistrationResult {
+    pub asset_id: BytesN<32>,
+    pub status: RegistrationStatus,
+}
+
+/// An asset as stored, with its position in each id list it belongs to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    pub asset: Asset,
+    pub slots: Vec<Slot>,
+}
+
+pub(crate) fn exists(env: &Env, asset_id: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Asset(asset_id.clone()))
+}
+
+fn load_entry(env: &Env, asset_id: &BytesN<32>) -> Option<Entry> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Asset(asset_id.clone()))
+}
+
+fn store_entry(env: &Env, entry: &Entry) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Asset(entry.asset.id.clone()), entry);
+}
+
+pub(crate) fn load(env: &Env, asset_id: &BytesN<32>) -> Option<Asset> {
+    load_entry(env, asset_id).map(|e| e.asset)
+}
+
+/// Persist a change to a stored asset, move it between the lists that
+/// follow its fields and fold the change into the portfolio stats.
+pub(crate) fn save(env: &Env, asset: &Asset) {
+    let mut entry = load_entry(env, &asset.id).unwrap();
+    let old = entry.asset.clone();
+    stats::record(env, &branches_of(&entry), &old, asset);
+
+    let (old_lists, new_lists) = (field_lists(env, &old), field_lists(env, asset));
+    for list in old_lists.iter() {
+        if !new_lists.contains(&list) {
+            unlink(env, &mut entry, &list);
+        }
+    }
+    for list in new_lists.iter() {
+        if !old_lists.contains(&list) {
+            link(env, &mut entry, list);
+        }
+    }
+
+    events::asset_changed(env, &old, asset);
+    entry.asset = asset.clone();
+    store_entry(env, &entry);
+}
+
+/// Lists an asset belongs to by its own fields. Disposed assets leave
+/// their owner's list.
+fn field_lists(env: &Env, a: &Asset) -> Vec<List> {
+    let mut lists = Vec::new(env);
+    lists.push_back(List::Category(a.company_id.clone(), a.category_id));
+    if a.department_id != department::UNASSIGNED {
+        lists.push_back(List::Department(a.company_id.clone(), a.department_id));
+    }
+    if a.status != AssetStatus::Disposed {
+        lists.push_back(List::Owner(a.owner.clone()));
+    }
+    lists
+}
+
+fn lists_of(entry: &Entry) -> Vec<List> {
+    let mut lists = Vec::new(entry.slots.env());
+    for slot in entry.slots.iter() {
+        lists.push_back(slot.list);
+    }
+    lists
+}
+
+fn branches_of(entry: &Entry) -> Vec<BytesN<32>> {
+    branches_in(entry.slots.env(), &lists_of(entry))
+}
+
+/// Lists a stored asset belongs to, in the order it joined them.
+pub(crate) fn lists(env: &Env, asset_id: &BytesN<32>) -> Vec<List> {
+    match load_entry(env, asset_id) {
+        Some(entry) => lists_of(&entry),
+        None => Vec::new(env),
+    }
+}
+
+/// Branches an asset is linked to, in the order they were linked.
+pub(crate) fn linked_branches(env: &Env, asset_id: &BytesN<32>) -> Vec<BytesN<32>> {
+    branches_in(env, &lists(env, asset_id))
+}
+
+fn link(env: &Env, entry: &mut Entry, list: List) {
+    let mut ids = Vec::new(env);
+    ids.push_back(entry.asset.id.clone());
+    let position = index::push(env, &list, &ids);
+    entry.slots.push_back(Slot { list, position });
+}
+
+fn unlink(env: &Env, entry: &mut Entry, list: &List) -> bool {
+    let Some(i) = entry.slots.iter().position(|s| &s.list == list) else {
+        return false;
+    };
+    let position = entry.slots.get_unchecked(i as u32).position;
+    if let Some(moved) = index::swap_remove(env, list, position) {
+        let mut other = load_entry(env, &moved).unwrap();
+        for (j, mut slot) in other.slots.iter().enumerate() {
+            if &slot.list == list {
+                slot.position = position;
+                other.slots.set(j as u32, slot);
+            }
+        }
+        store_entry(env, &other);
+    }
+    entry.slots.remove(i as u32);
+    true
+}
+
+/// Add a stored asset to `list`. Returns false if it is already in it.
+pub(crate) fn add_to(env: &Env, asset_id: &BytesN<32>, list: List) -> bool {
+    let mut entry = load_entry(env, asset_id).unwrap();
+    if entry.slots.iter().any(|s| s.list == list) {
+        return false;
+    }
+    link(env, &mut entry, list);
+    store_entry(env, &entry);
+    true
+}
+
+/// Remove a stored asset from `list`. Returns false if it is not in it.
+pub(crate) fn remove_from(env: &Env, asset_id: &BytesN<32>, list: &List) -> bool {
+    let mut entry = load_entry(env, asset_id).unwrap();
+    if !unlink(env, &mut entry, list) {
+        return false;
+    }
+    store_entry(env, &entry);
+    true
+}
+
+/// Check that a new asset does not collide with an existing one and that
+/// its category and department exist in its company.
+pub(crate) fn check_new(env: &Env, asset: &Asset) -> Result<(), Error> {
+    if exists(env, &asset.id) {
+        return Err(Error::AssetAlreadyExists);
+    }
+
+    let store = env.storage().persistent();
+    if !store.has(&category::DataKey::Category(
+        asset.company_id.clone(),
+        asset.category_id,
+    )) {
+        return Err(Error::CategoryNotFound);
+    }
+
+    if asset.department_id != department::UNASSIGNED
+        && !store.has(&department::DataKey::Department(
+            asset.company_id.clone(),
+            asset.department_id,
+        ))
+    {
+        return Err(Error::DepartmentNotFound);
+    }
+    Ok(())
+}
+
+/// Lists a batch of new assets joins, with the batch indexes of the
+/// assets joining each. Every asset also joins `links`.
+fn batch_lists(env: &Env, assets: &Vec<Asset>, links: &Vec<List>) -> Map<List, Vec<u32>> {
+    let mut lists: Map<List, Vec<u32>> = Map::new(env);
+    for (i, a) in assets.iter().enumerate() {
+        let mut joined = field_lists(env, &a);
+        joined.append(links);
+        for list in joined.iter() {
+            let mut members = lists.get(list.clone()).unwrap_or_else(|| Vec::new(env));
+            members.push_back(i as u32);
+            lists.set(list, members);
+        }
+    }
+    lists
+}
+
+fn branches_in(env: &Env, links: &Vec<List>) -> Vec<BytesN<32>> {
+    let mut branches = Vec::new(env);
+    for list in links.iter() {
+        if let List::Branch(branch_id) = list {
+            branches.push_back(branch_id);
+        }
+    }
+    branches
+}
+
+/// Ledger entries `insert` writes for a batch, leaving out auth nonces:
+/// one per asset, the touched list tails and filled pages, the global and
+/// branch stats and the event sequence.
+pub(crate) fn insert_writes(env: &Env, assets: &Vec<Asset>, links: &Vec<List>) -> u32 {
+    let mut writes = assets.len() + 2 + branches_in(env, links).len();
+    for (list, members) in batch_lists(env, assets, links).iter() {
+        writes += index::push_writes(env, &list, members.len());
+    }
+    writes
+}
+
+/// Persist checked new assets and add them to their own lists and to
+/// `links`, such as a branch or supplier. Every list and stats entry is
+/// written once for the whole batch.
+pub(crate) fn insert(env: &Env, assets: &Vec<Asset>, links: &Vec<List>) {
+    let mut slots: Vec<Vec<Slot>> = Vec::new(env);
+    for _ in assets.iter() {
+        slots.push_back(Vec::new(env));
+    }
+    for (list, members) in batch_lists(env, assets, links).iter() {
+        let mut ids = Vec::new(env);
+        for i in members.iter() {
+            ids.push_back(assets.get_unchecked(i).id);
+        }
+        let first = index::push(env, &list, &ids);
+        for (k, i) in members.iter().enumerate() {
+            let mut asset_slots = slots.get_unchecked(i);
+            asset_slots.push_back(Slot {
+                list: list.clone(),
+                position: first + k as u32,
+            });
+            slots.set(i, asset_slots);
+        }
+    }
+
+    let branches = branches_in(env, links);
+    stats::record_new(env, assets, &branches);
+    for (asset, slots) in assets.iter().zip(slots.iter()) {
+        events::asset_registered(env, &asset);
+        for branch_id in branches.iter() {
+            events::asset_link(env, &asset.id, &branch_id, true);
+        }
+        store_entry(env, &Entry { asset, slots });
+    }
+}
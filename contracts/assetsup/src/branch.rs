@@ -1,19 +1,51 @@
-use soroban_sdk::{Address, BytesN, String, contracttype};
+use soroban_sdk::{Address, BytesN, Env, String, contracttype};
+
+use crate::{
+    asset::{self, Asset},
+    events,
+    index::List,
+    stats,
+};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
-    Branch(BytesN<32>),
-    AssetList(BytesN<32>),
+    // (company_id, branch_id)
+    Branch(BytesN<32>, BytesN<32>),
+    // branch_id -> owning company_id
+    CompanyOf(BytesN<32>),
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Branch {
     pub id: BytesN<32>,
+    pub company_id: BytesN<32>,
     pub name: String,
     pub location: String,
     pub admin: Address,
 }
 
-// Note: Contract methods implemented in lib.rs
+/// Load a branch from its company's namespace.
+pub(crate) fn load(env: &Env, branch_id: &BytesN<32>) -> Option<Branch> {
+    let store = env.storage().persistent();
+    let company_id: BytesN<32> = store.get(&DataKey::CompanyOf(branch_id.clone()))?;
+    store.get(&DataKey::Branch(company_id, branch_id.clone()))
+}
+
+/// Link an asset to a branch, keeping the branch's stats in step. Linking
+/// twice is a no-op.
+pub(crate) fn add_asset(env: &Env, branch_id: &BytesN<32>, a: &Asset) {
+    if asset::add_to(env, &a.id, List::Branch(branch_id.clone())) {
+        stats::record_link(env, branch_id, a, true);
+        events::asset_link(env, &a.id, branch_id, true);
+    }
+}
+
+/// Unlink an asset from a branch. Unlinking an unlinked asset is a no-op.
+pub(crate) fn remove_asset(env: &Env, branch_id: &BytesN<32>, a: &Asset) {
+    if asset::remove_from(env, &a.id, &List::Branch(branch_id.clone())) {
+        stats::record_link(env, branch_id, a, false);
+        events::asset_link(env, &a.id, branch_id, false);
+    }
+}
@@ -0,0 +1,37 @@
+use soroban_sdk::{BytesN, String, contracttype};
+
+use crate::types::DepreciationMethod;
+
+/// Parent id of top-level categories.
+pub const ROOT: u64 = 0;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    // (company_id, category_id)
+    Category(BytesN<32>, u64),
+    // (company_id, parent_id)
+    Children(BytesN<32>, u64),
+}
+
+/// Default depreciation applied to assets registered in a category.
+/// `salvage_value_bps` is the residual value in basis points of purchase cost.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepreciationSettings {
+    pub method: DepreciationMethod,
+    pub useful_life_months: u32,
+    pub salvage_value_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Category {
+    pub id: u64,
+    pub company_id: BytesN<32>,
+    pub name: String,
+    pub parent_id: u64,
+    pub depreciation: DepreciationSettings,
+}
+
+// Note: Contract methods implemented in lib.rs
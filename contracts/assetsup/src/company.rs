@@ -0,0 +1,20 @@
+use soroban_sdk::{Address, BytesN, String, contracttype};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Company(BytesN<32>),
+    BranchList(BytesN<32>),
+}
+
+/// A tenant of the contract. Every branch, department and asset belongs to
+/// exactly one company and is administered by that company's `admin`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Company {
+    pub id: BytesN<32>,
+    pub name: String,
+    pub admin: Address,
+}
+
+// Note: Contract methods implemented in lib.rs
@@ -0,0 +1,45 @@
+use soroban_sdk::{BytesN, String, Vec, contracttype};
+
+use crate::stats::ValueTotal;
+
+/// Basis points an asset's allocation shares must add up to.
+pub const FULL_ALLOCATION_BPS: u32 = 10_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    // (company_id, cost_center_id)
+    CostCenter(BytesN<32>, u64),
+    // company_id -> Vec<cost_center_id>
+    CompanyCostCenters(BytesN<32>),
+    // asset_id -> Vec<Allocation>
+    Allocation(BytesN<32>),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CostCenter {
+    pub id: u64,
+    pub company_id: BytesN<32>,
+    pub name: String,
+}
+
+/// Portion of an asset charged to one cost center, in basis points.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Allocation {
+    pub cost_center_id: u64,
+    pub bps: u32,
+}
+
+/// A cost center's share of the assets allocated to it, with one entry in
+/// `totals` per currency in use.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CostCenterShare {
+    pub cost_center_id: u64,
+    pub asset_count: u32,
+    pub totals: Vec<ValueTotal>,
+}
+
+// Note: Contract methods implemented in lib.rs
@@ -0,0 +1,143 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec, contracttype};
+
+use crate::{
+    asset,
+    branch::Branch,
+    error::{CurrencyError, Error, ValidationError},
+    index::{self, List},
+    types::AssetStatus,
+};
+
+/// Fixed-point scale of FX rates: a rate of `FX_RATE_SCALE` is 1:1.
+pub const FX_RATE_SCALE: i128 = 1_000_000_000;
+
+/// Most decimal places a monetary amount may carry.
+pub const MAX_SCALE: u32 = 18;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    // branch_id -> Currency
+    ReportingCurrency(BytesN<32>),
+    // (base code, quote code) -> FxRate
+    FxRate(Symbol, Symbol),
+    // Address allowed to push FX rates besides the platform admin
+    FxFeeder,
+}
+
+/// Denomination of a monetary amount: an ISO 4217 code such as `NGN` and
+/// the number of decimal places the integer amount carries, e.g. an amount
+/// of `15000` at scale 2 is 150.00.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Currency {
+    pub code: Symbol,
+    pub scale: u32,
+}
+
+/// Price of one whole unit of `base` in whole units of `quote`, scaled by
+/// `FX_RATE_SCALE`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FxRate {
+    pub base: Symbol,
+    pub quote: Symbol,
+    pub rate: i128,
+    pub updated_at: u64,
+    pub source: Address,
+}
+
+/// An amount together with its denomination
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Money {
+    pub amount: i128,
+    pub currency: Currency,
+}
+
+/// Tag of a `Val` holding a symbol of up to 9 characters inline.
+const SYMBOL_SMALL_TAG: u64 = 14;
+
+/// A currency is valid if its code is three uppercase ASCII letters and
+/// its scale at most `MAX_SCALE`.
+pub(crate) fn is_valid(currency: &Currency) -> bool {
+    is_iso_code(&currency.code) && currency.scale <= MAX_SCALE
+}
+
+/// Short symbols are stored inside the value as 6-bit character codes,
+/// first character highest; `A` to `Z` are codes 12 to 37.
+fn is_iso_code(code: &Symbol) -> bool {
+    let payload = code.to_val().get_payload();
+    if payload & 0xff != SYMBOL_SMALL_TAG {
+        return false;
+    }
+    let chars = payload >> 8;
+    chars >> 18 == 0 && (0..3).all(|i| matches!((chars >> (6 * i)) & 0x3f, 12..=37))
+}
+
+/// Convert `amount` from one currency to another using the stored rate for
+/// the pair, or the inverse of the opposite pair.
+pub(crate) fn convert(
+    env: &Env,
+    amount: i128,
+    from: &Currency,
+    to: &Currency,
+) -> Result<i128, soroban_sdk::Error> {
+    let (mut num, mut den) = (1i128, 1i128);
+    if from.code != to.code {
+        let store = env.storage().persistent();
+        if let Some(fx) =
+            store.get::<_, FxRate>(&DataKey::FxRate(from.code.clone(), to.code.clone()))
+        {
+            (num, den) = (fx.rate, FX_RATE_SCALE);
+        } else if let Some(fx) =
+            store.get::<_, FxRate>(&DataKey::FxRate(to.code.clone(), from.code.clone()))
+        {
+            (num, den) = (FX_RATE_SCALE, fx.rate);
+        } else {
+            return Err(CurrencyError::FxRateNotFound.into());
+        }
+    }
+
+    // Scales come from callers and oracles; reject any too large to raise
+    let unit = |scale: u32| {
+        10i128
+            .checked_pow(scale)
+            .ok_or(ValidationError::InvalidCurrency)
+    };
+    num = num
+        .checked_mul(unit(to.scale)?)
+        .ok_or(CurrencyError::AmountOverflow)?;
+    den = den
+        .checked_mul(unit(from.scale)?)
+        .ok_or(CurrencyError::AmountOverflow)?;
+    amount
+        .checked_mul(num)
+        .map(|v| v / den)
+        .ok_or(CurrencyError::AmountOverflow.into())
+}
+
+/// Assets linked to a branch.
+pub(crate) fn branch_assets(env: &Env, branch: &Branch) -> Vec<BytesN<32>> {
+    index::all(env, &List::Branch(branch.id.clone()))
+}
+
+/// Sum the current value of the given assets in `target`, leaving out
+/// disposed assets.
+pub(crate) fn assets_value(
+    env: &Env,
+    asset_ids: &Vec<BytesN<32>>,
+    target: &Currency,
+) -> Result<i128, soroban_sdk::Error> {
+    let mut total: i128 = 0;
+    for asset_id in asset_ids.iter() {
+        let a = asset::load(env, &asset_id).ok_or(Error::AssetNotFound)?;
+        if a.status != AssetStatus::Disposed {
+            let value = convert(env, a.current_value, &a.currency, target)?;
+            total = total
+                .checked_add(value)
+                .ok_or(CurrencyError::AmountOverflow)?;
+        }
+    }
+    Ok(total)
+}
@@ -0,0 +1,24 @@
+use soroban_sdk::{Address, BytesN, String, contracttype};
+
+/// Department id carried by assets that are not assigned to any department.
+pub const UNASSIGNED: u64 = 0;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    // (company_id, department_id)
+    Department(BytesN<32>, u64),
+    BranchDepartments(BytesN<32>),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Department {
+    pub id: u64,
+    pub company_id: BytesN<32>,
+    pub branch_id: BytesN<32>,
+    pub name: String,
+    pub head: Address,
+}
+
+// Note: Contract methods implemented in lib.rs
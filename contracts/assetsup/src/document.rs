@@ -0,0 +1,54 @@
+use soroban_sdk::{Address, Bytes, BytesN, contracttype};
+
+/// Longest binary CID accepted for IPFS-hosted documents.
+pub const MAX_CID_LEN: u32 = 64;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    // (asset_id, hash)
+    Document(BytesN<32>, Bytes),
+    AssetDocuments(BytesN<32>),
+}
+
+/// Represents how the document content was hashed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum HashType {
+    Sha256,
+    IpfsCid,
+}
+
+/// Represents what an attached document is
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DocumentKind {
+    Invoice,
+    Receipt,
+    Photo,
+    Manual,
+    Warranty,
+    Other,
+}
+
+/// A tamper-evident reference to an off-chain file. Only the hash is kept
+/// on-chain; the file itself lives in the backend's upload storage or IPFS.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Document {
+    pub asset_id: BytesN<32>,
+    pub hash: Bytes,
+    pub hash_type: HashType,
+    pub kind: DocumentKind,
+    pub uploader: Address,
+    pub uploaded_at: u64,
+}
+
+pub(crate) fn is_valid_hash(hash: &Bytes, hash_type: &HashType) -> bool {
+    match hash_type {
+        HashType::Sha256 => hash.len() == 32,
+        HashType::IpfsCid => !hash.is_empty() && hash.len() <= MAX_CID_LEN,
+    }
+}
+
+// Note: Contract methods implemented in lib.rs
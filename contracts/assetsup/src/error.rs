@@ -20,6 +20,269 @@ pub enum Error {
     Unauthorized = 8,
     // Payment is not valid
     InvalidPayment = 9,
+    // Lien already exists
+    LienAlreadyExists = 10,
+    // Lien not found
+    LienNotFound = 11,
+    // Lien amount must be positive and expiry in the future
+    InvalidLien = 12,
+    // Another lien in force already holds this priority
+    LienPriorityTaken = 13,
+    // Lien is not active
+    LienNotActive = 14,
+    // Asset is encumbered by a lien
+    AssetEncumbered = 15,
+    // Asset has been disposed
+    AssetDisposed = 16,
+    // Policy already exists
+    PolicyAlreadyExists = 17,
+    // Policy not found
+    PolicyNotFound = 18,
+    // Coverage and premium must be positive and the window non-empty
+    InvalidPolicy = 19,
+    // Policy is cancelled or outside its validity window
+    PolicyNotActive = 20,
+    // Claim already exists
+    ClaimAlreadyExists = 21,
+    // Claim not found
+    ClaimNotFound = 22,
+    // Claim amount is not positive or exceeds remaining coverage
+    InvalidClaim = 23,
+    // Claim has already been decided
+    ClaimAlreadyDecided = 24,
+    // Claim is not approved for payout
+    ClaimNotApproved = 25,
+    // Department already exists
+    DepartmentAlreadyExists = 26,
+    // Department not found
+    DepartmentNotFound = 27,
+    // Company already exists
+    CompanyAlreadyExists = 28,
+    // Company not found
+    CompanyNotFound = 29,
+    // Records belong to different companies
+    CompanyMismatch = 30,
+    // Category already exists
+    CategoryAlreadyExists = 31,
+    // Category not found
+    CategoryNotFound = 32,
+    // Depreciation settings are out of range
+    InvalidDepreciationSettings = 33,
+    // Document already attached to this asset
+    DocumentAlreadyExists = 34,
+    // Document not found
+    DocumentNotFound = 35,
+    // Hash length does not match its hash type
+    InvalidDocumentHash = 36,
+    // Tag is already bound to an asset
+    TagAlreadyBound = 37,
+    // Tag not found
+    TagNotFound = 38,
+    // Tag was retired when its asset was re-tagged
+    TagRetired = 39,
+    // Asset already carries a tag
+    AssetAlreadyTagged = 40,
+    // Batch exceeds the maximum size
+    BatchTooLarge = 41,
+    // Inventory item already exists
+    InventoryItemAlreadyExists = 42,
+    // Inventory item not found
+    InventoryItemNotFound = 43,
+    // Stock quantity must be positive
+    InvalidQuantity = 44,
+    // Movement would take stock below zero
+    InsufficientStock = 45,
+    // Asset is not active
+    AssetNotActive = 46,
+    // Asset is travelling on a shipment
+    AssetInTransit = 47,
+    // Asset is checked out under a reservation
+    AssetCheckedOut = 48,
+}
+
+/// Rejections from the input validation layer.
+///
+/// Codes start at 100 so they never collide with `Error`, and each
+/// subsystem enum below takes the next hundred. Methods that can fail with
+/// more than one enum return `soroban_sdk::Error`, which carries the code
+/// of whichever was raised.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ValidationError {
+    // Name is empty
+    EmptyName = 100,
+    // Name is longer than MAX_NAME_LEN bytes
+    NameTooLong = 101,
+    // Purchase cost is negative
+    NegativeCost = 102,
+    // Current value is negative or above the purchase cost
+    InvalidCurrentValue = 103,
+    // Warranty expires before the purchase date
+    WarrantyBeforePurchase = 104,
+    // Id is all zero bytes
+    ZeroId = 105,
+    // Currency code or scale is not valid
+    InvalidCurrency = 106,
+}
+
+/// Purchase order and branch budget failures.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ProcurementError {
+    // Purchase order already exists
+    OrderAlreadyExists = 200,
+    // Purchase order not found
+    OrderNotFound = 201,
+    // Branch has no budget set
+    BudgetNotSet = 202,
+    // Budget amount is negative
+    InvalidBudget = 203,
+    // Quantity or unit cost is not positive, or the total overflows
+    InvalidOrder = 204,
+    // Order currency differs from the branch budget's
+    CurrencyMismatch = 205,
+    // Order total exceeds the remaining branch budget
+    OverBudget = 206,
+    // Order is not awaiting a decision
+    OrderNotRequested = 207,
+    // Order is not approved
+    OrderNotApproved = 208,
+    // Receipt is empty or exceeds the quantity still outstanding
+    InvalidReceipt = 209,
+    // Order has not been received
+    OrderNotReceived = 210,
+    // Order has already been rated
+    OrderAlreadyRated = 211,
+    // Rating is outside 1..=MAX_RATING
+    InvalidRating = 212,
+}
+
+/// Supplier registry failures.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SupplierError {
+    // Supplier already registered with the company
+    SupplierAlreadyExists = 300,
+    // Supplier not found
+    SupplierNotFound = 301,
+    // Supplier is not verified
+    SupplierNotVerified = 302,
+    // Asset has no recorded supplier
+    AssetSupplierNotFound = 303,
+}
+
+/// Vendor contract failures.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VendorContractError {
+    // Vendor contract already exists
+    ContractAlreadyExists = 400,
+    // Vendor contract not found
+    ContractNotFound = 401,
+    // Term does not end after it starts
+    InvalidTerm = 402,
+    // Vendor contract has been terminated
+    ContractNotActive = 403,
+}
+
+/// Shipment failures.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ShipmentError {
+    // Shipment already exists
+    ShipmentAlreadyExists = 500,
+    // Shipment not found
+    ShipmentNotFound = 501,
+    // Shipment has no assets or its origin is its destination
+    InvalidShipment = 502,
+    // Asset is not linked to the origin branch
+    AssetNotAtOrigin = 503,
+    // Shipment is no longer in transit
+    ShipmentNotInTransit = 504,
+}
+
+/// Location tracking failures.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum LocationError {
+    // Building is empty or coordinates are out of range
+    InvalidLocation = 600,
+    // No location reported for the asset
+    LocationNotFound = 601,
+}
+
+/// Cost center failures.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CostCenterError {
+    // Cost center already exists
+    CostCenterAlreadyExists = 700,
+    // Cost center not found
+    CostCenterNotFound = 701,
+    // Share is zero or its cost center appears twice
+    InvalidShare = 702,
+    // Shares do not add up to FULL_ALLOCATION_BPS
+    AllocationIncomplete = 703,
+}
+
+/// Currency and FX rate failures.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CurrencyError {
+    // Branch has no reporting currency
+    ReportingCurrencyNotSet = 800,
+    // No rate for the pair or its inverse
+    FxRateNotFound = 801,
+    // Rate is not positive or quotes a currency in itself
+    InvalidFxRate = 802,
+    // Converted or summed amount does not fit in an i128
+    AmountOverflow = 803,
+}
+
+/// Market price revaluation failures.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum OracleError {
+    // Category has no price feed
+    PriceFeedNotFound = 900,
+    // Oracle has no price for the feed's asset
+    PriceNotFound = 901,
+    // Price is older than the feed's max_age_secs
+    StalePrice = 902,
+    // New value deviates more than max_deviation_bps from the current one
+    DeviationTooLarge = 903,
+}
+
+/// Reservation and check-out failures.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ReservationError {
+    // Reservation not found
+    ReservationNotFound = 1000,
+    // Window is empty or already over
+    InvalidWindow = 1001,
+    // Window overlaps another booking of the asset
+    WindowTaken = 1002,
+    // Reservation is not awaiting a decision
+    ReservationNotPending = 1003,
+    // Reservation is no longer pending or approved
+    ReservationNotActive = 1004,
+    // No reservation covers the current time
+    NoCurrentReservation = 1005,
+    // Reservation is not approved
+    ReservationNotApproved = 1006,
+    // Asset is not checked out
+    AssetNotCheckedOut = 1007,
 }
 
 pub fn handle_error(env: &Env, error: Error) -> ! {
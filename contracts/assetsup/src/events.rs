@@ -0,0 +1,197 @@
+use soroban_sdk::{Address, BytesN, Env, IntoVal, String, Symbol, Val, contracttype};
+
+use crate::{
+    asset::Asset,
+    currency::Currency,
+    types::{AssetStatus, AssetType},
+};
+
+// Event names, used as the first topic. The second topic is the id of the
+// record the event is about.
+pub const COMPANY_CREATED: &str = "company_created";
+pub const COMPANY_ADMIN_CHANGED: &str = "company_admin";
+pub const BRANCH_CREATED: &str = "branch_created";
+pub const ASSET_REGISTERED: &str = "asset_registered";
+pub const ASSET_TRANSFERRED: &str = "asset_transferred";
+pub const ASSET_STATUS_CHANGED: &str = "asset_status";
+pub const ASSET_TOKENIZED: &str = "asset_tokenized";
+pub const ASSET_REVALUED: &str = "asset_revalued";
+pub const ASSET_LINKED: &str = "asset_linked";
+pub const ASSET_UNLINKED: &str = "asset_unlinked";
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    // Sequence number of the next event
+    NextSeq,
+}
+
+// Every payload carries `seq`, numbering the contract's events from 0 with
+// no gaps, so indexers can detect missed events.
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompanyCreated {
+    pub seq: u64,
+    pub company_id: BytesN<32>,
+    pub name: String,
+    pub admin: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompanyAdminChanged {
+    pub seq: u64,
+    pub company_id: BytesN<32>,
+    pub from: Address,
+    pub to: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BranchCreated {
+    pub seq: u64,
+    pub branch_id: BytesN<32>,
+    pub company_id: BytesN<32>,
+    pub name: String,
+    pub admin: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetRegistered {
+    pub seq: u64,
+    pub asset_id: BytesN<32>,
+    pub company_id: BytesN<32>,
+    pub name: String,
+    pub asset_type: AssetType,
+    pub status: AssetStatus,
+    pub owner: Address,
+    pub purchase_cost: i128,
+    pub current_value: i128,
+    pub currency: Currency,
+    /// Zero when the asset is registered untokenized
+    pub stellar_token_id: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetTransferred {
+    pub seq: u64,
+    pub asset_id: BytesN<32>,
+    pub from: Address,
+    pub to: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetStatusChanged {
+    pub seq: u64,
+    pub asset_id: BytesN<32>,
+    pub from: AssetStatus,
+    pub to: AssetStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetTokenized {
+    pub seq: u64,
+    pub asset_id: BytesN<32>,
+    pub token_id: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetRevalued {
+    pub seq: u64,
+    pub asset_id: BytesN<32>,
+    pub current_value: i128,
+}
+
+/// Link or unlink between an asset and a branch.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetBranchLink {
+    pub seq: u64,
+    pub asset_id: BytesN<32>,
+    pub branch_id: BytesN<32>,
+}
+
+/// Claim the next sequence number.
+pub(crate) fn next_seq(env: &Env) -> u64 {
+    let store = env.storage().persistent();
+    let seq: u64 = store.get(&DataKey::NextSeq).unwrap_or(0);
+    store.set(&DataKey::NextSeq, &(seq + 1));
+    seq
+}
+
+pub(crate) fn publish<D: IntoVal<Env, Val>>(env: &Env, name: &str, id: &BytesN<32>, data: D) {
+    env.events()
+        .publish((Symbol::new(env, name), id.clone()), data);
+}
+
+pub(crate) fn asset_registered(env: &Env, a: &Asset) {
+    let data = AssetRegistered {
+        seq: next_seq(env),
+        asset_id: a.id.clone(),
+        company_id: a.company_id.clone(),
+        name: a.name.clone(),
+        asset_type: a.asset_type.clone(),
+        status: a.status.clone(),
+        owner: a.owner.clone(),
+        purchase_cost: a.purchase_cost,
+        current_value: a.current_value,
+        currency: a.currency.clone(),
+        stellar_token_id: a.stellar_token_id.clone(),
+    };
+    publish(env, ASSET_REGISTERED, &a.id, data);
+}
+
+/// Publish one event per tracked field that differs between the stored and
+/// the new version of an asset.
+pub(crate) fn asset_changed(env: &Env, old: &Asset, new: &Asset) {
+    if old.owner != new.owner {
+        let data = AssetTransferred {
+            seq: next_seq(env),
+            asset_id: new.id.clone(),
+            from: old.owner.clone(),
+            to: new.owner.clone(),
+        };
+        publish(env, ASSET_TRANSFERRED, &new.id, data);
+    }
+    if old.status != new.status {
+        let data = AssetStatusChanged {
+            seq: next_seq(env),
+            asset_id: new.id.clone(),
+            from: old.status.clone(),
+            to: new.status.clone(),
+        };
+        publish(env, ASSET_STATUS_CHANGED, &new.id, data);
+    }
+    if old.stellar_token_id != new.stellar_token_id {
+        let data = AssetTokenized {
+            seq: next_seq(env),
+            asset_id: new.id.clone(),
+            token_id: new.stellar_token_id.clone(),
+        };
+        publish(env, ASSET_TOKENIZED, &new.id, data);
+    }
+    if old.current_value != new.current_value {
+        let data = AssetRevalued {
+            seq: next_seq(env),
+            asset_id: new.id.clone(),
+            current_value: new.current_value,
+        };
+        publish(env, ASSET_REVALUED, &new.id, data);
+    }
+}
+
+pub(crate) fn asset_link(env: &Env, asset_id: &BytesN<32>, branch_id: &BytesN<32>, linked: bool) {
+    let data = AssetBranchLink {
+        seq: next_seq(env),
+        asset_id: asset_id.clone(),
+        branch_id: branch_id.clone(),
+    };
+    let name = if linked { ASSET_LINKED } else { ASSET_UNLINKED };
+    publish(env, name, asset_id, data);
+}
@@ -0,0 +1,158 @@
+use soroban_sdk::{Address, BytesN, Env, Vec, contracttype};
+
+/// Ids per stored page. Keeps every index entry small however long the
+/// list grows.
+pub const PAGE_SIZE: u32 = 64;
+
+/// An id list kept by the contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum List {
+    // branch_id
+    Branch(BytesN<32>),
+    // (company_id, category_id)
+    Category(BytesN<32>, u64),
+    // (company_id, department_id)
+    Department(BytesN<32>, u64),
+    // Undisposed assets held by an address, across companies
+    Owner(Address),
+    // (company_id, cost_center_id)
+    CostCenter(BytesN<32>, u64),
+    // (company_id, supplier)
+    Supplier(BytesN<32>, Address),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    // list -> Tail
+    Tail(List),
+    // (list, page number) -> Vec of PAGE_SIZE ids; only full pages are
+    // stored here
+    Page(List, u32),
+}
+
+/// Where an id sits in one of the lists it belongs to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Slot {
+    pub list: List,
+    pub position: u32,
+}
+
+/// Length of a list and its last, partly filled page. A list of `len` ids
+/// has `len / PAGE_SIZE` full pages before the tail.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tail {
+    pub len: u32,
+    pub ids: Vec<BytesN<32>>,
+}
+
+fn tail(env: &Env, list: &List) -> Tail {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Tail(list.clone()))
+        .unwrap_or_else(|| Tail {
+            len: 0,
+            ids: Vec::new(env),
+        })
+}
+
+fn page(env: &Env, list: &List, number: u32) -> Vec<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Page(list.clone(), number))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub(crate) fn len(env: &Env, list: &List) -> u32 {
+    tail(env, list).len
+}
+
+/// Ledger entries written by appending `count` ids to a list: the tail,
+/// plus one for every page the append fills.
+pub(crate) fn push_writes(env: &Env, list: &List, count: u32) -> u32 {
+    let filled = tail(env, list).ids.len() + count;
+    1 + filled / PAGE_SIZE
+}
+
+/// Append ids to a list and return the position of the first one.
+pub(crate) fn push(env: &Env, list: &List, ids: &Vec<BytesN<32>>) -> u32 {
+    let store = env.storage().persistent();
+    let mut t = tail(env, list);
+    let first = t.len;
+    for id in ids.iter() {
+        t.ids.push_back(id);
+        t.len += 1;
+        if t.ids.len() == PAGE_SIZE {
+            store.set(&DataKey::Page(list.clone(), t.len / PAGE_SIZE - 1), &t.ids);
+            t.ids = Vec::new(env);
+        }
+    }
+    store.set(&DataKey::Tail(list.clone()), &t);
+    first
+}
+
+/// Remove the id at `position` by moving the last id into its place.
+/// Returns the moved id, if any, so the caller can record its new position.
+pub(crate) fn swap_remove(env: &Env, list: &List, position: u32) -> Option<BytesN<32>> {
+    let store = env.storage().persistent();
+    let mut t = tail(env, list);
+    if position >= t.len {
+        return None;
+    }
+
+    // Make sure the last id sits in the tail
+    if t.ids.is_empty() {
+        let key = DataKey::Page(list.clone(), t.len / PAGE_SIZE - 1);
+        t.ids = page(env, list, t.len / PAGE_SIZE - 1);
+        store.remove(&key);
+    }
+    let last = t.ids.pop_back_unchecked();
+    t.len -= 1;
+
+    let moved = if position == t.len {
+        None
+    } else {
+        let number = position / PAGE_SIZE;
+        let offset = position % PAGE_SIZE;
+        if number == t.len / PAGE_SIZE {
+            t.ids.set(offset, last.clone());
+        } else {
+            let mut ids = page(env, list, number);
+            ids.set(offset, last.clone());
+            store.set(&DataKey::Page(list.clone(), number), &ids);
+        }
+        Some(last)
+    };
+    store.set(&DataKey::Tail(list.clone()), &t);
+    moved
+}
+
+/// Up to `limit` ids starting at position `start`.
+pub(crate) fn range(env: &Env, list: &List, start: u32, limit: u32) -> Vec<BytesN<32>> {
+    let t = tail(env, list);
+    let end = start.saturating_add(limit).min(t.len);
+    let mut ids = Vec::new(env);
+    let mut position = start;
+    while position < end {
+        let number = position / PAGE_SIZE;
+        let page_ids = if number == t.len / PAGE_SIZE {
+            t.ids.clone()
+        } else {
+            page(env, list, number)
+        };
+        let page_end = ((number + 1) * PAGE_SIZE).min(end);
+        for offset in position % PAGE_SIZE..page_end - number * PAGE_SIZE {
+            ids.push_back(page_ids.get_unchecked(offset));
+        }
+        position = page_end;
+    }
+    ids
+}
+
+/// Every id in a list.
+pub(crate) fn all(env: &Env, list: &List) -> Vec<BytesN<32>> {
+    range(env, list, 0, u32::MAX)
+}
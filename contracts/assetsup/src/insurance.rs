@@ -0,0 +1,77 @@
+use soroban_sdk::{Address, BytesN, contracttype};
+
+use crate::currency::Currency;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Policy(BytesN<32>),
+    AssetPolicies(BytesN<32>),
+    Claim(BytesN<32>),
+    AssetClaims(BytesN<32>),
+}
+
+/// Represents whether a policy still provides coverage
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PolicyStatus {
+    Active,
+    Cancelled,
+}
+
+/// Represents where a claim is in the insurer's decision process
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClaimStatus {
+    Filed,
+    Approved,
+    Rejected,
+    Paid,
+}
+
+/// Coverage issued by an insurer for a single asset.
+/// `policy_hash` anchors the off-chain policy document. Coverage, premium
+/// and claims are denominated in `currency`, and approved claims are paid
+/// out in `payout_token`, both fixed when the policy is created.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsurancePolicy {
+    pub id: BytesN<32>,
+    pub asset_id: BytesN<32>,
+    pub insurer: Address,
+    pub policy_hash: BytesN<32>,
+    pub coverage_amount: i128,
+    pub premium: i128,
+    pub currency: Currency,
+    pub payout_token: Address,
+    pub start_date: u64,
+    pub end_date: u64,
+    pub approved_total: i128,
+    pub status: PolicyStatus,
+}
+
+impl InsurancePolicy {
+    pub fn covers(&self, now: u64) -> bool {
+        self.status == PolicyStatus::Active && self.start_date <= now && now < self.end_date
+    }
+}
+
+/// A loss claim filed by the asset owner against a policy, in the policy's
+/// currency. `approved_amount` is zero until the insurer approves the claim.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Claim {
+    pub id: BytesN<32>,
+    pub policy_id: BytesN<32>,
+    pub asset_id: BytesN<32>,
+    pub claimant: Address,
+    pub amount: i128,
+    pub approved_amount: i128,
+    pub currency: Currency,
+    pub evidence_hash: BytesN<32>,
+    pub status: ClaimStatus,
+    pub filed_at: u64,
+    pub decided_at: u64,
+}
+
+// Note: Contract methods implemented in lib.rs
@@ -0,0 +1,110 @@
+use soroban_sdk::{Address, BytesN, Env, String, contracttype};
+
+use crate::error::Error;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    // (branch_id, sku)
+    Item(BytesN<32>, String),
+    // branch_id -> Vec<sku>
+    BranchItems(BytesN<32>),
+    // (branch_id, sku, sequence number)
+    Movement(BytesN<32>, String, u32),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MovementKind {
+    Receive,
+    Issue,
+    Adjust,
+    TransferIn,
+    TransferOut,
+}
+
+/// Stock of one consumable SKU held by a branch.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InventoryItem {
+    pub branch_id: BytesN<32>,
+    pub sku: String,
+    pub name: String,
+    pub quantity: u64,
+    pub movement_count: u32,
+}
+
+/// One entry of an item's stock ledger. `change` is signed so that issues,
+/// outgoing transfers and downward adjustments read as negative.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StockMovement {
+    pub seq: u32,
+    pub kind: MovementKind,
+    pub change: i128,
+    pub balance_after: u64,
+    pub reason: String,
+    pub actor: Address,
+    // Other branch of an inter-branch transfer
+    pub counterpart: Option<BytesN<32>>,
+    pub timestamp: u64,
+}
+
+pub(crate) fn load(
+    env: &Env,
+    branch_id: &BytesN<32>,
+    sku: &String,
+) -> Result<InventoryItem, Error> {
+    match env
+        .storage()
+        .persistent()
+        .get(&DataKey::Item(branch_id.clone(), sku.clone()))
+    {
+        Some(item) => Ok(item),
+        None => Err(Error::InventoryItemNotFound),
+    }
+}
+
+/// Apply `change` to an item, append it to the item's movement history and
+/// persist both. Fails with `InsufficientStock` rather than letting the
+/// quantity on hand go negative.
+pub(crate) fn apply(
+    env: &Env,
+    item: &mut InventoryItem,
+    kind: MovementKind,
+    change: i128,
+    reason: String,
+    actor: Address,
+    counterpart: Option<BytesN<32>>,
+) -> Result<(), Error> {
+    let balance = item.quantity as i128 + change;
+    if balance < 0 {
+        return Err(Error::InsufficientStock);
+    }
+    item.quantity = u64::try_from(balance).map_err(|_| Error::InvalidQuantity)?;
+
+    let movement = StockMovement {
+        seq: item.movement_count,
+        kind,
+        change,
+        balance_after: item.quantity,
+        reason,
+        actor,
+        counterpart,
+        timestamp: env.ledger().timestamp(),
+    };
+    let store = env.storage().persistent();
+    store.set(
+        &DataKey::Movement(item.branch_id.clone(), item.sku.clone(), movement.seq),
+        &movement,
+    );
+
+    item.movement_count += 1;
+    store.set(
+        &DataKey::Item(item.branch_id.clone(), item.sku.clone()),
+        item,
+    );
+    Ok(())
+}
+
+// Note: Contract methods implemented in lib.rs
@@ -1,13 +1,41 @@
 #![no_std]
+#![allow(clippy::too_many_arguments)]
 
-use crate::error::{Error, handle_error};
-use soroban_sdk::{Address, BytesN, Env, String, Vec, contract, contractimpl, contracttype};
+use crate::error::{
+    CostCenterError, CurrencyError, Error, LocationError, OracleError, ProcurementError,
+    ReservationError, ShipmentError, SupplierError, ValidationError, VendorContractError,
+};
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, String, Symbol, Vec, contract, contractimpl, contracttype, token,
+    vec,
+};
 
-pub(crate) mod asset;
-pub(crate) mod branch;
-pub(crate) mod error;
+pub mod asset;
+pub mod branch;
+pub mod category;
+pub mod company;
+pub mod cost_center;
+pub mod currency;
+pub mod department;
+pub mod document;
+pub mod error;
 pub(crate) mod errors;
-pub(crate) mod types;
+pub mod events;
+pub mod index;
+pub mod insurance;
+pub mod inventory;
+pub mod lien;
+pub mod location;
+pub mod oracle;
+pub mod procurement;
+pub mod reservation;
+pub mod shipment;
+pub mod stats;
+pub mod supplier;
+pub mod tag;
+pub mod types;
+pub mod validation;
+pub mod vendor_contract;
 
 pub use types::*;
 
@@ -26,153 +54,651 @@ impl AssetUpContract {
         admin.require_auth();
 
         if env.storage().persistent().has(&DataKey::Admin) {
-            handle_error(&env, Error::AlreadyInitialized)
+            return Err(Error::AlreadyInitialized);
         }
         env.storage().persistent().set(&DataKey::Admin, &admin);
         Ok(())
     }
 
     pub fn get_admin(env: Env) -> Result<Address, Error> {
-        let key = DataKey::Admin;
-        if !env.storage().persistent().has(&key) {
-            handle_error(&env, Error::AdminNotFound)
+        env.storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotFound)
+    }
+
+    // Company functions
+
+    /// Create a company (tenant) with its own admin.
+    ///
+    /// Access: Only the contract admin (set during `initialize`) can call this.
+    ///
+    /// The company admin, not the contract admin, manages the company's
+    /// branches and assets from then on.
+    pub fn create_company(
+        env: Env,
+        company_id: BytesN<32>,
+        name: String,
+        admin: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        let contract_admin = Self::get_admin(env.clone())?;
+        contract_admin.require_auth();
+
+        validation::check_id(&env, &company_id)?;
+        validation::check_name(&name)?;
+
+        let key = company::DataKey::Company(company_id.clone());
+        let store = env.storage().persistent();
+        if store.has(&key) {
+            return Err(Error::CompanyAlreadyExists.into());
+        }
+
+        let company = company::Company {
+            id: company_id.clone(),
+            name: name.clone(),
+            admin: admin.clone(),
+        };
+        store.set(&key, &company);
+
+        let data = events::CompanyCreated {
+            seq: events::next_seq(&env),
+            company_id: company_id.clone(),
+            name,
+            admin,
+        };
+        events::publish(&env, events::COMPANY_CREATED, &company_id, data);
+        Ok(())
+    }
+
+    /// Hand a company over to a new admin.
+    ///
+    /// Access: Only the current company admin can call this.
+    pub fn set_company_admin(
+        env: Env,
+        company_id: BytesN<32>,
+        admin: Address,
+    ) -> Result<(), Error> {
+        let mut company = Self::get_company(env.clone(), company_id.clone())?;
+        company.admin.require_auth();
+
+        let from = company.admin.clone();
+        company.admin = admin.clone();
+        env.storage()
+            .persistent()
+            .set(&company::DataKey::Company(company_id.clone()), &company);
+
+        let data = events::CompanyAdminChanged {
+            seq: events::next_seq(&env),
+            company_id: company_id.clone(),
+            from,
+            to: admin,
+        };
+        events::publish(&env, events::COMPANY_ADMIN_CHANGED, &company_id, data);
+        Ok(())
+    }
+
+    pub fn get_company(env: Env, company_id: BytesN<32>) -> Result<company::Company, Error> {
+        let key = company::DataKey::Company(company_id);
+        match env.storage().persistent().get(&key) {
+            Some(c) => Ok(c),
+            None => Err(Error::CompanyNotFound),
         }
+    }
 
-        let admin = env.storage().persistent().get(&key).unwrap();
-        Ok(admin)
+    pub fn get_company_branches(
+        env: Env,
+        company_id: BytesN<32>,
+    ) -> Result<Vec<BytesN<32>>, Error> {
+        Self::get_company(env.clone(), company_id.clone())?;
+
+        let key = company::DataKey::BranchList(company_id);
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(&env)))
     }
 
     // Asset functions
-    pub fn register_asset(env: Env, asset: asset::Asset) -> Result<(), Error> {
+
+    /// Register an asset in its company's namespace.
+    ///
+    /// Access: Both the asset owner and the admin of `asset.company_id` must
+    /// authorize the call.
+    ///
+    /// Asset ids are unique across companies so that assets can be looked up
+    /// by id alone. Malformed fields are rejected with the matching
+    /// `ValidationError`.
+    pub fn register_asset(env: Env, asset: asset::Asset) -> Result<(), soroban_sdk::Error> {
         // Access control
         asset.owner.require_auth();
+        let company = Self::get_company(env.clone(), asset.company_id.clone())?;
+        company.admin.require_auth();
+
+        validation::check_asset(&env, &asset)?;
+
+        asset::check_new(&env, &asset)?;
+        asset::insert(&env, &vec![&env, asset], &Vec::new(&env));
+        Ok(())
+    }
 
-        if asset.name.is_empty() {
-            panic!("Name cannot be empty");
+    /// Register many assets in one transaction.
+    ///
+    /// Access: Every distinct owner and every distinct company admin in the
+    /// batch must authorize the call; each address is asked only once.
+    ///
+    /// Behavior:
+    /// - At most `MAX_BATCH_SIZE` assets per call, and no more than fit in
+    ///   `MAX_WRITE_ENTRIES` ledger writes; larger batches are rejected with
+    ///   `BatchTooLarge` before anything is written.
+    /// - Invalid rows are skipped and reported instead of aborting the batch;
+    ///   the result vector is aligned with `assets`.
+    /// - When `branch_id` is given, every registered asset is also linked to
+    ///   that branch; assets from another company are reported as
+    ///   `CompanyMismatch`.
+    pub fn register_assets(
+        env: Env,
+        assets: Vec<asset::Asset>,
+        branch_id: Option<BytesN<32>>,
+    ) -> Result<Vec<asset::RegistrationResult>, Error> {
+        if assets.len() > asset::MAX_BATCH_SIZE {
+            return Err(Error::BatchTooLarge);
         }
 
-        let key = asset::DataKey::Asset(asset.id.clone());
+        let branch = match branch_id {
+            Some(id) => Some(Self::get_branch(env.clone(), id)?),
+            None => None,
+        };
+
         let store = env.storage().persistent();
-        if store.has(&key) {
-            return Err(Error::AssetAlreadyExists);
+        let mut signers: Vec<Address> = Vec::new(&env);
+        let mut accepted: Vec<asset::Asset> = Vec::new(&env);
+        let mut results = Vec::new(&env);
+
+        for a in assets.iter() {
+            let status = match store
+                .get::<_, company::Company>(&company::DataKey::Company(a.company_id.clone()))
+            {
+                None => asset::RegistrationStatus::CompanyNotFound,
+                Some(_)
+                    if branch
+                        .as_ref()
+                        .is_some_and(|b| b.company_id != a.company_id) =>
+                {
+                    asset::RegistrationStatus::CompanyMismatch
+                }
+                Some(company) => match validation::check_asset(&env, &a) {
+                    Err(ValidationError::EmptyName | ValidationError::NameTooLong) => {
+                        asset::RegistrationStatus::InvalidName
+                    }
+                    Err(ValidationError::NegativeCost) => asset::RegistrationStatus::NegativeCost,
+                    Err(ValidationError::InvalidCurrentValue) => {
+                        asset::RegistrationStatus::InvalidCurrentValue
+                    }
+                    Err(ValidationError::WarrantyBeforePurchase) => {
+                        asset::RegistrationStatus::WarrantyBeforePurchase
+                    }
+                    Err(ValidationError::ZeroId) => asset::RegistrationStatus::ZeroId,
+                    Err(ValidationError::InvalidCurrency) => {
+                        asset::RegistrationStatus::InvalidCurrency
+                    }
+                    Ok(()) if accepted.iter().any(|b| b.id == a.id) => {
+                        asset::RegistrationStatus::AlreadyExists
+                    }
+                    Ok(()) => match asset::check_new(&env, &a) {
+                        Err(Error::AssetAlreadyExists) => asset::RegistrationStatus::AlreadyExists,
+                        Err(Error::CategoryNotFound) => asset::RegistrationStatus::CategoryNotFound,
+                        Err(Error::DepartmentNotFound) => {
+                            asset::RegistrationStatus::DepartmentNotFound
+                        }
+                        Err(e) => return Err(e),
+                        Ok(()) => {
+                            for signer in [a.owner.clone(), company.admin] {
+                                if !signers.contains(&signer) {
+                                    signers.push_back(signer);
+                                }
+                            }
+                            accepted.push_back(a.clone());
+                            asset::RegistrationStatus::Registered
+                        }
+                    },
+                },
+            };
+            results.push_back(asset::RegistrationResult {
+                asset_id: a.id.clone(),
+                status,
+            });
         }
-        store.set(&key, &asset);
-        Ok(())
+
+        // Each signer also writes an auth nonce
+        let mut links = Vec::new(&env);
+        if let Some(b) = branch {
+            links.push_back(index::List::Branch(b.id));
+        }
+        if asset::insert_writes(&env, &accepted, &links) + signers.len() > asset::MAX_WRITE_ENTRIES
+        {
+            return Err(Error::BatchTooLarge);
+        }
+
+        for signer in signers.iter() {
+            signer.require_auth();
+        }
+        asset::insert(&env, &accepted, &links);
+        Ok(results)
     }
 
     pub fn get_asset(env: Env, asset_id: BytesN<32>) -> Result<asset::Asset, Error> {
-        let key = asset::DataKey::Asset(asset_id);
-        let store = env.storage().persistent();
-        match store.get::<_, asset::Asset>(&key) {
+        match asset::load(&env, &asset_id) {
             Some(a) => Ok(a),
             None => Err(Error::AssetNotFound),
         }
     }
 
+    /// Transfer ownership of an asset to `new_owner`.
+    ///
+    /// Access: Only the current owner can call this.
+    ///
+    /// Fails with `AssetEncumbered` while any lien on the asset is in force,
+    /// and with `AssetInTransit` or `AssetCheckedOut` while the asset is
+    /// away.
+    pub fn transfer_asset(env: Env, asset_id: BytesN<32>, new_owner: Address) -> Result<(), Error> {
+        let mut a = Self::get_asset(env.clone(), asset_id.clone())?;
+        a.owner.require_auth();
+
+        if a.status == AssetStatus::Disposed {
+            return Err(Error::AssetDisposed);
+        }
+        if a.status == AssetStatus::InTransit {
+            return Err(Error::AssetInTransit);
+        }
+        if reservation::is_checked_out(&env, &asset_id) {
+            return Err(Error::AssetCheckedOut);
+        }
+        if lien::is_encumbered(&env, &asset_id) {
+            return Err(Error::AssetEncumbered);
+        }
+
+        a.owner = new_owner;
+        asset::save(&env, &a);
+        Ok(())
+    }
+
+    /// Mark an asset as disposed.
+    ///
+    /// Access: Only the current owner can call this.
+    ///
+    /// Fails with `AssetEncumbered` while any lien on the asset is in force,
+    /// and with `AssetInTransit` or `AssetCheckedOut` while the asset is
+    /// away.
+    pub fn dispose_asset(env: Env, asset_id: BytesN<32>) -> Result<(), Error> {
+        let mut a = Self::get_asset(env.clone(), asset_id.clone())?;
+        a.owner.require_auth();
+
+        if a.status == AssetStatus::Disposed {
+            return Err(Error::AssetDisposed);
+        }
+        if a.status == AssetStatus::InTransit {
+            return Err(Error::AssetInTransit);
+        }
+        if reservation::is_checked_out(&env, &asset_id) {
+            return Err(Error::AssetCheckedOut);
+        }
+        if lien::is_encumbered(&env, &asset_id) {
+            return Err(Error::AssetEncumbered);
+        }
+
+        a.status = AssetStatus::Disposed;
+        asset::save(&env, &a);
+        Ok(())
+    }
+
     // Branch functions
     pub fn create_branch(
         env: Env,
+        company_id: BytesN<32>,
         id: BytesN<32>,
         name: String,
         location: String,
         admin: Address,
-    ) -> Result<(), Error> {
-        // Enforce admin-only access for branch creation
-        let contract_admin = Self::get_admin(env.clone())?;
-        contract_admin.require_auth();
+    ) -> Result<(), soroban_sdk::Error> {
+        // Enforce company-admin-only access for branch creation
+        let company = Self::get_company(env.clone(), company_id.clone())?;
+        company.admin.require_auth();
 
-        if name.is_empty() {
-            panic!("Branch name cannot be empty");
-        }
+        validation::check_id(&env, &id)?;
+        validation::check_name(&name)?;
 
-        let key = branch::DataKey::Branch(id.clone());
         let store = env.storage().persistent();
-        if store.has(&key) {
-            return Err(Error::BranchAlreadyExists);
+        if store.has(&branch::DataKey::CompanyOf(id.clone())) {
+            return Err(Error::BranchAlreadyExists.into());
         }
 
         let branch = branch::Branch {
             id: id.clone(),
+            company_id: company_id.clone(),
             name,
             location,
             admin,
         };
 
-        store.set(&key, &branch);
+        store.set(
+            &branch::DataKey::Branch(company_id.clone(), id.clone()),
+            &branch,
+        );
+        store.set(&branch::DataKey::CompanyOf(id.clone()), &company_id);
+
+        let data = events::BranchCreated {
+            seq: events::next_seq(&env),
+            branch_id: id.clone(),
+            company_id: company_id.clone(),
+            name: branch.name,
+            admin: branch.admin,
+        };
+        events::publish(&env, events::BRANCH_CREATED, &id, data);
 
-        // Initialize empty asset list for this branch
-        let asset_list_key = branch::DataKey::AssetList(id);
-        let empty_asset_list: Vec<BytesN<32>> = Vec::new(&env);
-        store.set(&asset_list_key, &empty_asset_list);
+        let branch_list_key = company::DataKey::BranchList(company_id);
+        let mut branches: Vec<BytesN<32>> = store
+            .get(&branch_list_key)
+            .unwrap_or_else(|| Vec::new(&env));
+        branches.push_back(id);
+        store.set(&branch_list_key, &branches);
 
         Ok(())
     }
 
+    /// Link an asset to a branch of the same company.
+    ///
+    /// Access: Only the admin of the branch's company can call this.
     pub fn add_asset_to_branch(
         env: Env,
         branch_id: BytesN<32>,
         asset_id: BytesN<32>,
     ) -> Result<(), Error> {
         // Verify branch exists
-        let branch_key = branch::DataKey::Branch(branch_id.clone());
-        let store = env.storage().persistent();
-        if !store.has(&branch_key) {
-            return Err(Error::BranchNotFound);
+        let branch = Self::get_branch(env.clone(), branch_id.clone())?;
+        let company = Self::get_company(env.clone(), branch.company_id.clone())?;
+        company.admin.require_auth();
+
+        // Verify asset exists and belongs to the same company
+        let a = Self::get_asset(env.clone(), asset_id.clone())?;
+        if a.company_id != branch.company_id {
+            return Err(Error::CompanyMismatch);
         }
 
-        // Verify asset exists
-        let asset_key = asset::DataKey::Asset(asset_id.clone());
-        if !store.has(&asset_key) {
-            return Err(Error::AssetNotFound);
+        // Already linked assets are left as they are
+        branch::add_asset(&env, &branch_id, &a);
+
+        Ok(())
+    }
+
+    /// Page through the assets linked to a branch.
+    pub fn get_branch_assets(
+        env: Env,
+        branch_id: BytesN<32>,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<BytesN<32>>, Error> {
+        // Verify branch exists
+        Self::get_branch(env.clone(), branch_id.clone())?;
+
+        let list = index::List::Branch(branch_id);
+        Ok(index::range(&env, &list, start, limit))
+    }
+
+    pub fn get_branch(env: Env, branch_id: BytesN<32>) -> Result<branch::Branch, Error> {
+        match branch::load(&env, &branch_id) {
+            Some(branch) => Ok(branch),
+            None => Err(Error::BranchNotFound),
         }
+    }
 
-        // Get current asset list
-        let asset_list_key = branch::DataKey::AssetList(branch_id);
-        let mut asset_list: Vec<BytesN<32>> =
-            store.get(&asset_list_key).unwrap_or_else(|| Vec::new(&env));
+    // Department functions
 
-        // Check if asset is already in the list
-        for existing_asset_id in asset_list.iter() {
-            if existing_asset_id == asset_id {
-                return Ok(()); // Asset already linked, no error
-            }
+    /// Create a department under an existing branch.
+    ///
+    /// Access: Only the branch admin can call this.
+    ///
+    /// `department_id` is the value assets carry in `Asset.department_id`
+    /// and must be unique within the branch's company; `0` is reserved for
+    /// unassigned assets.
+    pub fn create_department(
+        env: Env,
+        branch_id: BytesN<32>,
+        department_id: u64,
+        name: String,
+        head: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        let branch = Self::get_branch(env.clone(), branch_id.clone())?;
+        branch.admin.require_auth();
+
+        validation::check_name(&name)?;
+
+        let key = department::DataKey::Department(branch.company_id.clone(), department_id);
+        let store = env.storage().persistent();
+        if department_id == department::UNASSIGNED || store.has(&key) {
+            return Err(Error::DepartmentAlreadyExists.into());
         }
 
-        // Add asset to the list
-        asset_list.push_back(asset_id);
-        store.set(&asset_list_key, &asset_list);
+        let dept = department::Department {
+            id: department_id,
+            company_id: branch.company_id,
+            branch_id: branch_id.clone(),
+            name,
+            head,
+        };
+        store.set(&key, &dept);
+
+        let list_key = department::DataKey::BranchDepartments(branch_id);
+        let mut ids: Vec<u64> = store.get(&list_key).unwrap_or_else(|| Vec::new(&env));
+        ids.push_back(department_id);
+        store.set(&list_key, &ids);
 
         Ok(())
     }
 
-    pub fn get_branch_assets(env: Env, branch_id: BytesN<32>) -> Result<Vec<BytesN<32>>, Error> {
-        // Verify branch exists
-        let branch_key = branch::DataKey::Branch(branch_id.clone());
+    /// Replace the head of a department.
+    ///
+    /// Access: Only the admin of the department's branch can call this.
+    pub fn set_department_head(
+        env: Env,
+        company_id: BytesN<32>,
+        department_id: u64,
+        head: Address,
+    ) -> Result<(), Error> {
+        let mut dept = Self::get_department(env.clone(), company_id.clone(), department_id)?;
+        let branch = Self::get_branch(env.clone(), dept.branch_id.clone())?;
+        branch.admin.require_auth();
+
+        dept.head = head;
+        env.storage().persistent().set(
+            &department::DataKey::Department(company_id, department_id),
+            &dept,
+        );
+        Ok(())
+    }
+
+    /// Move an asset into a department of its company.
+    ///
+    /// Access: Only the head of the receiving department can call this.
+    pub fn assign_asset_to_department(
+        env: Env,
+        asset_id: BytesN<32>,
+        department_id: u64,
+    ) -> Result<(), Error> {
+        let mut a = Self::get_asset(env.clone(), asset_id.clone())?;
+        let dept = Self::get_department(env.clone(), a.company_id.clone(), department_id)?;
+        dept.head.require_auth();
+
+        a.department_id = department_id;
+        asset::save(&env, &a);
+        Ok(())
+    }
+
+    pub fn get_department(
+        env: Env,
+        company_id: BytesN<32>,
+        department_id: u64,
+    ) -> Result<department::Department, Error> {
+        let key = department::DataKey::Department(company_id, department_id);
+        match env.storage().persistent().get(&key) {
+            Some(d) => Ok(d),
+            None => Err(Error::DepartmentNotFound),
+        }
+    }
+
+    pub fn get_branch_departments(env: Env, branch_id: BytesN<32>) -> Result<Vec<u64>, Error> {
+        Self::get_branch(env.clone(), branch_id.clone())?;
+
+        let key = department::DataKey::BranchDepartments(branch_id);
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(&env)))
+    }
+
+    /// Page through the assets assigned to a department.
+    pub fn get_department_assets(
+        env: Env,
+        company_id: BytesN<32>,
+        department_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<BytesN<32>>, Error> {
+        Self::get_department(env.clone(), company_id.clone(), department_id)?;
+
+        let list = index::List::Department(company_id, department_id);
+        Ok(index::range(&env, &list, start, limit))
+    }
+
+    // Category functions
+
+    /// Create an asset category for a company.
+    ///
+    /// Access: Only the company admin can call this.
+    ///
+    /// Behavior:
+    /// - `category_id` must be non-zero and unique within the company.
+    /// - `parent_id` is `0` for a top-level category, otherwise it must name
+    ///   an existing category of the same company.
+    /// - `depreciation` supplies the defaults for assets in this category;
+    ///   `salvage_value_bps` may not exceed 10000.
+    pub fn create_category(
+        env: Env,
+        company_id: BytesN<32>,
+        category_id: u64,
+        name: String,
+        parent_id: u64,
+        depreciation: category::DepreciationSettings,
+    ) -> Result<(), soroban_sdk::Error> {
+        let company = Self::get_company(env.clone(), company_id.clone())?;
+        company.admin.require_auth();
+
+        validation::check_name(&name)?;
+
+        let key = category::DataKey::Category(company_id.clone(), category_id);
         let store = env.storage().persistent();
-        if !store.has(&branch_key) {
-            return Err(Error::BranchNotFound);
+        if category_id == category::ROOT || store.has(&key) {
+            return Err(Error::CategoryAlreadyExists.into());
+        }
+        if parent_id != category::ROOT
+            && !store.has(&category::DataKey::Category(company_id.clone(), parent_id))
+        {
+            return Err(Error::CategoryNotFound.into());
+        }
+        if depreciation.salvage_value_bps > 10_000 {
+            return Err(Error::InvalidDepreciationSettings.into());
+        }
+
+        let c = category::Category {
+            id: category_id,
+            company_id: company_id.clone(),
+            name,
+            parent_id,
+            depreciation,
+        };
+        store.set(&key, &c);
+
+        let children_key = category::DataKey::Children(company_id, parent_id);
+        let mut children: Vec<u64> = store.get(&children_key).unwrap_or_else(|| Vec::new(&env));
+        children.push_back(category_id);
+        store.set(&children_key, &children);
+
+        Ok(())
+    }
+
+    /// Replace a category's default depreciation settings.
+    ///
+    /// Access: Only the company admin can call this.
+    pub fn set_category_depreciation(
+        env: Env,
+        company_id: BytesN<32>,
+        category_id: u64,
+        depreciation: category::DepreciationSettings,
+    ) -> Result<(), Error> {
+        let mut c = Self::get_category(env.clone(), company_id.clone(), category_id)?;
+        let company = Self::get_company(env.clone(), company_id.clone())?;
+        company.admin.require_auth();
+
+        if depreciation.salvage_value_bps > 10_000 {
+            return Err(Error::InvalidDepreciationSettings);
         }
 
-        // Get asset list
-        let asset_list_key = branch::DataKey::AssetList(branch_id);
-        match store.get(&asset_list_key) {
-            Some(asset_list) => Ok(asset_list),
-            None => Ok(Vec::new(&env)), // Return empty list if no assets
+        c.depreciation = depreciation;
+        env.storage()
+            .persistent()
+            .set(&category::DataKey::Category(company_id, category_id), &c);
+        Ok(())
+    }
+
+    pub fn get_category(
+        env: Env,
+        company_id: BytesN<32>,
+        category_id: u64,
+    ) -> Result<category::Category, Error> {
+        let key = category::DataKey::Category(company_id, category_id);
+        match env.storage().persistent().get(&key) {
+            Some(c) => Ok(c),
+            None => Err(Error::CategoryNotFound),
         }
     }
 
-    pub fn get_branch(env: Env, branch_id: BytesN<32>) -> Result<branch::Branch, Error> {
-        let key = branch::DataKey::Branch(branch_id);
-        let store = env.storage().persistent();
-        match store.get::<_, branch::Branch>(&key) {
-            Some(branch) => Ok(branch),
-            None => Err(Error::BranchNotFound),
+    /// List the direct children of a category; pass `0` for top-level categories.
+    pub fn get_subcategories(
+        env: Env,
+        company_id: BytesN<32>,
+        parent_id: u64,
+    ) -> Result<Vec<u64>, Error> {
+        if parent_id != category::ROOT {
+            Self::get_category(env.clone(), company_id.clone(), parent_id)?;
         }
+
+        let key = category::DataKey::Children(company_id, parent_id);
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(&env)))
+    }
+
+    /// Page through the assets registered directly under a category.
+    /// Assets in subcategories are not included.
+    pub fn get_assets_by_category(
+        env: Env,
+        company_id: BytesN<32>,
+        category_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<BytesN<32>>, Error> {
+        Self::get_category(env.clone(), company_id.clone(), category_id)?;
+
+        let list = index::List::Category(company_id, category_id);
+        Ok(index::range(&env, &list, start, limit))
     }
 
     /// Tokenize an existing asset by attaching a Stellar token ID.
     ///
-    /// Access: Only the contract admin (set during `initialize`) can call this.
+    /// Access: Only the admin of the asset's company can call this.
     ///
     /// Behavior:
     /// - Loads the asset by `asset_id`.
@@ -183,27 +709,2437 @@ impl AssetUpContract {
         asset_id: BytesN<32>,
         token_id: BytesN<32>,
     ) -> Result<(), Error> {
-        // Enforce admin-only access
-        let admin_key = DataKey::Admin;
-        if !env.storage().persistent().has(&admin_key) {
-            handle_error(&env, Error::AdminNotFound)
+        // Fetch asset
+        let mut a = Self::get_asset(env.clone(), asset_id)?;
+
+        // Enforce company-admin-only access
+        let company = Self::get_company(env.clone(), a.company_id.clone())?;
+        company.admin.require_auth();
+
+        // Update token id
+        a.stellar_token_id = token_id;
+        asset::save(&env, &a);
+        Ok(())
+    }
+
+    // Lien functions
+
+    /// Register a lien against a financed asset.
+    ///
+    /// Access: Both the `lender` and the asset owner must authorize the call;
+    /// the owner's signature is their consent to the encumbrance.
+    ///
+    /// Behavior:
+    /// - `amount` must be positive and `expiry` later than the current ledger time.
+    /// - No other lien in force on the asset may hold the same `priority`.
+    /// - While the lien is in force the asset cannot be transferred or disposed.
+    pub fn register_lien(
+        env: Env,
+        lien_id: BytesN<32>,
+        asset_id: BytesN<32>,
+        lender: Address,
+        amount: i128,
+        currency: currency::Currency,
+        priority: u32,
+        expiry: u64,
+    ) -> Result<(), soroban_sdk::Error> {
+        let asset = Self::get_asset(env.clone(), asset_id.clone())?;
+        lender.require_auth();
+        asset.owner.require_auth();
+
+        if asset.status == AssetStatus::Disposed {
+            return Err(Error::AssetDisposed.into());
         }
-        let admin: Address = env.storage().persistent().get(&admin_key).unwrap();
-        admin.require_auth();
 
-        // Fetch asset
-        let key = asset::DataKey::Asset(asset_id.clone());
+        let now = env.ledger().timestamp();
+        if amount <= 0 || expiry <= now {
+            return Err(Error::InvalidLien.into());
+        }
+        if !currency::is_valid(&currency) {
+            return Err(ValidationError::InvalidCurrency.into());
+        }
+
+        let key = lien::DataKey::Lien(lien_id.clone());
+        let store = env.storage().persistent();
+        if store.has(&key) {
+            return Err(Error::LienAlreadyExists.into());
+        }
+
+        for existing in lien::get_asset_liens(&env, &asset_id).iter() {
+            if existing.is_in_force(now) && existing.priority == priority {
+                return Err(Error::LienPriorityTaken.into());
+            }
+        }
+
+        let l = lien::Lien {
+            id: lien_id.clone(),
+            asset_id: asset_id.clone(),
+            lender,
+            amount,
+            currency,
+            priority,
+            expiry,
+            status: lien::LienStatus::Active,
+            created_at: now,
+        };
+        store.set(&key, &l);
+
+        let list_key = lien::DataKey::AssetLiens(asset_id);
+        let mut ids: Vec<BytesN<32>> = store.get(&list_key).unwrap_or_else(|| Vec::new(&env));
+        ids.push_back(lien_id);
+        store.set(&list_key, &ids);
+
+        Ok(())
+    }
+
+    /// Release a lien, lifting its block on transfer and disposal.
+    ///
+    /// Access: Only the lender who registered the lien can call this.
+    pub fn release_lien(env: Env, lien_id: BytesN<32>) -> Result<(), Error> {
+        let key = lien::DataKey::Lien(lien_id);
         let store = env.storage().persistent();
-        let mut a: asset::Asset = match store.get(&key) {
+        let mut l: lien::Lien = match store.get(&key) {
             Some(v) => v,
-            None => return Err(Error::AssetNotFound),
+            None => return Err(Error::LienNotFound),
         };
+        l.lender.require_auth();
+
+        if l.status != lien::LienStatus::Active {
+            return Err(Error::LienNotActive);
+        }
+
+        l.status = lien::LienStatus::Released;
+        store.set(&key, &l);
+        Ok(())
+    }
+
+    pub fn get_lien(env: Env, lien_id: BytesN<32>) -> Result<lien::Lien, Error> {
+        let key = lien::DataKey::Lien(lien_id);
+        let store = env.storage().persistent();
+        match store.get::<_, lien::Lien>(&key) {
+            Some(l) => Ok(l),
+            None => Err(Error::LienNotFound),
+        }
+    }
+
+    /// List every lien ever registered against an asset, including released
+    /// and expired ones, in registration order.
+    pub fn get_liens(env: Env, asset_id: BytesN<32>) -> Result<Vec<lien::Lien>, Error> {
+        if !asset::exists(&env, &asset_id) {
+            return Err(Error::AssetNotFound);
+        }
+        Ok(lien::get_asset_liens(&env, &asset_id))
+    }
+
+    // Insurance functions
+
+    /// Attach an insurance policy to an asset.
+    ///
+    /// Access: Both the `insurer` and the asset owner must authorize the call.
+    ///
+    /// Behavior:
+    /// - `coverage_amount` must be positive, `premium` non-negative and
+    ///   `start_date` strictly before `end_date`.
+    /// - Amounts are in `currency`; claims are settled in `payout_token`.
+    /// - The policy is recorded in the asset's policy list.
+    pub fn create_policy(
+        env: Env,
+        policy_id: BytesN<32>,
+        asset_id: BytesN<32>,
+        insurer: Address,
+        policy_hash: BytesN<32>,
+        coverage_amount: i128,
+        premium: i128,
+        currency: currency::Currency,
+        payout_token: Address,
+        start_date: u64,
+        end_date: u64,
+    ) -> Result<(), soroban_sdk::Error> {
+        let asset = Self::get_asset(env.clone(), asset_id.clone())?;
+        insurer.require_auth();
+        asset.owner.require_auth();
+
+        if coverage_amount <= 0 || premium < 0 || start_date >= end_date {
+            return Err(Error::InvalidPolicy.into());
+        }
+        if !currency::is_valid(&currency) {
+            return Err(ValidationError::InvalidCurrency.into());
+        }
+
+        let key = insurance::DataKey::Policy(policy_id.clone());
+        let store = env.storage().persistent();
+        if store.has(&key) {
+            return Err(Error::PolicyAlreadyExists.into());
+        }
+
+        let policy = insurance::InsurancePolicy {
+            id: policy_id.clone(),
+            asset_id: asset_id.clone(),
+            insurer,
+            policy_hash,
+            coverage_amount,
+            premium,
+            currency,
+            payout_token,
+            start_date,
+            end_date,
+            approved_total: 0,
+            status: insurance::PolicyStatus::Active,
+        };
+        store.set(&key, &policy);
+
+        let list_key = insurance::DataKey::AssetPolicies(asset_id);
+        let mut ids: Vec<BytesN<32>> = store.get(&list_key).unwrap_or_else(|| Vec::new(&env));
+        ids.push_back(policy_id);
+        store.set(&list_key, &ids);
 
-        // Update token id
-        a.stellar_token_id = token_id;
-        store.set(&key, &a);
         Ok(())
     }
+
+    /// Cancel a policy so no further claims can be filed against it.
+    ///
+    /// Access: Only the insurer can call this.
+    pub fn cancel_policy(env: Env, policy_id: BytesN<32>) -> Result<(), Error> {
+        let mut policy = Self::get_policy(env.clone(), policy_id.clone())?;
+        policy.insurer.require_auth();
+
+        if policy.status != insurance::PolicyStatus::Active {
+            return Err(Error::PolicyNotActive);
+        }
+
+        policy.status = insurance::PolicyStatus::Cancelled;
+        env.storage()
+            .persistent()
+            .set(&insurance::DataKey::Policy(policy_id), &policy);
+        Ok(())
+    }
+
+    pub fn get_policy(
+        env: Env,
+        policy_id: BytesN<32>,
+    ) -> Result<insurance::InsurancePolicy, Error> {
+        let key = insurance::DataKey::Policy(policy_id);
+        match env.storage().persistent().get(&key) {
+            Some(p) => Ok(p),
+            None => Err(Error::PolicyNotFound),
+        }
+    }
+
+    pub fn get_asset_policies(
+        env: Env,
+        asset_id: BytesN<32>,
+    ) -> Result<Vec<insurance::InsurancePolicy>, Error> {
+        Self::get_asset(env.clone(), asset_id.clone())?;
+
+        let store = env.storage().persistent();
+        let ids: Vec<BytesN<32>> = store
+            .get(&insurance::DataKey::AssetPolicies(asset_id))
+            .unwrap_or_else(|| Vec::new(&env));
+
+        let mut policies = Vec::new(&env);
+        for id in ids.iter() {
+            if let Some(p) = store.get(&insurance::DataKey::Policy(id)) {
+                policies.push_back(p);
+            }
+        }
+        Ok(policies)
+    }
+
+    /// File a claim against an active policy.
+    ///
+    /// Access: Only the current asset owner can call this.
+    ///
+    /// Behavior:
+    /// - The policy must be active and the ledger time inside its window.
+    /// - `amount` must be positive and no larger than the coverage still
+    ///   available after previously approved claims.
+    pub fn file_claim(
+        env: Env,
+        claim_id: BytesN<32>,
+        policy_id: BytesN<32>,
+        amount: i128,
+        evidence_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        let policy = Self::get_policy(env.clone(), policy_id.clone())?;
+        let asset = Self::get_asset(env.clone(), policy.asset_id.clone())?;
+        asset.owner.require_auth();
+
+        let now = env.ledger().timestamp();
+        if !policy.covers(now) {
+            return Err(Error::PolicyNotActive);
+        }
+        if amount <= 0 || amount > policy.coverage_amount - policy.approved_total {
+            return Err(Error::InvalidClaim);
+        }
+
+        let key = insurance::DataKey::Claim(claim_id.clone());
+        let store = env.storage().persistent();
+        if store.has(&key) {
+            return Err(Error::ClaimAlreadyExists);
+        }
+
+        let claim = insurance::Claim {
+            id: claim_id.clone(),
+            policy_id,
+            asset_id: asset.id.clone(),
+            claimant: asset.owner,
+            amount,
+            approved_amount: 0,
+            currency: policy.currency,
+            evidence_hash,
+            status: insurance::ClaimStatus::Filed,
+            filed_at: now,
+            decided_at: 0,
+        };
+        store.set(&key, &claim);
+
+        let list_key = insurance::DataKey::AssetClaims(asset.id);
+        let mut ids: Vec<BytesN<32>> = store.get(&list_key).unwrap_or_else(|| Vec::new(&env));
+        ids.push_back(claim_id);
+        store.set(&list_key, &ids);
+
+        Ok(())
+    }
+
+    /// Approve or reject a filed claim.
+    ///
+    /// Access: Only the insurer of the claim's policy can call this.
+    ///
+    /// Behavior:
+    /// - On approval, `approved_amount` may be lower than the claimed amount
+    ///   and counts against the policy's remaining coverage.
+    /// - On rejection, `approved_amount` is ignored.
+    pub fn decide_claim(
+        env: Env,
+        claim_id: BytesN<32>,
+        approve: bool,
+        approved_amount: i128,
+    ) -> Result<(), Error> {
+        let mut claim = Self::get_claim(env.clone(), claim_id.clone())?;
+        let mut policy = Self::get_policy(env.clone(), claim.policy_id.clone())?;
+        policy.insurer.require_auth();
+
+        if claim.status != insurance::ClaimStatus::Filed {
+            return Err(Error::ClaimAlreadyDecided);
+        }
+
+        let store = env.storage().persistent();
+        if approve {
+            if approved_amount <= 0
+                || approved_amount > claim.amount
+                || approved_amount > policy.coverage_amount - policy.approved_total
+            {
+                return Err(Error::InvalidClaim);
+            }
+            claim.status = insurance::ClaimStatus::Approved;
+            claim.approved_amount = approved_amount;
+            policy.approved_total += approved_amount;
+            store.set(&insurance::DataKey::Policy(policy.id.clone()), &policy);
+        } else {
+            claim.status = insurance::ClaimStatus::Rejected;
+        }
+        claim.decided_at = env.ledger().timestamp();
+
+        store.set(&insurance::DataKey::Claim(claim_id), &claim);
+        Ok(())
+    }
+
+    /// Settle an approved claim by paying `approved_amount` of the policy's
+    /// payout token from the insurer to the claimant.
+    ///
+    /// Access: Only the insurer can call this.
+    pub fn settle_claim(env: Env, claim_id: BytesN<32>) -> Result<(), Error> {
+        let mut claim = Self::get_claim(env.clone(), claim_id.clone())?;
+        let policy = Self::get_policy(env.clone(), claim.policy_id.clone())?;
+        policy.insurer.require_auth();
+
+        if claim.status != insurance::ClaimStatus::Approved {
+            return Err(Error::ClaimNotApproved);
+        }
+
+        token::Client::new(&env, &policy.payout_token).transfer(
+            &policy.insurer,
+            &claim.claimant,
+            &claim.approved_amount,
+        );
+
+        claim.status = insurance::ClaimStatus::Paid;
+        env.storage()
+            .persistent()
+            .set(&insurance::DataKey::Claim(claim_id), &claim);
+        Ok(())
+    }
+
+    pub fn get_claim(env: Env, claim_id: BytesN<32>) -> Result<insurance::Claim, Error> {
+        let key = insurance::DataKey::Claim(claim_id);
+        match env.storage().persistent().get(&key) {
+            Some(c) => Ok(c),
+            None => Err(Error::ClaimNotFound),
+        }
+    }
+
+    /// Full claim history for an asset across all of its policies, in filing order.
+    pub fn get_asset_claims(
+        env: Env,
+        asset_id: BytesN<32>,
+    ) -> Result<Vec<insurance::Claim>, Error> {
+        Self::get_asset(env.clone(), asset_id.clone())?;
+
+        let store = env.storage().persistent();
+        let ids: Vec<BytesN<32>> = store
+            .get(&insurance::DataKey::AssetClaims(asset_id))
+            .unwrap_or_else(|| Vec::new(&env));
+
+        let mut claims = Vec::new(&env);
+        for id in ids.iter() {
+            if let Some(c) = store.get(&insurance::DataKey::Claim(id)) {
+                claims.push_back(c);
+            }
+        }
+        Ok(claims)
+    }
+
+    // Document functions
+
+    /// Attach a document hash to an asset.
+    ///
+    /// Access: `uploader` must be the asset owner or the admin of the asset's
+    /// company, and must authorize the call.
+    ///
+    /// Behavior:
+    /// - `hash` must be 32 bytes for `Sha256`, or a binary CID of at most
+    ///   `MAX_CID_LEN` bytes for `IpfsCid`.
+    /// - Attachments are append-only; the same hash cannot be attached twice.
+    pub fn attach_document(
+        env: Env,
+        asset_id: BytesN<32>,
+        hash: Bytes,
+        hash_type: document::HashType,
+        kind: document::DocumentKind,
+        uploader: Address,
+    ) -> Result<(), Error> {
+        let a = Self::get_asset(env.clone(), asset_id.clone())?;
+        let company = Self::get_company(env.clone(), a.company_id.clone())?;
+        if uploader != a.owner && uploader != company.admin {
+            return Err(Error::Unauthorized);
+        }
+        uploader.require_auth();
+
+        if !document::is_valid_hash(&hash, &hash_type) {
+            return Err(Error::InvalidDocumentHash);
+        }
+
+        let key = document::DataKey::Document(asset_id.clone(), hash.clone());
+        let store = env.storage().persistent();
+        if store.has(&key) {
+            return Err(Error::DocumentAlreadyExists);
+        }
+
+        let doc = document::Document {
+            asset_id: asset_id.clone(),
+            hash: hash.clone(),
+            hash_type,
+            kind,
+            uploader,
+            uploaded_at: env.ledger().timestamp(),
+        };
+        store.set(&key, &doc);
+
+        let list_key = document::DataKey::AssetDocuments(asset_id);
+        let mut hashes: Vec<Bytes> = store.get(&list_key).unwrap_or_else(|| Vec::new(&env));
+        hashes.push_back(hash);
+        store.set(&list_key, &hashes);
+
+        Ok(())
+    }
+
+    /// Returns true if `hash` was attached to the asset, proving the
+    /// off-chain file is unchanged since it was uploaded.
+    pub fn verify_document(env: Env, asset_id: BytesN<32>, hash: Bytes) -> bool {
+        env.storage()
+            .persistent()
+            .has(&document::DataKey::Document(asset_id, hash))
+    }
+
+    pub fn get_document(
+        env: Env,
+        asset_id: BytesN<32>,
+        hash: Bytes,
+    ) -> Result<document::Document, Error> {
+        let key = document::DataKey::Document(asset_id, hash);
+        match env.storage().persistent().get(&key) {
+            Some(d) => Ok(d),
+            None => Err(Error::DocumentNotFound),
+        }
+    }
+
+    /// List all documents attached to an asset in upload order.
+    pub fn get_asset_documents(
+        env: Env,
+        asset_id: BytesN<32>,
+    ) -> Result<Vec<document::Document>, Error> {
+        Self::get_asset(env.clone(), asset_id.clone())?;
+
+        let store = env.storage().persistent();
+        let hashes: Vec<Bytes> = store
+            .get(&document::DataKey::AssetDocuments(asset_id.clone()))
+            .unwrap_or_else(|| Vec::new(&env));
+
+        let mut docs = Vec::new(&env);
+        for hash in hashes.iter() {
+            if let Some(d) = store.get(&document::DataKey::Document(asset_id.clone(), hash)) {
+                docs.push_back(d);
+            }
+        }
+        Ok(docs)
+    }
+
+    // Tag functions
+
+    /// Bind a QR/barcode tag to an untagged asset.
+    ///
+    /// Access: Only the admin of the asset's company can call this.
+    ///
+    /// `tag_hash` is the hash of the label payload and may only ever be
+    /// bound once.
+    pub fn bind_tag(env: Env, asset_id: BytesN<32>, tag_hash: BytesN<32>) -> Result<(), Error> {
+        let a = Self::get_asset(env.clone(), asset_id.clone())?;
+        let company = Self::get_company(env.clone(), a.company_id)?;
+        company.admin.require_auth();
+
+        let store = env.storage().persistent();
+        if store.has(&tag::DataKey::AssetTag(asset_id.clone())) {
+            return Err(Error::AssetAlreadyTagged);
+        }
+
+        tag::bind(&env, asset_id, tag_hash)
+    }
+
+    /// Replace an asset's tag, e.g. after its label was damaged.
+    ///
+    /// Access: Only the admin of the asset's company can call this.
+    ///
+    /// The previous tag is retired and will no longer resolve.
+    pub fn retag_asset(
+        env: Env,
+        asset_id: BytesN<32>,
+        new_tag_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        let a = Self::get_asset(env.clone(), asset_id.clone())?;
+        let company = Self::get_company(env.clone(), a.company_id)?;
+        company.admin.require_auth();
+
+        let store = env.storage().persistent();
+        let old_tag: BytesN<32> = match store.get(&tag::DataKey::AssetTag(asset_id.clone())) {
+            Some(t) => t,
+            None => return Err(Error::TagNotFound),
+        };
+
+        tag::bind(&env, asset_id, new_tag_hash)?;
+
+        let old_key = tag::DataKey::Tag(old_tag);
+        let mut old: tag::TagBinding = store.get(&old_key).unwrap();
+        old.active = false;
+        store.set(&old_key, &old);
+        Ok(())
+    }
+
+    /// Resolve a scanned tag to its asset.
+    pub fn get_asset_by_tag(env: Env, tag_hash: BytesN<32>) -> Result<asset::Asset, Error> {
+        let binding = Self::get_tag_binding(env.clone(), tag_hash)?;
+        if !binding.active {
+            return Err(Error::TagRetired);
+        }
+        Self::get_asset(env, binding.asset_id)
+    }
+
+    pub fn get_tag_binding(env: Env, tag_hash: BytesN<32>) -> Result<tag::TagBinding, Error> {
+        let key = tag::DataKey::Tag(tag_hash);
+        match env.storage().persistent().get(&key) {
+            Some(b) => Ok(b),
+            None => Err(Error::TagNotFound),
+        }
+    }
+
+    pub fn get_asset_tag(env: Env, asset_id: BytesN<32>) -> Result<BytesN<32>, Error> {
+        let key = tag::DataKey::AssetTag(asset_id);
+        match env.storage().persistent().get(&key) {
+            Some(t) => Ok(t),
+            None => Err(Error::TagNotFound),
+        }
+    }
+
+    // Inventory functions
+
+    /// Register a consumable SKU at a branch with zero stock on hand.
+    ///
+    /// Access: Only the branch admin can call this.
+    pub fn create_inventory_item(
+        env: Env,
+        branch_id: BytesN<32>,
+        sku: String,
+        name: String,
+    ) -> Result<(), soroban_sdk::Error> {
+        let branch = Self::get_branch(env.clone(), branch_id.clone())?;
+        branch.admin.require_auth();
+
+        validation::check_name(&name)?;
+
+        let key = inventory::DataKey::Item(branch_id.clone(), sku.clone());
+        let store = env.storage().persistent();
+        if store.has(&key) {
+            return Err(Error::InventoryItemAlreadyExists.into());
+        }
+
+        let item = inventory::InventoryItem {
+            branch_id: branch_id.clone(),
+            sku: sku.clone(),
+            name,
+            quantity: 0,
+            movement_count: 0,
+        };
+        store.set(&key, &item);
+
+        let list_key = inventory::DataKey::BranchItems(branch_id);
+        let mut skus: Vec<String> = store.get(&list_key).unwrap_or_else(|| Vec::new(&env));
+        skus.push_back(sku);
+        store.set(&list_key, &skus);
+
+        Ok(())
+    }
+
+    /// Record stock received at a branch, e.g. from a delivery.
+    ///
+    /// Access: Only the branch admin can call this.
+    pub fn receive_stock(
+        env: Env,
+        branch_id: BytesN<32>,
+        sku: String,
+        quantity: u64,
+        reason: String,
+    ) -> Result<(), Error> {
+        let branch = Self::get_branch(env.clone(), branch_id.clone())?;
+        branch.admin.require_auth();
+
+        if quantity == 0 {
+            return Err(Error::InvalidQuantity);
+        }
+        let mut item = inventory::load(&env, &branch_id, &sku)?;
+        inventory::apply(
+            &env,
+            &mut item,
+            inventory::MovementKind::Receive,
+            quantity as i128,
+            reason,
+            branch.admin,
+            None,
+        )
+    }
+
+    /// Record stock issued from a branch for consumption.
+    ///
+    /// Access: Only the branch admin can call this.
+    pub fn issue_stock(
+        env: Env,
+        branch_id: BytesN<32>,
+        sku: String,
+        quantity: u64,
+        reason: String,
+    ) -> Result<(), Error> {
+        let branch = Self::get_branch(env.clone(), branch_id.clone())?;
+        branch.admin.require_auth();
+
+        if quantity == 0 {
+            return Err(Error::InvalidQuantity);
+        }
+        let mut item = inventory::load(&env, &branch_id, &sku)?;
+        inventory::apply(
+            &env,
+            &mut item,
+            inventory::MovementKind::Issue,
+            -(quantity as i128),
+            reason,
+            branch.admin,
+            None,
+        )
+    }
+
+    /// Correct the quantity on hand by `delta`, e.g. after a stock count.
+    ///
+    /// Access: Only the branch admin can call this.
+    pub fn adjust_stock(
+        env: Env,
+        branch_id: BytesN<32>,
+        sku: String,
+        delta: i64,
+        reason: String,
+    ) -> Result<(), Error> {
+        let branch = Self::get_branch(env.clone(), branch_id.clone())?;
+        branch.admin.require_auth();
+
+        if delta == 0 {
+            return Err(Error::InvalidQuantity);
+        }
+        let mut item = inventory::load(&env, &branch_id, &sku)?;
+        inventory::apply(
+            &env,
+            &mut item,
+            inventory::MovementKind::Adjust,
+            delta as i128,
+            reason,
+            branch.admin,
+            None,
+        )
+    }
+
+    /// Move stock of a SKU between two branches of the same company.
+    ///
+    /// Access: Only the admin of the sending branch can call this.
+    ///
+    /// The SKU must already be registered at both branches. The transfer is
+    /// recorded in the history of both items.
+    pub fn transfer_stock(
+        env: Env,
+        from_branch_id: BytesN<32>,
+        to_branch_id: BytesN<32>,
+        sku: String,
+        quantity: u64,
+        reason: String,
+    ) -> Result<(), Error> {
+        let from = Self::get_branch(env.clone(), from_branch_id.clone())?;
+        from.admin.require_auth();
+        let to = Self::get_branch(env.clone(), to_branch_id.clone())?;
+
+        if from.company_id != to.company_id {
+            return Err(Error::CompanyMismatch);
+        }
+        if quantity == 0 || from_branch_id == to_branch_id {
+            return Err(Error::InvalidQuantity);
+        }
+
+        let mut source = inventory::load(&env, &from_branch_id, &sku)?;
+        let mut destination = inventory::load(&env, &to_branch_id, &sku)?;
+        inventory::apply(
+            &env,
+            &mut source,
+            inventory::MovementKind::TransferOut,
+            -(quantity as i128),
+            reason.clone(),
+            from.admin.clone(),
+            Some(to_branch_id),
+        )?;
+        inventory::apply(
+            &env,
+            &mut destination,
+            inventory::MovementKind::TransferIn,
+            quantity as i128,
+            reason,
+            from.admin,
+            Some(from_branch_id),
+        )
+    }
+
+    pub fn get_inventory_item(
+        env: Env,
+        branch_id: BytesN<32>,
+        sku: String,
+    ) -> Result<inventory::InventoryItem, Error> {
+        inventory::load(&env, &branch_id, &sku)
+    }
+
+    /// List the SKUs registered at a branch.
+    pub fn get_branch_inventory(env: Env, branch_id: BytesN<32>) -> Result<Vec<String>, Error> {
+        Self::get_branch(env.clone(), branch_id.clone())?;
+
+        let key = inventory::DataKey::BranchItems(branch_id);
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(&env)))
+    }
+
+    /// Page through an item's movement history, oldest first.
+    pub fn get_stock_movements(
+        env: Env,
+        branch_id: BytesN<32>,
+        sku: String,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<inventory::StockMovement>, Error> {
+        let item = inventory::load(&env, &branch_id, &sku)?;
+        let store = env.storage().persistent();
+
+        let mut movements = Vec::new(&env);
+        let end = start.saturating_add(limit).min(item.movement_count);
+        for seq in start..end {
+            let key = inventory::DataKey::Movement(branch_id.clone(), sku.clone(), seq);
+            if let Some(m) = store.get(&key) {
+                movements.push_back(m);
+            }
+        }
+        Ok(movements)
+    }
+
+    // Procurement functions
+
+    /// Set the amount a branch may still commit to purchase orders, and the
+    /// currency those orders must be placed in.
+    ///
+    /// Access: Only the admin of the branch's company can call this.
+    pub fn set_branch_budget(
+        env: Env,
+        branch_id: BytesN<32>,
+        budget: currency::Money,
+    ) -> Result<(), soroban_sdk::Error> {
+        let branch = Self::get_branch(env.clone(), branch_id.clone())?;
+        let company = Self::get_company(env.clone(), branch.company_id)?;
+        company.admin.require_auth();
+
+        if budget.amount < 0 {
+            return Err(ProcurementError::InvalidBudget.into());
+        }
+        if !currency::is_valid(&budget.currency) {
+            return Err(ValidationError::InvalidCurrency.into());
+        }
+        env.storage()
+            .persistent()
+            .set(&procurement::DataKey::Budget(branch_id), &budget);
+        Ok(())
+    }
+
+    pub fn get_branch_budget(
+        env: Env,
+        branch_id: BytesN<32>,
+    ) -> Result<currency::Money, soroban_sdk::Error> {
+        Self::get_branch(env.clone(), branch_id.clone())?;
+        let key = procurement::DataKey::Budget(branch_id);
+        match env.storage().persistent().get(&key) {
+            Some(budget) => Ok(budget),
+            None => Err(ProcurementError::BudgetNotSet.into()),
+        }
+    }
+
+    /// Request the purchase of `quantity` identical assets for a branch.
+    ///
+    /// Access: The `requester` must authorize the call.
+    ///
+    /// The goods may arrive over several receipts. `unit_cost` is in
+    /// `currency`, which should describe the units of `payment_token` and
+    /// must match the branch budget's currency once one is set.
+    pub fn create_purchase_order(
+        env: Env,
+        po_id: BytesN<32>,
+        branch_id: BytesN<32>,
+        requester: Address,
+        supplier: Address,
+        item_name: String,
+        category_id: u64,
+        quantity: u32,
+        unit_cost: i128,
+        currency: currency::Currency,
+        payment_token: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        requester.require_auth();
+        let branch = Self::get_branch(env.clone(), branch_id.clone())?;
+
+        validation::check_id(&env, &po_id)?;
+        validation::check_name(&item_name)?;
+
+        let total = match unit_cost.checked_mul(quantity as i128) {
+            Some(t) if unit_cost > 0 && quantity > 0 => t,
+            _ => return Err(ProcurementError::InvalidOrder.into()),
+        };
+        if !currency::is_valid(&currency) {
+            return Err(ValidationError::InvalidCurrency.into());
+        }
+
+        let store = env.storage().persistent();
+        let budget: Option<currency::Money> =
+            store.get(&procurement::DataKey::Budget(branch_id.clone()));
+        if budget.is_some_and(|b| b.currency != currency) {
+            return Err(ProcurementError::CurrencyMismatch.into());
+        }
+        if !store.has(&category::DataKey::Category(
+            branch.company_id.clone(),
+            category_id,
+        )) {
+            return Err(Error::CategoryNotFound.into());
+        }
+
+        supplier::load(&env, &branch.company_id, &supplier)?;
+
+        let key = procurement::DataKey::PurchaseOrder(po_id.clone());
+        if store.has(&key) {
+            return Err(ProcurementError::OrderAlreadyExists.into());
+        }
+
+        let po = procurement::PurchaseOrder {
+            id: po_id.clone(),
+            company_id: branch.company_id,
+            branch_id: branch_id.clone(),
+            requester,
+            supplier,
+            item_name,
+            category_id,
+            quantity,
+            unit_cost,
+            total,
+            received: 0,
+            currency,
+            payment_token,
+            status: procurement::PurchaseOrderStatus::Requested,
+            created_at: env.ledger().timestamp(),
+            approved_at: 0,
+            received_at: 0,
+            rated: false,
+        };
+        store.set(&key, &po);
+
+        let list_key = procurement::DataKey::BranchOrders(branch_id);
+        let mut ids: Vec<BytesN<32>> = store.get(&list_key).unwrap_or_else(|| Vec::new(&env));
+        ids.push_back(po_id);
+        store.set(&list_key, &ids);
+
+        Ok(())
+    }
+
+    /// Approve or reject a requested purchase order.
+    ///
+    /// Access: Only the admin of the order's branch can call this.
+    ///
+    /// On approval the supplier must be verified and the order must be in
+    /// the currency of the branch budget; the order total is then deducted
+    /// from the budget and moved from the branch admin into escrow held by
+    /// this contract.
+    pub fn decide_purchase_order(
+        env: Env,
+        po_id: BytesN<32>,
+        approve: bool,
+    ) -> Result<(), soroban_sdk::Error> {
+        let mut po = Self::get_purchase_order(env.clone(), po_id.clone())?;
+        let branch = Self::get_branch(env.clone(), po.branch_id.clone())?;
+        branch.admin.require_auth();
+
+        if po.status != procurement::PurchaseOrderStatus::Requested {
+            return Err(ProcurementError::OrderNotRequested.into());
+        }
+
+        let store = env.storage().persistent();
+        if approve {
+            if !supplier::load(&env, &po.company_id, &po.supplier)?.verified {
+                return Err(SupplierError::SupplierNotVerified.into());
+            }
+            // A branch without a budget cannot commit to any order
+            let Ok(mut budget) = Self::get_branch_budget(env.clone(), po.branch_id.clone()) else {
+                return Err(ProcurementError::BudgetNotSet.into());
+            };
+            if budget.currency != po.currency {
+                return Err(ProcurementError::CurrencyMismatch.into());
+            }
+            if po.total > budget.amount {
+                return Err(ProcurementError::OverBudget.into());
+            }
+            budget.amount -= po.total;
+            store.set(&procurement::DataKey::Budget(po.branch_id.clone()), &budget);
+
+            token::Client::new(&env, &po.payment_token).transfer(
+                &branch.admin,
+                &env.current_contract_address(),
+                &po.total,
+            );
+
+            po.status = procurement::PurchaseOrderStatus::Approved;
+            po.approved_at = env.ledger().timestamp();
+        } else {
+            po.status = procurement::PurchaseOrderStatus::Rejected;
+        }
+
+        store.set(&procurement::DataKey::PurchaseOrder(po_id), &po);
+        Ok(())
+    }
+
+    /// Call off an approved order that will not be received, e.g. because
+    /// the supplier lost its verification.
+    ///
+    /// Access: Only the admin of the order's branch can call this.
+    ///
+    /// The escrow left after any partial receipts is refunded to the branch
+    /// admin and returned to the branch budget. Assets already received
+    /// are kept.
+    pub fn cancel_purchase_order(env: Env, po_id: BytesN<32>) -> Result<(), soroban_sdk::Error> {
+        let mut po = Self::get_purchase_order(env.clone(), po_id.clone())?;
+        let branch = Self::get_branch(env.clone(), po.branch_id.clone())?;
+        branch.admin.require_auth();
+
+        if po.status != procurement::PurchaseOrderStatus::Approved {
+            return Err(ProcurementError::OrderNotApproved.into());
+        }
+
+        let remaining = po.total - po.unit_cost * po.received as i128;
+        token::Client::new(&env, &po.payment_token).transfer(
+            &env.current_contract_address(),
+            &branch.admin,
+            &remaining,
+        );
+        let mut budget = Self::get_branch_budget(env.clone(), po.branch_id.clone())?;
+        budget.amount += remaining;
+        let store = env.storage().persistent();
+        store.set(&procurement::DataKey::Budget(po.branch_id.clone()), &budget);
+
+        po.status = procurement::PurchaseOrderStatus::Cancelled;
+        store.set(&procurement::DataKey::PurchaseOrder(po_id), &po);
+        Ok(())
+    }
+
+    /// Confirm delivery of some or all of an approved purchase order.
+    ///
+    /// Access: Only the requester can call this.
+    ///
+    /// Behavior:
+    /// - Registers one asset per id in `asset_ids`, no more than the
+    ///   quantity still outstanding. Receipts over `MAX_BATCH_SIZE`, or
+    ///   that would fill enough list pages to pass the write limit, fail
+    ///   with `BatchTooLarge`.
+    ///   The assets are owned by the requester, linked to the order's
+    ///   branch and its supplier, and take their purchase cost and date
+    ///   from the order.
+    /// - Releases `unit_cost` per asset from escrow to the supplier, which
+    ///   must still be verified.
+    /// - The order becomes `Received` once the full quantity has arrived.
+    pub fn receive_purchase_order(
+        env: Env,
+        po_id: BytesN<32>,
+        asset_ids: Vec<BytesN<32>>,
+    ) -> Result<(), soroban_sdk::Error> {
+        let mut po = Self::get_purchase_order(env.clone(), po_id.clone())?;
+        po.requester.require_auth();
+
+        if po.status != procurement::PurchaseOrderStatus::Approved {
+            return Err(ProcurementError::OrderNotApproved.into());
+        }
+        if asset_ids.is_empty() || asset_ids.len() > po.quantity - po.received {
+            return Err(ProcurementError::InvalidReceipt.into());
+        }
+        if asset_ids.len() > asset::MAX_BATCH_SIZE {
+            return Err(Error::BatchTooLarge.into());
+        }
+        if !supplier::load(&env, &po.company_id, &po.supplier)?.verified {
+            return Err(SupplierError::SupplierNotVerified.into());
+        }
+
+        let mut assets = Vec::new(&env);
+        for asset_id in asset_ids.iter() {
+            let a = asset::Asset {
+                id: asset_id.clone(),
+                company_id: po.company_id.clone(),
+                name: po.item_name.clone(),
+                asset_type: AssetType::Physical,
+                category_id: po.category_id,
+                branch_id: 0,
+                department_id: department::UNASSIGNED,
+                status: AssetStatus::Active,
+                purchase_date: po.approved_at,
+                purchase_cost: po.unit_cost,
+                current_value: po.unit_cost,
+                currency: po.currency.clone(),
+                warranty_expiry: 0,
+                stellar_token_id: BytesN::from_array(&env, &[0u8; 32]),
+                owner: po.requester.clone(),
+            };
+            asset::check_new(&env, &a)?;
+            if assets.contains(&a) {
+                return Err(Error::AssetAlreadyExists.into());
+            }
+            assets.push_back(a);
+        }
+        let links = vec![
+            &env,
+            index::List::Branch(po.branch_id.clone()),
+            index::List::Supplier(po.company_id.clone(), po.supplier.clone()),
+        ];
+        if asset::insert_writes(&env, &assets, &links) + procurement::RECEIPT_WRITES
+            > asset::MAX_WRITE_ENTRIES
+        {
+            return Err(Error::BatchTooLarge.into());
+        }
+        asset::insert(&env, &assets, &links);
+
+        token::Client::new(&env, &po.payment_token).transfer(
+            &env.current_contract_address(),
+            &po.supplier,
+            &(po.unit_cost * asset_ids.len() as i128),
+        );
+
+        po.received += asset_ids.len();
+        if po.received == po.quantity {
+            po.status = procurement::PurchaseOrderStatus::Received;
+            po.received_at = env.ledger().timestamp();
+        }
+        env.storage()
+            .persistent()
+            .set(&procurement::DataKey::PurchaseOrder(po_id), &po);
+        Ok(())
+    }
+
+    /// Rate the supplier's delivery and quality on a received order.
+    ///
+    /// Access: Only the requester can call this, once per order.
+    ///
+    /// Both ratings range from 1 to `MAX_RATING`.
+    pub fn rate_purchase_order(
+        env: Env,
+        po_id: BytesN<32>,
+        delivery_rating: u32,
+        quality_rating: u32,
+    ) -> Result<(), soroban_sdk::Error> {
+        let mut po = Self::get_purchase_order(env.clone(), po_id.clone())?;
+        po.requester.require_auth();
+
+        if po.status != procurement::PurchaseOrderStatus::Received {
+            return Err(ProcurementError::OrderNotReceived.into());
+        }
+        if po.rated {
+            return Err(ProcurementError::OrderAlreadyRated.into());
+        }
+        let valid = 1..=supplier::MAX_RATING;
+        if !valid.contains(&delivery_rating) || !valid.contains(&quality_rating) {
+            return Err(ProcurementError::InvalidRating.into());
+        }
+
+        let mut s = supplier::load(&env, &po.company_id, &po.supplier)?;
+        s.rating_count += 1;
+        s.delivery_rating_total += delivery_rating;
+        s.quality_rating_total += quality_rating;
+
+        let store = env.storage().persistent();
+        store.set(
+            &supplier::DataKey::Supplier(po.company_id.clone(), po.supplier.clone()),
+            &s,
+        );
+        po.rated = true;
+        store.set(&procurement::DataKey::PurchaseOrder(po_id), &po);
+        Ok(())
+    }
+
+    pub fn get_purchase_order(
+        env: Env,
+        po_id: BytesN<32>,
+    ) -> Result<procurement::PurchaseOrder, soroban_sdk::Error> {
+        let key = procurement::DataKey::PurchaseOrder(po_id);
+        match env.storage().persistent().get(&key) {
+            Some(po) => Ok(po),
+            None => Err(ProcurementError::OrderNotFound.into()),
+        }
+    }
+
+    pub fn get_branch_purchase_orders(
+        env: Env,
+        branch_id: BytesN<32>,
+    ) -> Result<Vec<BytesN<32>>, Error> {
+        Self::get_branch(env.clone(), branch_id.clone())?;
+
+        let key = procurement::DataKey::BranchOrders(branch_id);
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(&env)))
+    }
+
+    // Supplier functions
+
+    /// Add a supplier to a company's directory, unverified.
+    ///
+    /// Access: The `supplier` address must authorize the call.
+    pub fn register_supplier(
+        env: Env,
+        company_id: BytesN<32>,
+        supplier: Address,
+        name: String,
+        tax_id_hash: BytesN<32>,
+    ) -> Result<(), soroban_sdk::Error> {
+        supplier.require_auth();
+        Self::get_company(env.clone(), company_id.clone())?;
+
+        validation::check_name(&name)?;
+
+        let key = supplier::DataKey::Supplier(company_id.clone(), supplier.clone());
+        let store = env.storage().persistent();
+        if store.has(&key) {
+            return Err(SupplierError::SupplierAlreadyExists.into());
+        }
+
+        let s = supplier::Supplier {
+            address: supplier.clone(),
+            company_id: company_id.clone(),
+            name,
+            tax_id_hash,
+            verified: false,
+            rating_count: 0,
+            delivery_rating_total: 0,
+            quality_rating_total: 0,
+        };
+        store.set(&key, &s);
+
+        let list_key = supplier::DataKey::CompanySuppliers(company_id);
+        let mut list: Vec<Address> = store.get(&list_key).unwrap_or_else(|| Vec::new(&env));
+        list.push_back(supplier);
+        store.set(&list_key, &list);
+
+        Ok(())
+    }
+
+    /// Mark a supplier as verified, or revoke verification. Only verified
+    /// suppliers can be paid through purchase orders.
+    ///
+    /// Access: Only the company admin can call this.
+    pub fn set_supplier_verified(
+        env: Env,
+        company_id: BytesN<32>,
+        supplier: Address,
+        verified: bool,
+    ) -> Result<(), soroban_sdk::Error> {
+        let company = Self::get_company(env.clone(), company_id.clone())?;
+        company.admin.require_auth();
+
+        let mut s = supplier::load(&env, &company_id, &supplier)?;
+        s.verified = verified;
+        env.storage()
+            .persistent()
+            .set(&supplier::DataKey::Supplier(company_id, supplier), &s);
+        Ok(())
+    }
+
+    pub fn get_supplier(
+        env: Env,
+        company_id: BytesN<32>,
+        supplier: Address,
+    ) -> Result<supplier::Supplier, SupplierError> {
+        supplier::load(&env, &company_id, &supplier)
+    }
+
+    pub fn get_company_suppliers(env: Env, company_id: BytesN<32>) -> Result<Vec<Address>, Error> {
+        Self::get_company(env.clone(), company_id.clone())?;
+
+        let key = supplier::DataKey::CompanySuppliers(company_id);
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(&env)))
+    }
+
+    /// Record which supplier an asset came from, e.g. for assets bought
+    /// outside procurement. Assets received on a purchase order are linked
+    /// automatically.
+    ///
+    /// Access: Only the admin of the asset's company can call this.
+    pub fn set_asset_supplier(
+        env: Env,
+        asset_id: BytesN<32>,
+        supplier: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        let a = Self::get_asset(env.clone(), asset_id.clone())?;
+        let company = Self::get_company(env.clone(), a.company_id.clone())?;
+        company.admin.require_auth();
+
+        supplier::load(&env, &a.company_id, &supplier)?;
+        supplier::link_asset(&env, &a.company_id, &supplier, &asset_id);
+        Ok(())
+    }
+
+    pub fn get_asset_supplier(
+        env: Env,
+        asset_id: BytesN<32>,
+    ) -> Result<Address, soroban_sdk::Error> {
+        supplier::of_asset(&env, &asset_id).ok_or(SupplierError::AssetSupplierNotFound.into())
+    }
+
+    /// Page through the assets sourced from a supplier, e.g. to route a
+    /// recall.
+    pub fn get_supplier_assets(
+        env: Env,
+        company_id: BytesN<32>,
+        supplier: Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<BytesN<32>>, soroban_sdk::Error> {
+        supplier::load(&env, &company_id, &supplier)?;
+
+        let list = index::List::Supplier(company_id, supplier);
+        Ok(index::range(&env, &list, start, limit))
+    }
+
+    // Vendor contract functions
+
+    /// Anchor a service contract between a company and a vendor.
+    ///
+    /// Access: Both the company admin and the `vendor` must authorize the
+    /// call.
+    ///
+    /// Every covered asset must belong to the company, and `end_date` must
+    /// be later than `start_date`.
+    pub fn create_vendor_contract(
+        env: Env,
+        contract_id: BytesN<32>,
+        company_id: BytesN<32>,
+        vendor: Address,
+        document_hash: BytesN<32>,
+        covered_assets: Vec<BytesN<32>>,
+        sla_response_secs: u64,
+        start_date: u64,
+        end_date: u64,
+        auto_renew: bool,
+    ) -> Result<(), soroban_sdk::Error> {
+        let company = Self::get_company(env.clone(), company_id.clone())?;
+        company.admin.require_auth();
+        vendor.require_auth();
+
+        if end_date <= start_date {
+            return Err(VendorContractError::InvalidTerm.into());
+        }
+        for asset_id in covered_assets.iter() {
+            if Self::get_asset(env.clone(), asset_id)?.company_id != company_id {
+                return Err(Error::CompanyMismatch.into());
+            }
+        }
+
+        let key = vendor_contract::DataKey::VendorContract(contract_id.clone());
+        let store = env.storage().persistent();
+        if store.has(&key) {
+            return Err(VendorContractError::ContractAlreadyExists.into());
+        }
+
+        let c = vendor_contract::VendorContract {
+            id: contract_id.clone(),
+            company_id: company_id.clone(),
+            vendor,
+            document_hash,
+            covered_assets,
+            sla_response_secs,
+            start_date,
+            end_date,
+            auto_renew,
+            renewals: Vec::new(&env),
+            status: vendor_contract::VendorContractStatus::Active,
+        };
+        store.set(&key, &c);
+
+        let list_key = vendor_contract::DataKey::CompanyContracts(company_id);
+        let mut ids: Vec<BytesN<32>> = store.get(&list_key).unwrap_or_else(|| Vec::new(&env));
+        ids.push_back(contract_id);
+        store.set(&list_key, &ids);
+
+        Ok(())
+    }
+
+    /// Extend an active contract to `new_end_date` under a new document. The
+    /// renewal is added to the contract's history as a term running from the
+    /// end of the current one, and auto-renewal then rolls over in terms of
+    /// that length. Earlier terms are left as signed.
+    ///
+    /// Access: Both the company admin and the vendor must authorize the call.
+    pub fn renew_vendor_contract(
+        env: Env,
+        contract_id: BytesN<32>,
+        new_end_date: u64,
+        document_hash: BytesN<32>,
+    ) -> Result<(), soroban_sdk::Error> {
+        let mut c = Self::get_vendor_contract(env.clone(), contract_id.clone())?;
+        let company = Self::get_company(env.clone(), c.company_id.clone())?;
+        company.admin.require_auth();
+        c.vendor.require_auth();
+
+        if c.status != vendor_contract::VendorContractStatus::Active {
+            return Err(VendorContractError::ContractNotActive.into());
+        }
+        let now = env.ledger().timestamp();
+        let current_end = c.end_at(now);
+        if new_end_date <= current_end {
+            return Err(VendorContractError::InvalidTerm.into());
+        }
+
+        c.renewals.push_back(vendor_contract::Renewal {
+            document_hash,
+            start_date: current_end,
+            end_date: new_end_date,
+            renewed_at: now,
+        });
+        env.storage()
+            .persistent()
+            .set(&vendor_contract::DataKey::VendorContract(contract_id), &c);
+        Ok(())
+    }
+
+    /// Terminate an active contract, ending any auto-renewal.
+    ///
+    /// Access: Both the company admin and the vendor must authorize the call.
+    pub fn terminate_vendor_contract(
+        env: Env,
+        contract_id: BytesN<32>,
+    ) -> Result<(), soroban_sdk::Error> {
+        let mut c = Self::get_vendor_contract(env.clone(), contract_id.clone())?;
+        let company = Self::get_company(env.clone(), c.company_id.clone())?;
+        company.admin.require_auth();
+        c.vendor.require_auth();
+
+        if c.status != vendor_contract::VendorContractStatus::Active {
+            return Err(VendorContractError::ContractNotActive.into());
+        }
+
+        c.status = vendor_contract::VendorContractStatus::Terminated;
+        env.storage()
+            .persistent()
+            .set(&vendor_contract::DataKey::VendorContract(contract_id), &c);
+        Ok(())
+    }
+
+    pub fn get_vendor_contract(
+        env: Env,
+        contract_id: BytesN<32>,
+    ) -> Result<vendor_contract::VendorContract, soroban_sdk::Error> {
+        let key = vendor_contract::DataKey::VendorContract(contract_id);
+        match env.storage().persistent().get(&key) {
+            Some(c) => Ok(c),
+            None => Err(VendorContractError::ContractNotFound.into()),
+        }
+    }
+
+    pub fn get_company_vendor_contracts(
+        env: Env,
+        company_id: BytesN<32>,
+    ) -> Result<Vec<BytesN<32>>, soroban_sdk::Error> {
+        Self::get_company(env.clone(), company_id.clone())?;
+
+        let key = vendor_contract::DataKey::CompanyContracts(company_id);
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(&env)))
+    }
+
+    /// List a company's active contracts whose current term ends within
+    /// `window` seconds of the current ledger time, including auto-renewing
+    /// ones that are about to roll over.
+    pub fn get_expiring_vendor_contracts(
+        env: Env,
+        company_id: BytesN<32>,
+        window: u64,
+    ) -> Result<Vec<vendor_contract::VendorContract>, soroban_sdk::Error> {
+        let now = env.ledger().timestamp();
+        let mut expiring = Vec::new(&env);
+        for id in Self::get_company_vendor_contracts(env.clone(), company_id)?.iter() {
+            let c = Self::get_vendor_contract(env.clone(), id)?;
+            let end = c.end_at(now);
+            if c.status == vendor_contract::VendorContractStatus::Active
+                && now <= end
+                && end - now <= window
+            {
+                expiring.push_back(c);
+            }
+        }
+        Ok(expiring)
+    }
+
+    // Shipment functions
+
+    /// Dispatch assets from one branch to another through a carrier.
+    ///
+    /// Access: Only the origin branch admin can call this; their signature
+    /// is the first handoff, to `carrier`.
+    ///
+    /// Every asset must be active, not checked out and linked to the origin
+    /// branch. The assets are marked `InTransit` until delivery is
+    /// confirmed.
+    pub fn create_shipment(
+        env: Env,
+        shipment_id: BytesN<32>,
+        asset_ids: Vec<BytesN<32>>,
+        origin_branch: BytesN<32>,
+        destination_branch: BytesN<32>,
+        carrier: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        let origin = Self::get_branch(env.clone(), origin_branch.clone())?;
+        origin.admin.require_auth();
+        let destination = Self::get_branch(env.clone(), destination_branch.clone())?;
+
+        if origin.company_id != destination.company_id {
+            return Err(Error::CompanyMismatch.into());
+        }
+        if asset_ids.is_empty() || origin_branch == destination_branch {
+            return Err(ShipmentError::InvalidShipment.into());
+        }
+
+        let key = shipment::DataKey::Shipment(shipment_id.clone());
+        let store = env.storage().persistent();
+        if store.has(&key) {
+            return Err(ShipmentError::ShipmentAlreadyExists.into());
+        }
+
+        for asset_id in asset_ids.iter() {
+            let mut a = Self::get_asset(env.clone(), asset_id.clone())?;
+            if !asset::linked_branches(&env, &asset_id).contains(&origin_branch) {
+                return Err(ShipmentError::AssetNotAtOrigin.into());
+            }
+            // Also rejects ids repeated within `asset_ids`
+            if a.status != AssetStatus::Active {
+                return Err(Error::AssetNotActive.into());
+            }
+            if reservation::is_checked_out(&env, &asset_id) {
+                return Err(Error::AssetCheckedOut.into());
+            }
+            a.status = AssetStatus::InTransit;
+            asset::save(&env, &a);
+            store.set(&shipment::DataKey::AssetShipment(asset_id), &shipment_id);
+        }
+
+        let now = env.ledger().timestamp();
+        let mut handoffs = Vec::new(&env);
+        handoffs.push_back(shipment::Handoff {
+            from: origin.admin,
+            to: carrier.clone(),
+            at: now,
+        });
+
+        let s = shipment::Shipment {
+            id: shipment_id,
+            company_id: origin.company_id,
+            asset_ids,
+            origin_branch,
+            destination_branch,
+            carrier: carrier.clone(),
+            holder: carrier,
+            handoffs,
+            status: shipment::ShipmentStatus::InTransit,
+            created_at: now,
+            delivered_at: 0,
+        };
+        store.set(&key, &s);
+        Ok(())
+    }
+
+    /// Pass custody of a shipment to the next holder, e.g. between
+    /// couriers or depots.
+    ///
+    /// Access: Only the current holder can call this.
+    pub fn handoff_shipment(
+        env: Env,
+        shipment_id: BytesN<32>,
+        to: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        let mut s = Self::get_shipment(env.clone(), shipment_id.clone())?;
+        s.holder.require_auth();
+
+        if s.status != shipment::ShipmentStatus::InTransit {
+            return Err(ShipmentError::ShipmentNotInTransit.into());
+        }
+
+        s.handoffs.push_back(shipment::Handoff {
+            from: s.holder.clone(),
+            to: to.clone(),
+            at: env.ledger().timestamp(),
+        });
+        s.holder = to;
+        env.storage()
+            .persistent()
+            .set(&shipment::DataKey::Shipment(shipment_id), &s);
+        Ok(())
+    }
+
+    /// Sign proof of delivery, completing the relocation.
+    ///
+    /// Access: Only the destination branch admin can call this.
+    ///
+    /// The assets are moved from the origin to the destination branch and
+    /// become active again; the final handoff to the signer is recorded.
+    pub fn confirm_delivery(env: Env, shipment_id: BytesN<32>) -> Result<(), soroban_sdk::Error> {
+        let mut s = Self::get_shipment(env.clone(), shipment_id.clone())?;
+        let destination = Self::get_branch(env.clone(), s.destination_branch.clone())?;
+        destination.admin.require_auth();
+
+        if s.status != shipment::ShipmentStatus::InTransit {
+            return Err(ShipmentError::ShipmentNotInTransit.into());
+        }
+
+        let store = env.storage().persistent();
+        for asset_id in s.asset_ids.iter() {
+            let mut a = Self::get_asset(env.clone(), asset_id.clone())?;
+            a.status = AssetStatus::Active;
+            asset::save(&env, &a);
+            branch::remove_asset(&env, &s.origin_branch, &a);
+            branch::add_asset(&env, &s.destination_branch, &a);
+            store.remove(&shipment::DataKey::AssetShipment(asset_id));
+        }
+
+        let now = env.ledger().timestamp();
+        s.handoffs.push_back(shipment::Handoff {
+            from: s.holder.clone(),
+            to: destination.admin.clone(),
+            at: now,
+        });
+        s.holder = destination.admin;
+        s.status = shipment::ShipmentStatus::Delivered;
+        s.delivered_at = now;
+        store.set(&shipment::DataKey::Shipment(shipment_id), &s);
+        Ok(())
+    }
+
+    /// Call a shipment back before delivery, returning the goods to the
+    /// origin branch.
+    ///
+    /// Access: Only the origin branch admin can call this.
+    ///
+    /// The assets never left the origin branch and become active again; the
+    /// final handoff, from the current holder back to the signer, closes
+    /// the chain of custody.
+    pub fn cancel_shipment(env: Env, shipment_id: BytesN<32>) -> Result<(), soroban_sdk::Error> {
+        let mut s = Self::get_shipment(env.clone(), shipment_id.clone())?;
+        let origin = Self::get_branch(env.clone(), s.origin_branch.clone())?;
+        origin.admin.require_auth();
+
+        if s.status != shipment::ShipmentStatus::InTransit {
+            return Err(ShipmentError::ShipmentNotInTransit.into());
+        }
+
+        let store = env.storage().persistent();
+        for asset_id in s.asset_ids.iter() {
+            let mut a = Self::get_asset(env.clone(), asset_id.clone())?;
+            a.status = AssetStatus::Active;
+            asset::save(&env, &a);
+            store.remove(&shipment::DataKey::AssetShipment(asset_id));
+        }
+
+        s.handoffs.push_back(shipment::Handoff {
+            from: s.holder.clone(),
+            to: origin.admin.clone(),
+            at: env.ledger().timestamp(),
+        });
+        s.holder = origin.admin;
+        s.status = shipment::ShipmentStatus::Cancelled;
+        store.set(&shipment::DataKey::Shipment(shipment_id), &s);
+        Ok(())
+    }
+
+    pub fn get_shipment(
+        env: Env,
+        shipment_id: BytesN<32>,
+    ) -> Result<shipment::Shipment, soroban_sdk::Error> {
+        let key = shipment::DataKey::Shipment(shipment_id);
+        match env.storage().persistent().get(&key) {
+            Some(s) => Ok(s),
+            None => Err(ShipmentError::ShipmentNotFound.into()),
+        }
+    }
+
+    /// Look up the shipment an asset is currently travelling on.
+    pub fn get_asset_shipment(
+        env: Env,
+        asset_id: BytesN<32>,
+    ) -> Result<shipment::Shipment, soroban_sdk::Error> {
+        let key = shipment::DataKey::AssetShipment(asset_id);
+        match env.storage().persistent().get(&key) {
+            Some(id) => Self::get_shipment(env, id),
+            None => Err(ShipmentError::ShipmentNotFound.into()),
+        }
+    }
+
+    // Location functions
+
+    /// Record where an asset currently is.
+    ///
+    /// Access: `reporter` must be the asset owner or the admin of the
+    /// asset's company, and must authorize the call.
+    ///
+    /// `geo` may be `GeoData::None`; otherwise it must be a valid geohash or
+    /// a latitude/longitude pair within range.
+    pub fn record_location(
+        env: Env,
+        asset_id: BytesN<32>,
+        building: String,
+        room: String,
+        geo: location::GeoData,
+        reporter: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        let a = Self::get_asset(env.clone(), asset_id.clone())?;
+        let company = Self::get_company(env.clone(), a.company_id.clone())?;
+        if reporter != a.owner && reporter != company.admin {
+            return Err(Error::Unauthorized.into());
+        }
+        reporter.require_auth();
+
+        if building.is_empty() || !location::is_valid_geo(&geo) {
+            return Err(LocationError::InvalidLocation.into());
+        }
+
+        let store = env.storage().persistent();
+        let count_key = location::DataKey::LocationCount(asset_id.clone());
+        let seq: u32 = store.get(&count_key).unwrap_or(0);
+
+        let update = location::LocationUpdate {
+            seq,
+            building,
+            room,
+            geo,
+            reporter,
+            reported_at: env.ledger().timestamp(),
+        };
+        store.set(&location::DataKey::Location(asset_id, seq), &update);
+        store.set(&count_key, &(seq + 1));
+        Ok(())
+    }
+
+    /// Most recent location reported for an asset.
+    pub fn get_current_location(
+        env: Env,
+        asset_id: BytesN<32>,
+    ) -> Result<location::LocationUpdate, soroban_sdk::Error> {
+        let store = env.storage().persistent();
+        let count: u32 = store
+            .get(&location::DataKey::LocationCount(asset_id.clone()))
+            .unwrap_or(0);
+        if count == 0 {
+            return Err(LocationError::LocationNotFound.into());
+        }
+        Ok(store
+            .get(&location::DataKey::Location(asset_id, count - 1))
+            .unwrap())
+    }
+
+    /// Page through an asset's location history, oldest first.
+    pub fn get_location_history(
+        env: Env,
+        asset_id: BytesN<32>,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<location::LocationUpdate>, Error> {
+        if !asset::exists(&env, &asset_id) {
+            return Err(Error::AssetNotFound);
+        }
+
+        let store = env.storage().persistent();
+        let count: u32 = store
+            .get(&location::DataKey::LocationCount(asset_id.clone()))
+            .unwrap_or(0);
+
+        let mut history = Vec::new(&env);
+        let end = start.saturating_add(limit).min(count);
+        for seq in start..end {
+            let key = location::DataKey::Location(asset_id.clone(), seq);
+            if let Some(update) = store.get(&key) {
+                history.push_back(update);
+            }
+        }
+        Ok(history)
+    }
+
+    // Cost center functions
+
+    /// Register a cost center for a company.
+    ///
+    /// Access: Only the company admin can call this.
+    pub fn create_cost_center(
+        env: Env,
+        company_id: BytesN<32>,
+        cost_center_id: u64,
+        name: String,
+    ) -> Result<(), soroban_sdk::Error> {
+        let company = Self::get_company(env.clone(), company_id.clone())?;
+        company.admin.require_auth();
+
+        validation::check_name(&name)?;
+
+        let key = cost_center::DataKey::CostCenter(company_id.clone(), cost_center_id);
+        let store = env.storage().persistent();
+        if store.has(&key) {
+            return Err(CostCenterError::CostCenterAlreadyExists.into());
+        }
+
+        let cc = cost_center::CostCenter {
+            id: cost_center_id,
+            company_id: company_id.clone(),
+            name,
+        };
+        store.set(&key, &cc);
+
+        let list_key = cost_center::DataKey::CompanyCostCenters(company_id);
+        let mut ids: Vec<u64> = store.get(&list_key).unwrap_or_else(|| Vec::new(&env));
+        ids.push_back(cost_center_id);
+        store.set(&list_key, &ids);
+
+        Ok(())
+    }
+
+    pub fn get_cost_center(
+        env: Env,
+        company_id: BytesN<32>,
+        cost_center_id: u64,
+    ) -> Result<cost_center::CostCenter, soroban_sdk::Error> {
+        let key = cost_center::DataKey::CostCenter(company_id, cost_center_id);
+        match env.storage().persistent().get(&key) {
+            Some(cc) => Ok(cc),
+            None => Err(CostCenterError::CostCenterNotFound.into()),
+        }
+    }
+
+    pub fn get_company_cost_centers(env: Env, company_id: BytesN<32>) -> Result<Vec<u64>, Error> {
+        Self::get_company(env.clone(), company_id.clone())?;
+
+        let key = cost_center::DataKey::CompanyCostCenters(company_id);
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(&env)))
+    }
+
+    /// Replace how an asset's cost is split across cost centers.
+    ///
+    /// Access: Only the admin of the asset's company can call this.
+    ///
+    /// Each cost center may appear once with a positive share, and the
+    /// shares must add up to `FULL_ALLOCATION_BPS`.
+    pub fn set_asset_allocation(
+        env: Env,
+        asset_id: BytesN<32>,
+        allocations: Vec<cost_center::Allocation>,
+    ) -> Result<(), soroban_sdk::Error> {
+        let a = Self::get_asset(env.clone(), asset_id.clone())?;
+        let company = Self::get_company(env.clone(), a.company_id.clone())?;
+        company.admin.require_auth();
+
+        let mut seen: Vec<u64> = Vec::new(&env);
+        let mut total: u32 = 0;
+        for alloc in allocations.iter() {
+            Self::get_cost_center(env.clone(), a.company_id.clone(), alloc.cost_center_id)?;
+            if alloc.bps == 0 || seen.contains(alloc.cost_center_id) {
+                return Err(CostCenterError::InvalidShare.into());
+            }
+            seen.push_back(alloc.cost_center_id);
+            total = total.saturating_add(alloc.bps);
+        }
+        if total != cost_center::FULL_ALLOCATION_BPS {
+            return Err(CostCenterError::AllocationIncomplete.into());
+        }
+
+        let store = env.storage().persistent();
+        let key = cost_center::DataKey::Allocation(asset_id.clone());
+        let previous: Vec<cost_center::Allocation> =
+            store.get(&key).unwrap_or_else(|| Vec::new(&env));
+        for alloc in previous.iter() {
+            let list = index::List::CostCenter(a.company_id.clone(), alloc.cost_center_id);
+            asset::remove_from(&env, &asset_id, &list);
+        }
+        for id in seen.iter() {
+            let list = index::List::CostCenter(a.company_id.clone(), id);
+            asset::add_to(&env, &asset_id, list);
+        }
+
+        store.set(&key, &allocations);
+        Ok(())
+    }
+
+    pub fn get_asset_allocation(
+        env: Env,
+        asset_id: BytesN<32>,
+    ) -> Result<Vec<cost_center::Allocation>, Error> {
+        if !asset::exists(&env, &asset_id) {
+            return Err(Error::AssetNotFound);
+        }
+        let key = cost_center::DataKey::Allocation(asset_id);
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(&env)))
+    }
+
+    /// Sum a cost center's share of the purchase cost and current value of
+    /// the assets allocated to it, per currency. Disposed assets are left
+    /// out and shares are rounded down per asset.
+    pub fn get_cost_center_share(
+        env: Env,
+        company_id: BytesN<32>,
+        cost_center_id: u64,
+    ) -> Result<cost_center::CostCenterShare, soroban_sdk::Error> {
+        Self::get_cost_center(env.clone(), company_id.clone(), cost_center_id)?;
+
+        let list = index::List::CostCenter(company_id, cost_center_id);
+        let asset_ids = index::all(&env, &list);
+
+        let mut share = cost_center::CostCenterShare {
+            cost_center_id,
+            asset_count: 0,
+            totals: Vec::new(&env),
+        };
+        let full = cost_center::FULL_ALLOCATION_BPS as i128;
+        for asset_id in asset_ids.iter() {
+            let a = Self::get_asset(env.clone(), asset_id.clone())?;
+            if a.status == AssetStatus::Disposed {
+                continue;
+            }
+            let allocations = Self::get_asset_allocation(env.clone(), asset_id)?;
+            for alloc in allocations.iter() {
+                if alloc.cost_center_id == cost_center_id {
+                    let bps = alloc.bps as i128;
+                    share.asset_count += 1;
+                    stats::add_total(
+                        &mut share.totals,
+                        &a.currency,
+                        a.purchase_cost * bps / full,
+                        a.current_value * bps / full,
+                    );
+                }
+            }
+        }
+        Ok(share)
+    }
+
+    /// Shares of every cost center of a company, in registration order.
+    pub fn get_cost_center_shares(
+        env: Env,
+        company_id: BytesN<32>,
+    ) -> Result<Vec<cost_center::CostCenterShare>, soroban_sdk::Error> {
+        let mut shares = Vec::new(&env);
+        for id in Self::get_company_cost_centers(env.clone(), company_id.clone())?.iter() {
+            shares.push_back(Self::get_cost_center_share(
+                env.clone(),
+                company_id.clone(),
+                id,
+            )?);
+        }
+        Ok(shares)
+    }
+
+    // Currency functions
+
+    /// Set the currency a branch reports its asset values in.
+    ///
+    /// Access: Only the admin of the branch's company can call this.
+    pub fn set_reporting_currency(
+        env: Env,
+        branch_id: BytesN<32>,
+        currency: currency::Currency,
+    ) -> Result<(), soroban_sdk::Error> {
+        let branch = Self::get_branch(env.clone(), branch_id.clone())?;
+        let company = Self::get_company(env.clone(), branch.company_id)?;
+        company.admin.require_auth();
+
+        if !currency::is_valid(&currency) {
+            return Err(ValidationError::InvalidCurrency.into());
+        }
+        env.storage()
+            .persistent()
+            .set(&currency::DataKey::ReportingCurrency(branch_id), &currency);
+        Ok(())
+    }
+
+    pub fn get_reporting_currency(
+        env: Env,
+        branch_id: BytesN<32>,
+    ) -> Result<currency::Currency, soroban_sdk::Error> {
+        let key = currency::DataKey::ReportingCurrency(branch_id);
+        match env.storage().persistent().get(&key) {
+            Some(c) => Ok(c),
+            None => Err(CurrencyError::ReportingCurrencyNotSet.into()),
+        }
+    }
+
+    /// Allow `feeder`, typically an oracle relayer, to push FX rates.
+    ///
+    /// Access: Only the platform admin can call this.
+    pub fn set_fx_feeder(env: Env, feeder: Address) -> Result<(), Error> {
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&currency::DataKey::FxFeeder, &feeder);
+        Ok(())
+    }
+
+    /// Publish the rate of `base` in `quote`, scaled by `FX_RATE_SCALE`.
+    ///
+    /// Access: `source` must be the platform admin or the FX feeder, and
+    /// must authorize the call.
+    pub fn set_fx_rate(
+        env: Env,
+        source: Address,
+        base: Symbol,
+        quote: Symbol,
+        rate: i128,
+    ) -> Result<(), soroban_sdk::Error> {
+        let store = env.storage().persistent();
+        let feeder: Option<Address> = store.get(&currency::DataKey::FxFeeder);
+        if source != Self::get_admin(env.clone())? && Some(source.clone()) != feeder {
+            return Err(Error::Unauthorized.into());
+        }
+        source.require_auth();
+
+        if rate <= 0 || base == quote {
+            return Err(CurrencyError::InvalidFxRate.into());
+        }
+
+        let fx = currency::FxRate {
+            base: base.clone(),
+            quote: quote.clone(),
+            rate,
+            updated_at: env.ledger().timestamp(),
+            source,
+        };
+        store.set(&currency::DataKey::FxRate(base, quote), &fx);
+        Ok(())
+    }
+
+    pub fn get_fx_rate(
+        env: Env,
+        base: Symbol,
+        quote: Symbol,
+    ) -> Result<currency::FxRate, soroban_sdk::Error> {
+        let key = currency::DataKey::FxRate(base, quote);
+        match env.storage().persistent().get(&key) {
+            Some(fx) => Ok(fx),
+            None => Err(CurrencyError::FxRateNotFound.into()),
+        }
+    }
+
+    /// Convert an amount between currencies. A missing pair falls back to
+    /// the inverse of the opposite pair; results are rounded down.
+    pub fn convert_amount(
+        env: Env,
+        amount: i128,
+        from: currency::Currency,
+        to: currency::Currency,
+    ) -> Result<i128, soroban_sdk::Error> {
+        currency::convert(&env, amount, &from, &to)
+    }
+
+    /// Current value of a branch's assets in its reporting currency.
+    /// Disposed assets are left out.
+    pub fn get_branch_value(
+        env: Env,
+        branch_id: BytesN<32>,
+    ) -> Result<currency::Money, soroban_sdk::Error> {
+        let branch = Self::get_branch(env.clone(), branch_id.clone())?;
+        let reporting = Self::get_reporting_currency(env.clone(), branch_id)?;
+        let amount =
+            currency::assets_value(&env, &currency::branch_assets(&env, &branch), &reporting)?;
+        Ok(currency::Money {
+            amount,
+            currency: reporting,
+        })
+    }
+
+    /// Current value of the assets linked to every branch of a company,
+    /// converted into `currency`. Disposed assets are left out.
+    pub fn get_company_value(
+        env: Env,
+        company_id: BytesN<32>,
+        currency: currency::Currency,
+    ) -> Result<currency::Money, soroban_sdk::Error> {
+        // An asset linked to several branches is counted once
+        let mut asset_ids = Vec::new(&env);
+        for branch_id in Self::get_company_branches(env.clone(), company_id)?.iter() {
+            let branch = Self::get_branch(env.clone(), branch_id)?;
+            for asset_id in currency::branch_assets(&env, &branch).iter() {
+                if !asset_ids.contains(&asset_id) {
+                    asset_ids.push_back(asset_id);
+                }
+            }
+        }
+        let amount = currency::assets_value(&env, &asset_ids, &currency)?;
+        Ok(currency::Money { amount, currency })
+    }
+
+    // Oracle functions
+
+    /// Set the market price feed used to revalue the assets of a category.
+    ///
+    /// Access: Only the company admin can call this.
+    pub fn set_category_price_feed(
+        env: Env,
+        company_id: BytesN<32>,
+        category_id: u64,
+        feed: oracle::PriceFeed,
+    ) -> Result<(), Error> {
+        let company = Self::get_company(env.clone(), company_id.clone())?;
+        company.admin.require_auth();
+        Self::get_category(env.clone(), company_id.clone(), category_id)?;
+
+        env.storage()
+            .persistent()
+            .set(&oracle::DataKey::PriceFeed(company_id, category_id), &feed);
+        Ok(())
+    }
+
+    pub fn get_category_price_feed(
+        env: Env,
+        company_id: BytesN<32>,
+        category_id: u64,
+    ) -> Result<oracle::PriceFeed, soroban_sdk::Error> {
+        let key = oracle::DataKey::PriceFeed(company_id, category_id);
+        match env.storage().persistent().get(&key) {
+            Some(feed) => Ok(feed),
+            None => Err(OracleError::PriceFeedNotFound.into()),
+        }
+    }
+
+    /// Set an asset's `current_value` to the latest market price of its
+    /// category's feed and return the new value. Anyone may call this.
+    ///
+    /// Behavior:
+    /// - Fails with `PriceFeedNotFound` when the category has no feed and
+    ///   with `PriceNotFound` when the oracle has no price.
+    /// - Fails with `StalePrice` when the price is older than the feed's
+    ///   `max_age_secs`.
+    /// - Fails with `DeviationTooLarge` when the new value differs from the
+    ///   current one by more than `max_deviation_bps`. An asset written down
+    ///   to zero is compared against its `purchase_cost` instead; one with
+    ///   neither takes the first price as is.
+    /// - Fails with `AmountOverflow` when the deviation cannot be computed.
+    pub fn revalue_asset(env: Env, asset_id: BytesN<32>) -> Result<i128, soroban_sdk::Error> {
+        let mut a = Self::get_asset(env.clone(), asset_id)?;
+        if a.status == AssetStatus::Disposed {
+            return Err(Error::AssetDisposed.into());
+        }
+        let feed = Self::get_category_price_feed(env.clone(), a.company_id.clone(), a.category_id)?;
+
+        let client = oracle::PriceOracleClient::new(&env, &feed.oracle);
+        let price = match client.lastprice(&feed.asset) {
+            Some(p) => p,
+            None => return Err(OracleError::PriceNotFound.into()),
+        };
+        if env.ledger().timestamp().saturating_sub(price.timestamp) > feed.max_age_secs {
+            return Err(OracleError::StalePrice.into());
+        }
+
+        let quoted = currency::Currency {
+            code: feed.price_code,
+            scale: client.decimals(),
+        };
+        if !currency::is_valid(&quoted) {
+            return Err(ValidationError::InvalidCurrency.into());
+        }
+        let value = currency::convert(&env, price.price, &quoted, &a.currency)?;
+
+        let reference = if a.current_value > 0 {
+            a.current_value
+        } else {
+            a.purchase_cost
+        };
+        if reference > 0 {
+            let deviation = value
+                .checked_sub(reference)
+                .and_then(i128::checked_abs)
+                .and_then(|d| d.checked_mul(10_000))
+                .ok_or(CurrencyError::AmountOverflow)?;
+            let allowed = reference
+                .checked_mul(feed.max_deviation_bps as i128)
+                .ok_or(CurrencyError::AmountOverflow)?;
+            if deviation > allowed {
+                return Err(OracleError::DeviationTooLarge.into());
+            }
+        }
+
+        a.current_value = value;
+        asset::save(&env, &a);
+        Ok(value)
+    }
+
+    // Stats functions
+
+    /// Running aggregates over the assets linked to a branch.
+    pub fn get_branch_stats(
+        env: Env,
+        branch_id: BytesN<32>,
+    ) -> Result<stats::PortfolioStats, Error> {
+        Self::get_branch(env.clone(), branch_id.clone())?;
+        Ok(stats::load(&env, &stats::DataKey::Branch(branch_id)))
+    }
+
+    /// Running aggregates over every registered asset.
+    pub fn get_global_stats(env: Env) -> stats::PortfolioStats {
+        stats::load(&env, &stats::DataKey::Global)
+    }
+
+    // Owner index functions
+
+    /// Page through the undisposed assets held by an address. Assets are
+    /// listed in the order they were acquired, except that an asset leaving
+    /// the list is replaced by the last one.
+    pub fn get_assets_by_owner(
+        env: Env,
+        owner: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<BytesN<32>> {
+        index::range(&env, &index::List::Owner(owner), start, limit)
+    }
+
+    /// Number of undisposed assets held by an address.
+    pub fn count_assets_by_owner(env: Env, owner: Address) -> u32 {
+        index::len(&env, &index::List::Owner(owner))
+    }
+    // Reservation functions
+
+    /// Require the branch admin's approval for bookings of assets linked to
+    /// a branch, or lift the requirement.
+    ///
+    /// Access: Only the branch admin can call this.
+    pub fn set_reservation_approval(
+        env: Env,
+        branch_id: BytesN<32>,
+        required: bool,
+    ) -> Result<(), Error> {
+        let branch = Self::get_branch(env.clone(), branch_id.clone())?;
+        branch.admin.require_auth();
+        env.storage().persistent().set(
+            &reservation::DataKey::ApprovalRequired(branch_id),
+            &required,
+        );
+        Ok(())
+    }
+
+    pub fn get_reservation_approval(env: Env, branch_id: BytesN<32>) -> Result<bool, Error> {
+        Self::get_branch(env.clone(), branch_id.clone())?;
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&reservation::DataKey::ApprovalRequired(branch_id))
+            .unwrap_or(false))
+    }
+
+    /// Book an asset for `[from, to)` and return the reservation id.
+    ///
+    /// Access: The `requester` must authorize the call.
+    ///
+    /// Behavior:
+    /// - The window must be non-empty and end in the future.
+    /// - Fails with `WindowTaken` if the window overlaps a pending,
+    ///   approved or checked-out reservation of the asset.
+    /// - The booking is approved at once unless a branch the asset is
+    ///   linked to requires approval; it then stays pending until that
+    ///   branch's admin decides.
+    pub fn reserve(
+        env: Env,
+        asset_id: BytesN<32>,
+        from: u64,
+        to: u64,
+        requester: Address,
+    ) -> Result<u64, soroban_sdk::Error> {
+        requester.require_auth();
+        let a = Self::get_asset(env.clone(), asset_id.clone())?;
+        if a.status == AssetStatus::Disposed {
+            return Err(Error::AssetDisposed.into());
+        }
+
+        let now = env.ledger().timestamp();
+        let window = reservation::Window { from, to };
+        if from >= to || to <= now {
+            return Err(ReservationError::InvalidWindow.into());
+        }
+        for r in reservation::asset_reservations(&env, &asset_id).iter() {
+            if r.holds_window() && r.window.overlaps(&window) {
+                return Err(ReservationError::WindowTaken.into());
+            }
+        }
+
+        let approval_branch = reservation::approval_branch(&env, &asset_id);
+        let status = match approval_branch {
+            Some(_) => reservation::ReservationStatus::Pending,
+            None => reservation::ReservationStatus::Approved,
+        };
+        let id = reservation::next_id(&env);
+        let r = reservation::Reservation {
+            id,
+            asset_id: asset_id.clone(),
+            requester,
+            window,
+            status,
+            approval_branch,
+            created_at: now,
+        };
+
+        let store = env.storage().persistent();
+        store.set(&reservation::DataKey::Reservation(id), &r);
+        let list_key = reservation::DataKey::AssetReservations(asset_id);
+        let mut ids: Vec<u64> = store.get(&list_key).unwrap_or_else(|| Vec::new(&env));
+        ids.push_back(id);
+        store.set(&list_key, &ids);
+        Ok(id)
+    }
+
+    /// Approve or reject a pending reservation.
+    ///
+    /// Access: Only the admin of the branch that required approval can
+    /// call this.
+    pub fn decide_reservation(
+        env: Env,
+        reservation_id: u64,
+        approve: bool,
+    ) -> Result<(), soroban_sdk::Error> {
+        let mut r = Self::get_reservation(env.clone(), reservation_id)?;
+        if r.status != reservation::ReservationStatus::Pending {
+            return Err(ReservationError::ReservationNotPending.into());
+        }
+        let Some(branch_id) = r.approval_branch.clone() else {
+            return Err(ReservationError::ReservationNotPending.into());
+        };
+        let branch = Self::get_branch(env.clone(), branch_id)?;
+        branch.admin.require_auth();
+
+        r.status = if approve {
+            reservation::ReservationStatus::Approved
+        } else {
+            reservation::ReservationStatus::Rejected
+        };
+        env.storage()
+            .persistent()
+            .set(&reservation::DataKey::Reservation(reservation_id), &r);
+        Ok(())
+    }
+
+    /// Withdraw a pending or approved reservation, freeing its window.
+    /// Expired bookings can no longer be withdrawn.
+    ///
+    /// Access: Only the requester can call this.
+    pub fn cancel_reservation(env: Env, reservation_id: u64) -> Result<(), soroban_sdk::Error> {
+        let mut r = Self::get_reservation(env.clone(), reservation_id)?;
+        r.requester.require_auth();
+
+        match r.status {
+            reservation::ReservationStatus::Pending | reservation::ReservationStatus::Approved => {
+                r.status = reservation::ReservationStatus::Cancelled;
+            }
+            _ => return Err(ReservationError::ReservationNotActive.into()),
+        }
+        env.storage()
+            .persistent()
+            .set(&reservation::DataKey::Reservation(reservation_id), &r);
+        Ok(())
+    }
+
+    /// Take an asset out under the reservation whose window is running.
+    ///
+    /// Access: The `holder` must authorize the call and be the requester
+    /// of the current reservation.
+    ///
+    /// Fails with `NoCurrentReservation` if no reservation covers the
+    /// current time and with `ReservationNotApproved` if that reservation is
+    /// still pending. Fails with `AssetCheckedOut` or `AssetNotActive` if
+    /// the asset is already out or not active.
+    pub fn check_out(
+        env: Env,
+        asset_id: BytesN<32>,
+        holder: Address,
+    ) -> Result<u64, soroban_sdk::Error> {
+        holder.require_auth();
+        let a = Self::get_asset(env.clone(), asset_id.clone())?;
+        if a.status != AssetStatus::Active {
+            return Err(Error::AssetNotActive.into());
+        }
+        if reservation::is_checked_out(&env, &asset_id) {
+            return Err(Error::AssetCheckedOut.into());
+        }
+
+        let now = env.ledger().timestamp();
+        let Some(mut r) = reservation::asset_reservations(&env, &asset_id)
+            .iter()
+            .find(|r| r.holds_window() && r.window.contains(now))
+        else {
+            return Err(ReservationError::NoCurrentReservation.into());
+        };
+        if r.requester != holder {
+            return Err(Error::Unauthorized.into());
+        }
+        if r.status != reservation::ReservationStatus::Approved {
+            return Err(ReservationError::ReservationNotApproved.into());
+        }
+
+        r.status = reservation::ReservationStatus::CheckedOut;
+        let store = env.storage().persistent();
+        store.set(&reservation::DataKey::Reservation(r.id), &r);
+        store.set(&reservation::DataKey::CheckedOut(asset_id), &r.id);
+        Ok(r.id)
+    }
+
+    /// Return a checked-out asset, closing its reservation.
+    ///
+    /// Access: Only the holder who checked the asset out can call this.
+    pub fn check_in(env: Env, asset_id: BytesN<32>) -> Result<(), soroban_sdk::Error> {
+        let key = reservation::DataKey::CheckedOut(asset_id);
+        let store = env.storage().persistent();
+        let Some(id) = store.get::<_, u64>(&key) else {
+            return Err(ReservationError::AssetNotCheckedOut.into());
+        };
+        let mut r = Self::get_reservation(env.clone(), id)?;
+        r.requester.require_auth();
+
+        r.status = reservation::ReservationStatus::Returned;
+        store.set(&reservation::DataKey::Reservation(id), &r);
+        store.remove(&key);
+        Ok(())
+    }
+
+    /// Check an asset back in on behalf of a holder who cannot or will not
+    /// return it through `check_in`, e.g. after an overdue return.
+    ///
+    /// Access: Only the admin of `branch_id`, a branch the asset is linked
+    /// to, can call this.
+    pub fn force_check_in(
+        env: Env,
+        asset_id: BytesN<32>,
+        branch_id: BytesN<32>,
+    ) -> Result<(), soroban_sdk::Error> {
+        let branch = Self::get_branch(env.clone(), branch_id.clone())?;
+        branch.admin.require_auth();
+        if !asset::linked_branches(&env, &asset_id).contains(&branch_id) {
+            return Err(Error::Unauthorized.into());
+        }
+
+        let key = reservation::DataKey::CheckedOut(asset_id);
+        let store = env.storage().persistent();
+        let Some(id) = store.get::<_, u64>(&key) else {
+            return Err(ReservationError::AssetNotCheckedOut.into());
+        };
+        let mut r = Self::get_reservation(env.clone(), id)?;
+        r.status = reservation::ReservationStatus::Returned;
+        store.set(&reservation::DataKey::Reservation(id), &r);
+        store.remove(&key);
+        Ok(())
+    }
+
+    /// Look up a reservation. Pending and approved bookings whose window
+    /// has ended are reported as `Expired`.
+    pub fn get_reservation(
+        env: Env,
+        reservation_id: u64,
+    ) -> Result<reservation::Reservation, soroban_sdk::Error> {
+        reservation::load(&env, reservation_id).ok_or(ReservationError::ReservationNotFound.into())
+    }
+
+    /// Reservations of an asset overlapping `window`, in booking order.
+    /// Cancelled and rejected bookings are left out.
+    pub fn get_reservations(
+        env: Env,
+        asset_id: BytesN<32>,
+        window: reservation::Window,
+    ) -> Result<Vec<reservation::Reservation>, soroban_sdk::Error> {
+        Self::get_asset(env.clone(), asset_id.clone())?;
+        let mut found = Vec::new(&env);
+        for r in reservation::asset_reservations(&env, &asset_id).iter() {
+            let withdrawn = matches!(
+                r.status,
+                reservation::ReservationStatus::Cancelled
+                    | reservation::ReservationStatus::Rejected
+            );
+            if !withdrawn && r.window.overlaps(&window) {
+                found.push_back(r);
+            }
+        }
+        Ok(found)
+    }
 }
 
 mod tests;
@@ -0,0 +1,66 @@
+use soroban_sdk::{Address, BytesN, Env, Vec, contracttype};
+
+use crate::currency::Currency;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Lien(BytesN<32>),
+    AssetLiens(BytesN<32>),
+}
+
+/// Lifecycle of a lien registered against an asset
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LienStatus {
+    Active,
+    Released,
+}
+
+/// A lender's claim against a financed asset, for `amount` in `currency`.
+/// Lower `priority` values rank ahead of higher ones.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Lien {
+    pub id: BytesN<32>,
+    pub asset_id: BytesN<32>,
+    pub lender: Address,
+    pub amount: i128,
+    pub currency: Currency,
+    pub priority: u32,
+    pub expiry: u64,
+    pub status: LienStatus,
+    pub created_at: u64,
+}
+
+impl Lien {
+    /// A lien encumbers its asset while active and not yet expired.
+    pub fn is_in_force(&self, now: u64) -> bool {
+        self.status == LienStatus::Active && self.expiry > now
+    }
+}
+
+pub(crate) fn get_asset_liens(env: &Env, asset_id: &BytesN<32>) -> Vec<Lien> {
+    let store = env.storage().persistent();
+    let ids: Vec<BytesN<32>> = store
+        .get(&DataKey::AssetLiens(asset_id.clone()))
+        .unwrap_or_else(|| Vec::new(env));
+
+    let mut liens = Vec::new(env);
+    for id in ids.iter() {
+        if let Some(lien) = store.get::<_, Lien>(&DataKey::Lien(id)) {
+            liens.push_back(lien);
+        }
+    }
+    liens
+}
+
+/// Returns true if any lien in force blocks transfer or disposal of the asset.
+pub(crate) fn is_encumbered(env: &Env, asset_id: &BytesN<32>) -> bool {
+    let now = env.ledger().timestamp();
+    get_asset_liens(env, asset_id)
+        .iter()
+        .any(|lien| lien.is_in_force(now))
+}
+
+// Note: Contract methods implemented in lib.rs
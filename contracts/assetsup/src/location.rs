@@ -0,0 +1,62 @@
+use soroban_sdk::{Address, BytesN, String, contracttype};
+
+/// Fixed-point scale of `GeoData::LatLong` coordinates: 1e-7 degrees,
+/// roughly 1 cm at the equator.
+pub const COORD_SCALE: i64 = 10_000_000;
+
+/// Longest geohash accepted; 12 characters already resolve to a few cm.
+pub const MAX_GEOHASH_LEN: u32 = 12;
+
+const GEOHASH_ALPHABET: &[u8] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    // (asset_id, sequence number)
+    Location(BytesN<32>, u32),
+    // asset_id -> number of recorded locations
+    LocationCount(BytesN<32>),
+}
+
+/// Coarse position attached to a location update, if any
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GeoData {
+    None,
+    Geohash(String),
+    // (latitude, longitude) scaled by COORD_SCALE
+    LatLong(i64, i64),
+}
+
+/// Where an asset was reported to be, and by whom.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LocationUpdate {
+    pub seq: u32,
+    pub building: String,
+    pub room: String,
+    pub geo: GeoData,
+    pub reporter: Address,
+    pub reported_at: u64,
+}
+
+pub(crate) fn is_valid_geo(geo: &GeoData) -> bool {
+    match geo {
+        GeoData::None => true,
+        GeoData::Geohash(hash) => {
+            let len = hash.len();
+            if len == 0 || len > MAX_GEOHASH_LEN {
+                return false;
+            }
+            let mut buf = [0u8; MAX_GEOHASH_LEN as usize];
+            let chars = &mut buf[..len as usize];
+            hash.copy_into_slice(chars);
+            chars.iter().all(|c| GEOHASH_ALPHABET.contains(c))
+        }
+        GeoData::LatLong(lat, long) => {
+            lat.abs() <= 90 * COORD_SCALE && long.abs() <= 180 * COORD_SCALE
+        }
+    }
+}
+
+// Note: Contract methods implemented in lib.rs
@@ -0,0 +1,48 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol, contractclient, contracttype};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    // (company_id, category_id) -> PriceFeed
+    PriceFeed(BytesN<32>, u64),
+}
+
+/// Asset identifier of the SEP-40 oracle interface
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OracleAsset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+/// Price record of the SEP-40 oracle interface
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// The subset of SEP-40 used for revaluation.
+// Only the generated client is used by this contract
+#[allow(dead_code)]
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracle {
+    fn lastprice(env: Env, asset: OracleAsset) -> Option<PriceData>;
+    fn decimals(env: Env) -> u32;
+}
+
+/// Market price source for the assets of one category.
+/// Prices are quoted in `price_code` with the oracle's `decimals()`, and
+/// are converted into each asset's currency with the stored FX rates.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceFeed {
+    pub oracle: Address,
+    pub asset: OracleAsset,
+    pub price_code: Symbol,
+    pub max_age_secs: u64,
+    pub max_deviation_bps: u32,
+}
+
+// Note: Contract methods implemented in lib.rs
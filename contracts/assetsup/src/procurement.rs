@@ -0,0 +1,60 @@
+use soroban_sdk::{Address, BytesN, String, contracttype};
+
+use crate::currency::Currency;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    PurchaseOrder(BytesN<32>),
+    // branch_id -> Vec<po_id>
+    BranchOrders(BytesN<32>),
+    // branch_id -> Money left for purchase orders, in the branch's order currency
+    Budget(BytesN<32>),
+}
+
+/// Ledger entries a goods receipt writes besides those of the assets it
+/// registers: the order, the escrow and supplier token balances and the
+/// requester's auth nonce.
+pub const RECEIPT_WRITES: u32 = 4;
+
+/// Represents where a purchase order is between request and delivery
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PurchaseOrderStatus {
+    Requested,
+    Approved,
+    Rejected,
+    Received,
+    Cancelled,
+}
+
+/// A request to buy `quantity` identical assets for a branch from a
+/// supplier registered in the branch's company.
+/// `total` is held in escrow by the contract from approval. Each goods
+/// receipt releases `unit_cost` per unit received to `supplier`; cancelling
+/// refunds whatever is left to the branch admin.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PurchaseOrder {
+    pub id: BytesN<32>,
+    pub company_id: BytesN<32>,
+    pub branch_id: BytesN<32>,
+    pub requester: Address,
+    pub supplier: Address,
+    pub item_name: String,
+    pub category_id: u64,
+    pub quantity: u32,
+    pub unit_cost: i128,
+    pub total: i128,
+    // Units delivered so far, over one or more receipts
+    pub received: u32,
+    // Denomination of unit_cost and total; must match payment_token
+    pub currency: Currency,
+    pub payment_token: Address,
+    pub status: PurchaseOrderStatus,
+    pub created_at: u64,
+    pub approved_at: u64,
+    // Set when the last unit is received
+    pub received_at: u64,
+    pub rated: bool,
+}
@@ -0,0 +1,128 @@
+use soroban_sdk::{Address, BytesN, Env, Vec, contracttype};
+
+use crate::asset;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Reservation(u64),
+    // asset_id -> Vec<reservation_id>, in booking order
+    AssetReservations(BytesN<32>),
+    // asset_id -> reservation_id while the asset is checked out
+    CheckedOut(BytesN<32>),
+    // branch_id -> true when reservations need the branch admin's approval
+    ApprovalRequired(BytesN<32>),
+    NextId,
+}
+
+/// Lifecycle of a reservation. A pending or approved booking whose window
+/// ends without a check-out reads as `Expired`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReservationStatus {
+    Pending,
+    Approved,
+    Rejected,
+    Cancelled,
+    CheckedOut,
+    Returned,
+    Expired,
+}
+
+/// Half-open time range `[from, to)` in ledger seconds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Window {
+    pub from: u64,
+    pub to: u64,
+}
+
+impl Window {
+    pub fn overlaps(&self, other: &Window) -> bool {
+        self.from < other.to && other.from < self.to
+    }
+
+    pub fn contains(&self, at: u64) -> bool {
+        self.from <= at && at < self.to
+    }
+}
+
+/// A booking of an asset by `requester` for `window`.
+/// `approval_branch` is the branch whose admin must approve the booking,
+/// if any branch the asset is linked to requires approval.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reservation {
+    pub id: u64,
+    pub asset_id: BytesN<32>,
+    pub requester: Address,
+    pub window: Window,
+    pub status: ReservationStatus,
+    pub approval_branch: Option<BytesN<32>>,
+    pub created_at: u64,
+}
+
+impl Reservation {
+    /// Pending, approved and checked-out reservations hold their window;
+    /// no other booking may overlap them.
+    pub fn holds_window(&self) -> bool {
+        matches!(
+            self.status,
+            ReservationStatus::Pending
+                | ReservationStatus::Approved
+                | ReservationStatus::CheckedOut
+        )
+    }
+}
+
+/// Stored reservation, expired if its window has passed unused.
+pub(crate) fn load(env: &Env, id: u64) -> Option<Reservation> {
+    let mut r: Reservation = env.storage().persistent().get(&DataKey::Reservation(id))?;
+    let unused = matches!(
+        r.status,
+        ReservationStatus::Pending | ReservationStatus::Approved
+    );
+    if unused && env.ledger().timestamp() >= r.window.to {
+        r.status = ReservationStatus::Expired;
+    }
+    Some(r)
+}
+
+pub(crate) fn next_id(env: &Env) -> u64 {
+    let store = env.storage().persistent();
+    let id: u64 = store.get(&DataKey::NextId).unwrap_or(1);
+    store.set(&DataKey::NextId, &(id + 1));
+    id
+}
+
+pub(crate) fn asset_reservations(env: &Env, asset_id: &BytesN<32>) -> Vec<Reservation> {
+    let store = env.storage().persistent();
+    let ids: Vec<u64> = store
+        .get(&DataKey::AssetReservations(asset_id.clone()))
+        .unwrap_or_else(|| Vec::new(env));
+
+    let mut reservations = Vec::new(env);
+    for id in ids.iter() {
+        if let Some(r) = load(env, id) {
+            reservations.push_back(r);
+        }
+    }
+    reservations
+}
+
+/// Returns true while a reservation holder has the asset checked out.
+pub(crate) fn is_checked_out(env: &Env, asset_id: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::CheckedOut(asset_id.clone()))
+}
+
+/// First branch linked to the asset that requires approval of bookings.
+pub(crate) fn approval_branch(env: &Env, asset_id: &BytesN<32>) -> Option<BytesN<32>> {
+    let store = env.storage().persistent();
+    asset::linked_branches(env, asset_id).iter().find(|b| {
+        store
+            .get(&DataKey::ApprovalRequired(b.clone()))
+            .unwrap_or(false)
+    })
+}
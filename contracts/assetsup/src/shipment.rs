@@ -0,0 +1,49 @@
+use soroban_sdk::{Address, BytesN, Vec, contracttype};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Shipment(BytesN<32>),
+    // asset_id -> shipment_id while the asset is in transit
+    AssetShipment(BytesN<32>),
+}
+
+/// Represents whether a shipment has reached its destination branch or
+/// been called back to its origin
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ShipmentStatus {
+    InTransit,
+    Delivered,
+    Cancelled,
+}
+
+/// One transfer of custody, signed by the party giving up the goods.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Handoff {
+    pub from: Address,
+    pub to: Address,
+    pub at: u64,
+}
+
+/// Assets relocating between two branches of the same company.
+/// `holder` is whoever currently has physical custody; `handoffs` is the
+/// full chain of custody from the origin branch admin onwards.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Shipment {
+    pub id: BytesN<32>,
+    pub company_id: BytesN<32>,
+    pub asset_ids: Vec<BytesN<32>>,
+    pub origin_branch: BytesN<32>,
+    pub destination_branch: BytesN<32>,
+    pub carrier: Address,
+    pub holder: Address,
+    pub handoffs: Vec<Handoff>,
+    pub status: ShipmentStatus,
+    pub created_at: u64,
+    pub delivered_at: u64,
+}
+
+// Note: Contract methods implemented in lib.rs
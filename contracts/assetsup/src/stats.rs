@@ -0,0 +1,169 @@
+use soroban_sdk::{BytesN, Env, Vec, contracttype};
+
+use crate::{
+    asset::Asset,
+    currency::Currency,
+    types::{AssetStatus, AssetType},
+};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Global,
+    Branch(BytesN<32>),
+}
+
+/// Purchase cost and current value of the assets held in one currency.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValueTotal {
+    pub currency: Currency,
+    pub purchase_cost: i128,
+    pub current_value: i128,
+}
+
+/// Running aggregates over a set of assets.
+/// Disposed assets are counted but left out of `totals`, which hold one
+/// entry per currency in use.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PortfolioStats {
+    pub asset_count: u32,
+    pub active_count: u32,
+    pub in_maintenance_count: u32,
+    pub in_transit_count: u32,
+    pub disposed_count: u32,
+    pub physical_count: u32,
+    pub digital_count: u32,
+    pub tokenized_count: u32,
+    pub totals: Vec<ValueTotal>,
+}
+
+impl PortfolioStats {
+    pub fn new(env: &Env) -> Self {
+        PortfolioStats {
+            asset_count: 0,
+            active_count: 0,
+            in_maintenance_count: 0,
+            in_transit_count: 0,
+            disposed_count: 0,
+            physical_count: 0,
+            digital_count: 0,
+            tokenized_count: 0,
+            totals: Vec::new(env),
+        }
+    }
+
+    /// Add (`sign` = 1) or remove (`sign` = -1) one asset's contribution.
+    fn apply(&mut self, env: &Env, a: &Asset, sign: i32) {
+        let bump = |count: &mut u32| *count = count.saturating_add_signed(sign);
+        bump(&mut self.asset_count);
+        bump(match a.status {
+            AssetStatus::Active => &mut self.active_count,
+            AssetStatus::InMaintenance => &mut self.in_maintenance_count,
+            AssetStatus::InTransit => &mut self.in_transit_count,
+            AssetStatus::Disposed => &mut self.disposed_count,
+        });
+        bump(match a.asset_type {
+            AssetType::Physical => &mut self.physical_count,
+            AssetType::Digital => &mut self.digital_count,
+        });
+        if is_tokenized(env, a) {
+            bump(&mut self.tokenized_count);
+        }
+        if a.status == AssetStatus::Disposed {
+            return;
+        }
+
+        let sign = sign as i128;
+        add_total(
+            &mut self.totals,
+            &a.currency,
+            sign * a.purchase_cost,
+            sign * a.current_value,
+        );
+    }
+}
+
+/// Add amounts to the entry for `currency` in `totals`, creating it if
+/// needed.
+pub(crate) fn add_total(
+    totals: &mut Vec<ValueTotal>,
+    currency: &Currency,
+    purchase_cost: i128,
+    current_value: i128,
+) {
+    let index = totals.iter().position(|t| &t.currency == currency);
+    let mut total = match index {
+        Some(i) => totals.get_unchecked(i as u32),
+        None => ValueTotal {
+            currency: currency.clone(),
+            purchase_cost: 0,
+            current_value: 0,
+        },
+    };
+    total.purchase_cost += purchase_cost;
+    total.current_value += current_value;
+    match index {
+        Some(i) => totals.set(i as u32, total),
+        None => totals.push_back(total),
+    }
+}
+
+fn is_tokenized(env: &Env, a: &Asset) -> bool {
+    a.stellar_token_id != BytesN::from_array(env, &[0u8; 32])
+}
+
+pub(crate) fn load(env: &Env, key: &DataKey) -> PortfolioStats {
+    env.storage()
+        .persistent()
+        .get(key)
+        .unwrap_or_else(|| PortfolioStats::new(env))
+}
+
+fn update(env: &Env, key: DataKey, old: Option<&Asset>, new: Option<&Asset>) {
+    let mut stats = load(env, &key);
+    if let Some(a) = old {
+        stats.apply(env, a, -1);
+    }
+    if let Some(a) = new {
+        stats.apply(env, a, 1);
+    }
+    env.storage().persistent().set(&key, &stats);
+}
+
+/// Replace `old` with `new` in the global stats and in the stats of the
+/// branches the asset is linked to.
+pub(crate) fn record(env: &Env, branches: &Vec<BytesN<32>>, old: &Asset, new: &Asset) {
+    update(env, DataKey::Global, Some(old), Some(new));
+    for branch_id in branches.iter() {
+        update(env, DataKey::Branch(branch_id), Some(old), Some(new));
+    }
+}
+
+/// Add a batch of new assets to the global stats and to the stats of the
+/// branches they are linked to, writing each once.
+pub(crate) fn record_new(env: &Env, assets: &Vec<Asset>, branches: &Vec<BytesN<32>>) {
+    let mut keys = Vec::new(env);
+    keys.push_back(DataKey::Global);
+    for branch_id in branches.iter() {
+        keys.push_back(DataKey::Branch(branch_id));
+    }
+    for key in keys.iter() {
+        let mut stats = load(env, &key);
+        for a in assets.iter() {
+            stats.apply(env, &a, 1);
+        }
+        env.storage().persistent().set(&key, &stats);
+    }
+}
+
+/// Add an asset to, or remove it from, a branch's stats.
+pub(crate) fn record_link(env: &Env, branch_id: &BytesN<32>, a: &Asset, linked: bool) {
+    let key = DataKey::Branch(branch_id.clone());
+    if linked {
+        update(env, key, None, Some(a));
+    } else {
+        update(env, key, Some(a), None);
+    }
+}
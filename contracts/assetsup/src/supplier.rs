@@ -0,0 +1,71 @@
+use soroban_sdk::{Address, BytesN, Env, String, contracttype};
+
+use crate::{asset, error::SupplierError, index::List};
+
+/// Highest score a requester can give when rating a completed order.
+pub const MAX_RATING: u32 = 5;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    // (company_id, supplier)
+    Supplier(BytesN<32>, Address),
+    // company_id -> Vec<supplier>
+    CompanySuppliers(BytesN<32>),
+}
+
+/// A supplier in a company's vendor directory.
+/// Ratings are kept as running totals over `rating_count` completed orders
+/// so averages can be derived without storing every score.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Supplier {
+    pub address: Address,
+    pub company_id: BytesN<32>,
+    pub name: String,
+    pub tax_id_hash: BytesN<32>,
+    pub verified: bool,
+    pub rating_count: u32,
+    pub delivery_rating_total: u32,
+    pub quality_rating_total: u32,
+}
+
+pub(crate) fn load(
+    env: &Env,
+    company_id: &BytesN<32>,
+    supplier: &Address,
+) -> Result<Supplier, SupplierError> {
+    let key = DataKey::Supplier(company_id.clone(), supplier.clone());
+    match env.storage().persistent().get(&key) {
+        Some(s) => Ok(s),
+        None => Err(SupplierError::SupplierNotFound),
+    }
+}
+
+/// Supplier an asset was sourced from, if recorded.
+pub(crate) fn of_asset(env: &Env, asset_id: &BytesN<32>) -> Option<Address> {
+    asset::lists(env, asset_id)
+        .iter()
+        .find_map(|list| match list {
+            List::Supplier(_, supplier) => Some(supplier),
+            _ => None,
+        })
+}
+
+/// Record `supplier` as the source of a stored asset, replacing any
+/// earlier link.
+pub(crate) fn link_asset(
+    env: &Env,
+    company_id: &BytesN<32>,
+    supplier: &Address,
+    asset_id: &BytesN<32>,
+) {
+    if let Some(previous) = of_asset(env, asset_id) {
+        asset::remove_from(env, asset_id, &List::Supplier(company_id.clone(), previous));
+    }
+    asset::add_to(
+        env,
+        asset_id,
+        List::Supplier(company_id.clone(), supplier.clone()),
+    );
+}
@@ -0,0 +1,45 @@
+use soroban_sdk::{BytesN, Env, contracttype};
+
+use crate::error::Error;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    // tag_hash -> TagBinding
+    Tag(BytesN<32>),
+    // asset_id -> current tag_hash
+    AssetTag(BytesN<32>),
+}
+
+/// Binding between a printed QR/barcode label and an asset.
+/// Retired labels stay reserved so a stale label can never resolve to a
+/// different asset.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TagBinding {
+    pub tag_hash: BytesN<32>,
+    pub asset_id: BytesN<32>,
+    pub bound_at: u64,
+    pub active: bool,
+}
+
+/// Bind `tag_hash` to `asset_id` and make it the asset's current tag.
+pub(crate) fn bind(env: &Env, asset_id: BytesN<32>, tag_hash: BytesN<32>) -> Result<(), Error> {
+    let key = DataKey::Tag(tag_hash.clone());
+    let store = env.storage().persistent();
+    if store.has(&key) {
+        return Err(Error::TagAlreadyBound);
+    }
+
+    let binding = TagBinding {
+        tag_hash: tag_hash.clone(),
+        asset_id: asset_id.clone(),
+        bound_at: env.ledger().timestamp(),
+        active: true,
+    };
+    store.set(&key, &binding);
+    store.set(&DataKey::AssetTag(asset_id), &tag_hash);
+    Ok(())
+}
+
+// Note: Contract methods implemented in lib.rs
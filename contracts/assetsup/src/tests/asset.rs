@@ -2,46 +2,56 @@
 
 extern crate std;
 
-use soroban_sdk::{Address, BytesN, Env, String, testutils::Address as _};
+use soroban_sdk::{Address, String, testutils::Address as _};
 
 use crate::{
     asset::Asset,
     types::{AssetStatus, AssetType},
 };
 
-use super::initialize::setup_test_environment;
-
-fn make_bytes32(env: &Env, seed: u32) -> BytesN<32> {
-    let mut arr = [0u8; 32];
-    // Simple deterministic fill
-    for (i, item) in arr.iter_mut().enumerate() {
-        *item = ((seed as usize + i) % 256) as u8;
-    }
-    BytesN::from_array(env, &arr)
-}
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, make_bytes32, setup_company, setup_test_environment,
+};
 
 #[test]
 fn test_register_and_get_asset_success() {
-    let (env, client, _admin) = setup_test_environment();
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
     let owner = Address::generate(&env);
 
+    let branch_id = make_bytes32(&env, 100);
+    client.create_branch(
+        &company_id,
+        &branch_id,
+        &String::from_str(&env, "Main Branch"),
+        &String::from_str(&env, "Lagos"),
+        &admin,
+    );
+    client.create_department(
+        &branch_id,
+        &20,
+        &String::from_str(&env, "Engineering"),
+        &owner,
+    );
+
     let id = make_bytes32(&env, 1);
     let token = make_bytes32(&env, 2);
 
     let name = String::from_str(&env, "Laptop A");
-    let category = String::from_str(&env, "Electronics");
 
     let asset = Asset {
         id: id.clone(),
+        company_id: company_id.clone(),
         name: name.clone(),
         asset_type: AssetType::Digital,
-        category: category.clone(),
+        category_id: DEFAULT_CATEGORY,
         branch_id: 10,
         department_id: 20,
         status: AssetStatus::Active,
         purchase_date: 1_725_000_000,
         purchase_cost: 120_000,
         current_value: 100_000,
+        currency: DEFAULT_CURRENCY,
         warranty_expiry: 1_800_000_000,
         stellar_token_id: token.clone(),
         owner: owner.clone(),
@@ -55,7 +65,7 @@ fn test_register_and_get_asset_success() {
     assert_eq!(got.id, id);
     assert_eq!(got.name, name);
     assert_eq!(got.asset_type, AssetType::Digital);
-    assert_eq!(got.category, category);
+    assert_eq!(got.category_id, DEFAULT_CATEGORY);
     assert_eq!(got.branch_id, 10);
     assert_eq!(got.department_id, 20);
     assert_eq!(got.status, AssetStatus::Active);
@@ -70,26 +80,28 @@ fn test_register_and_get_asset_success() {
 #[test]
 #[should_panic]
 fn test_register_asset_duplicate() {
-    let (env, client, _admin) = setup_test_environment();
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
     let owner = Address::generate(&env);
 
     let id = make_bytes32(&env, 3);
     let token = make_bytes32(&env, 4);
 
     let name = String::from_str(&env, "Office Chair");
-    let category = String::from_str(&env, "Furniture");
 
     let asset = Asset {
         id: id.clone(),
+        company_id: company_id.clone(),
         name: name.clone(),
         asset_type: AssetType::Physical,
-        category: category.clone(),
+        category_id: DEFAULT_CATEGORY,
         branch_id: 1,
-        department_id: 2,
+        department_id: 0,
         status: AssetStatus::Active,
         purchase_date: 1_700_000_000,
         purchase_cost: 15_000,
         current_value: 12_000,
+        currency: DEFAULT_CURRENCY,
         warranty_expiry: 1_750_000_000,
         stellar_token_id: token.clone(),
         owner: owner.clone(),
@@ -0,0 +1,229 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{Address, BytesN, Env, String, Vec, testutils::Address as _};
+
+use crate::{
+    AssetUpContractClient,
+    asset::{Asset, MAX_BATCH_SIZE, MAX_WRITE_ENTRIES, RegistrationStatus},
+    error::Error,
+    types::{AssetStatus, AssetType},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, create_branch, make_bytes32, setup_company,
+    setup_test_environment,
+};
+
+fn make_asset(env: &Env, company_id: &BytesN<32>, seed: u32, owner: &Address) -> Asset {
+    Asset {
+        id: make_bytes32(env, seed),
+        company_id: company_id.clone(),
+        name: String::from_str(env, "Desk"),
+        asset_type: AssetType::Physical,
+        category_id: DEFAULT_CATEGORY,
+        branch_id: 1,
+        department_id: 0,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost: 300,
+        current_value: 250,
+        currency: DEFAULT_CURRENCY,
+        warranty_expiry: 1_800_000_000,
+        stellar_token_id: make_bytes32(env, seed + 1),
+        owner: owner.clone(),
+    }
+}
+
+/// Register `assets` in consecutive batches of `MAX_BATCH_SIZE`
+pub fn register_in_batches(
+    env: &Env,
+    client: &AssetUpContractClient,
+    assets: &[Asset],
+) -> std::vec::Vec<RegistrationStatus> {
+    let mut statuses = std::vec::Vec::new();
+    for chunk in assets.chunks(MAX_BATCH_SIZE as usize) {
+        let batch = Vec::from_slice(env, chunk);
+        statuses.extend(
+            client
+                .register_assets(&batch, &None)
+                .iter()
+                .map(|r| r.status),
+        );
+    }
+    statuses
+}
+
+#[test]
+fn test_register_assets_links_branch() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let branch_id = create_branch(&env, &client, &company_id, 200, &admin);
+
+    let owner = Address::generate(&env);
+    let mut assets = Vec::new(&env);
+    for seed in [1, 3, 5] {
+        assets.push_back(make_asset(&env, &company_id, seed, &owner));
+    }
+
+    let results = client.register_assets(&assets, &Some(branch_id.clone()));
+    assert_eq!(results.len(), 3);
+    for r in results.iter() {
+        assert_eq!(r.status, RegistrationStatus::Registered);
+    }
+
+    let linked = client.get_branch_assets(&branch_id, &0, &u32::MAX);
+    assert_eq!(linked.len(), 3);
+    assert_eq!(linked.get(2).unwrap(), make_bytes32(&env, 5));
+    assert_eq!(client.get_asset(&make_bytes32(&env, 3)).owner, owner);
+}
+
+#[test]
+fn test_register_assets_reports_bad_rows() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let owner = Address::generate(&env);
+
+    let existing = make_asset(&env, &company_id, 1, &owner);
+    client.register_asset(&existing);
+
+    let mut unnamed = make_asset(&env, &company_id, 3, &owner);
+    unnamed.name = String::from_str(&env, "");
+    let mut uncategorized = make_asset(&env, &company_id, 5, &owner);
+    uncategorized.category_id = 99;
+    let mut no_department = make_asset(&env, &company_id, 7, &owner);
+    no_department.department_id = 42;
+    let foreign = make_asset(&env, &make_bytes32(&env, 250), 9, &owner);
+    let good = make_asset(&env, &company_id, 11, &owner);
+
+    let statuses = register_in_batches(
+        &env,
+        &client,
+        &[
+            existing,
+            unnamed,
+            uncategorized,
+            no_department,
+            foreign,
+            good.clone(),
+            good,
+        ],
+    );
+    assert_eq!(
+        statuses,
+        [
+            RegistrationStatus::AlreadyExists,
+            RegistrationStatus::InvalidName,
+            RegistrationStatus::CategoryNotFound,
+            RegistrationStatus::DepartmentNotFound,
+            RegistrationStatus::CompanyNotFound,
+            RegistrationStatus::Registered,
+            RegistrationStatus::AlreadyExists,
+        ]
+    );
+    assert!(client.try_get_asset(&make_bytes32(&env, 3)).is_err());
+    assert!(client.try_get_asset(&make_bytes32(&env, 11)).is_ok());
+}
+
+#[test]
+fn test_register_assets_branch_of_other_company() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let other_company = make_bytes32(&env, 250);
+    client.create_company(&other_company, &String::from_str(&env, "Globex"), &admin);
+    let branch_id = create_branch(&env, &client, &other_company, 200, &admin);
+
+    let mut assets = Vec::new(&env);
+    assets.push_back(make_asset(&env, &company_id, 1, &Address::generate(&env)));
+
+    let results = client.register_assets(&assets, &Some(branch_id.clone()));
+    assert_eq!(
+        results.get(0).unwrap().status,
+        RegistrationStatus::CompanyMismatch
+    );
+    assert_eq!(client.get_branch_assets(&branch_id, &0, &u32::MAX).len(), 0);
+}
+
+#[test]
+fn test_register_assets_authorizes_each_owner_once() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let mut assets = Vec::new(&env);
+    assets.push_back(make_asset(&env, &company_id, 1, &alice));
+    assets.push_back(make_asset(&env, &company_id, 3, &bob));
+    assets.push_back(make_asset(&env, &company_id, 5, &alice));
+    client.register_assets(&assets, &None);
+
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(a, _)| a).collect();
+    assert_eq!(signers, [alice, admin, bob]);
+}
+
+#[test]
+fn test_register_assets_full_batch() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let owner = Address::generate(&env);
+
+    let mut assets = Vec::new(&env);
+    for i in 0..MAX_BATCH_SIZE {
+        assets.push_back(make_asset(&env, &company_id, i * 2 + 1, &owner));
+    }
+    let results = client.register_assets(&assets, &None);
+    assert_eq!(results.len(), MAX_BATCH_SIZE);
+
+    assets.push_back(make_asset(&env, &company_id, 201, &owner));
+    let res = client.try_register_assets(&assets, &None);
+    assert_eq!(res, Err(Ok(Error::BatchTooLarge)));
+}
+
+#[test]
+fn test_full_batch_fits_write_limit() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let branch_id = create_branch(&env, &client, &company_id, 200, &admin);
+
+    let owner = Address::generate(&env);
+    let mut assets = Vec::new(&env);
+    for i in 0..MAX_BATCH_SIZE {
+        assets.push_back(make_asset(&env, &company_id, i * 2 + 1, &owner));
+    }
+    client.register_assets(&assets, &Some(branch_id.clone()));
+    let resources = env.cost_estimate().resources();
+    assert!(resources.write_entries <= MAX_WRITE_ENTRIES);
+    assert_eq!(
+        client.get_branch_assets(&branch_id, &0, &u32::MAX).len(),
+        MAX_BATCH_SIZE
+    );
+}
+
+#[test]
+fn test_batch_over_write_limit_rejected() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+
+    // Distinct owners each bring an owner list and an auth nonce
+    let mut assets = Vec::new(&env);
+    for i in 0..MAX_BATCH_SIZE {
+        assets.push_back(make_asset(
+            &env,
+            &company_id,
+            i * 2 + 1,
+            &Address::generate(&env),
+        ));
+    }
+    let res = client.try_register_assets(&assets, &None);
+    assert_eq!(res, Err(Ok(Error::BatchTooLarge)));
+    assert!(client.try_get_asset(&make_bytes32(&env, 1)).is_err());
+
+    let mut half = Vec::new(&env);
+    for a in assets.iter().take(5) {
+        half.push_back(a);
+    }
+    client.register_assets(&half, &None);
+    let resources = env.cost_estimate().resources();
+    assert!(resources.write_entries <= MAX_WRITE_ENTRIES);
+}
@@ -2,7 +2,9 @@
 
 extern crate std;
 
-use super::initialize::setup_test_environment;
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, setup_company, setup_test_environment,
+};
 use crate::asset::Asset;
 use crate::types::{AssetStatus, AssetType};
 use soroban_sdk::{Address, BytesN, String, testutils::Address as _};
@@ -10,7 +12,7 @@ use soroban_sdk::{Address, BytesN, String, testutils::Address as _};
 #[test]
 fn test_create_branch() {
     let (env, client, admin) = setup_test_environment();
-    client.initialize(&admin);
+    let company_id = setup_company(&env, &client, &admin);
 
     let branch_id = BytesN::from_array(&env, &[1u8; 32]);
     let branch_name = String::from_str(&env, "Main Branch");
@@ -18,7 +20,13 @@ fn test_create_branch() {
     let branch_admin = Address::generate(&env);
 
     // Create branch
-    client.create_branch(&branch_id, &branch_name, &branch_location, &branch_admin);
+    client.create_branch(
+        &company_id,
+        &branch_id,
+        &branch_name,
+        &branch_location,
+        &branch_admin,
+    );
 
     // Verify branch was created
     let branch = client.get_branch(&branch_id);
@@ -28,7 +36,7 @@ fn test_create_branch() {
     assert_eq!(branch.admin, branch_admin);
 
     // Verify empty asset list was initialized
-    let assets = client.get_branch_assets(&branch_id);
+    let assets = client.get_branch_assets(&branch_id, &0, &u32::MAX);
     assert_eq!(assets.len(), 0);
 }
 
@@ -36,7 +44,7 @@ fn test_create_branch() {
 #[should_panic]
 fn test_create_branch_duplicate() {
     let (env, client, admin) = setup_test_environment();
-    client.initialize(&admin);
+    let company_id = setup_company(&env, &client, &admin);
 
     let branch_id = BytesN::from_array(&env, &[1u8; 32]);
     let branch_name = String::from_str(&env, "Main Branch");
@@ -44,17 +52,29 @@ fn test_create_branch_duplicate() {
     let branch_admin = Address::generate(&env);
 
     // Create branch first time
-    client.create_branch(&branch_id, &branch_name, &branch_location, &branch_admin);
+    client.create_branch(
+        &company_id,
+        &branch_id,
+        &branch_name,
+        &branch_location,
+        &branch_admin,
+    );
 
     // Try to create branch with same ID - should panic
-    client.create_branch(&branch_id, &branch_name, &branch_location, &branch_admin);
+    client.create_branch(
+        &company_id,
+        &branch_id,
+        &branch_name,
+        &branch_location,
+        &branch_admin,
+    );
 }
 
 #[test]
-#[should_panic]
+#[should_panic(expected = "Error(Contract, #100)")]
 fn test_create_branch_empty_name() {
     let (env, client, admin) = setup_test_environment();
-    client.initialize(&admin);
+    let company_id = setup_company(&env, &client, &admin);
 
     let branch_id = BytesN::from_array(&env, &[1u8; 32]);
     let branch_name = String::from_str(&env, ""); // Empty name
@@ -62,34 +82,48 @@ fn test_create_branch_empty_name() {
     let branch_admin = Address::generate(&env);
 
     // Should panic on empty name
-    client.create_branch(&branch_id, &branch_name, &branch_location, &branch_admin);
+    client.create_branch(
+        &company_id,
+        &branch_id,
+        &branch_name,
+        &branch_location,
+        &branch_admin,
+    );
 }
 
 #[test]
 fn test_add_asset_to_branch() {
     let (env, client, admin) = setup_test_environment();
-    client.initialize(&admin);
+    let company_id = setup_company(&env, &client, &admin);
 
     // Create branch
     let branch_id = BytesN::from_array(&env, &[1u8; 32]);
     let branch_name = String::from_str(&env, "Main Branch");
     let branch_location = String::from_str(&env, "New York");
     let branch_admin = Address::generate(&env);
-    client.create_branch(&branch_id, &branch_name, &branch_location, &branch_admin);
+    client.create_branch(
+        &company_id,
+        &branch_id,
+        &branch_name,
+        &branch_location,
+        &branch_admin,
+    );
 
     // Create asset
     let asset_id = BytesN::from_array(&env, &[2u8; 32]);
     let asset = Asset {
         id: asset_id.clone(),
+        company_id: company_id.clone(),
         name: String::from_str(&env, "Test Asset"),
         asset_type: AssetType::Physical,
-        category: String::from_str(&env, "Computer"),
+        category_id: DEFAULT_CATEGORY,
         branch_id: 1,
-        department_id: 1,
+        department_id: 0,
         status: AssetStatus::Active,
         purchase_date: 1234567890,
         purchase_cost: 1000,
         current_value: 800,
+        currency: DEFAULT_CURRENCY,
         warranty_expiry: 1234567890,
         stellar_token_id: BytesN::from_array(&env, &[3u8; 32]),
         owner: Address::generate(&env),
@@ -100,7 +134,7 @@ fn test_add_asset_to_branch() {
     client.add_asset_to_branch(&branch_id, &asset_id);
 
     // Verify asset is in branch
-    let assets = client.get_branch_assets(&branch_id);
+    let assets = client.get_branch_assets(&branch_id, &0, &u32::MAX);
     assert_eq!(assets.len(), 1);
     assert_eq!(assets.get(0).unwrap(), asset_id);
 }
@@ -109,7 +143,7 @@ fn test_add_asset_to_branch() {
 #[should_panic]
 fn test_add_asset_to_nonexistent_branch() {
     let (env, client, admin) = setup_test_environment();
-    client.initialize(&admin);
+    setup_company(&env, &client, &admin);
 
     let branch_id = BytesN::from_array(&env, &[1u8; 32]);
     let asset_id = BytesN::from_array(&env, &[2u8; 32]);
@@ -122,14 +156,20 @@ fn test_add_asset_to_nonexistent_branch() {
 #[should_panic]
 fn test_add_nonexistent_asset_to_branch() {
     let (env, client, admin) = setup_test_environment();
-    client.initialize(&admin);
+    let company_id = setup_company(&env, &client, &admin);
 
     // Create branch
     let branch_id = BytesN::from_array(&env, &[1u8; 32]);
     let branch_name = String::from_str(&env, "Main Branch");
     let branch_location = String::from_str(&env, "New York");
     let branch_admin = Address::generate(&env);
-    client.create_branch(&branch_id, &branch_name, &branch_location, &branch_admin);
+    client.create_branch(
+        &company_id,
+        &branch_id,
+        &branch_name,
+        &branch_location,
+        &branch_admin,
+    );
 
     let asset_id = BytesN::from_array(&env, &[2u8; 32]);
 
@@ -140,28 +180,36 @@ fn test_add_nonexistent_asset_to_branch() {
 #[test]
 fn test_add_duplicate_asset_to_branch() {
     let (env, client, admin) = setup_test_environment();
-    client.initialize(&admin);
+    let company_id = setup_company(&env, &client, &admin);
 
     // Create branch
     let branch_id = BytesN::from_array(&env, &[1u8; 32]);
     let branch_name = String::from_str(&env, "Main Branch");
     let branch_location = String::from_str(&env, "New York");
     let branch_admin = Address::generate(&env);
-    client.create_branch(&branch_id, &branch_name, &branch_location, &branch_admin);
+    client.create_branch(
+        &company_id,
+        &branch_id,
+        &branch_name,
+        &branch_location,
+        &branch_admin,
+    );
 
     // Create asset
     let asset_id = BytesN::from_array(&env, &[2u8; 32]);
     let asset = Asset {
         id: asset_id.clone(),
+        company_id: company_id.clone(),
         name: String::from_str(&env, "Test Asset"),
         asset_type: AssetType::Physical,
-        category: String::from_str(&env, "Computer"),
+        category_id: DEFAULT_CATEGORY,
         branch_id: 1,
-        department_id: 1,
+        department_id: 0,
         status: AssetStatus::Active,
         purchase_date: 1234567890,
         purchase_cost: 1000,
         current_value: 800,
+        currency: DEFAULT_CURRENCY,
         warranty_expiry: 1234567890,
         stellar_token_id: BytesN::from_array(&env, &[3u8; 32]),
         owner: Address::generate(&env),
@@ -175,7 +223,7 @@ fn test_add_duplicate_asset_to_branch() {
     client.add_asset_to_branch(&branch_id, &asset_id);
 
     // Verify asset is still only once in the list
-    let assets = client.get_branch_assets(&branch_id);
+    let assets = client.get_branch_assets(&branch_id, &0, &u32::MAX);
     assert_eq!(assets.len(), 1);
     assert_eq!(assets.get(0).unwrap(), asset_id);
 }
@@ -183,14 +231,20 @@ fn test_add_duplicate_asset_to_branch() {
 #[test]
 fn test_get_branch_assets_multiple() {
     let (env, client, admin) = setup_test_environment();
-    client.initialize(&admin);
+    let company_id = setup_company(&env, &client, &admin);
 
     // Create branch
     let branch_id = BytesN::from_array(&env, &[1u8; 32]);
     let branch_name = String::from_str(&env, "Main Branch");
     let branch_location = String::from_str(&env, "New York");
     let branch_admin = Address::generate(&env);
-    client.create_branch(&branch_id, &branch_name, &branch_location, &branch_admin);
+    client.create_branch(
+        &company_id,
+        &branch_id,
+        &branch_name,
+        &branch_location,
+        &branch_admin,
+    );
 
     // Create multiple assets
     let asset1_id = BytesN::from_array(&env, &[2u8; 32]);
@@ -199,15 +253,17 @@ fn test_get_branch_assets_multiple() {
 
     let asset1 = Asset {
         id: asset1_id.clone(),
+        company_id: company_id.clone(),
         name: String::from_str(&env, "Asset 1"),
         asset_type: AssetType::Physical,
-        category: String::from_str(&env, "Computer"),
+        category_id: DEFAULT_CATEGORY,
         branch_id: 1,
-        department_id: 1,
+        department_id: 0,
         status: AssetStatus::Active,
         purchase_date: 1234567890,
         purchase_cost: 1000,
         current_value: 800,
+        currency: DEFAULT_CURRENCY,
         warranty_expiry: 1234567890,
         stellar_token_id: BytesN::from_array(&env, &[5u8; 32]),
         owner: Address::generate(&env),
@@ -215,15 +271,17 @@ fn test_get_branch_assets_multiple() {
 
     let asset2 = Asset {
         id: asset2_id.clone(),
+        company_id: company_id.clone(),
         name: String::from_str(&env, "Asset 2"),
         asset_type: AssetType::Physical,
-        category: String::from_str(&env, "Desk"),
+        category_id: DEFAULT_CATEGORY,
         branch_id: 1,
-        department_id: 1,
+        department_id: 0,
         status: AssetStatus::Active,
         purchase_date: 1234567890,
         purchase_cost: 500,
         current_value: 400,
+        currency: DEFAULT_CURRENCY,
         warranty_expiry: 1234567890,
         stellar_token_id: BytesN::from_array(&env, &[6u8; 32]),
         owner: Address::generate(&env),
@@ -231,15 +289,17 @@ fn test_get_branch_assets_multiple() {
 
     let asset3 = Asset {
         id: asset3_id.clone(),
+        company_id: company_id.clone(),
         name: String::from_str(&env, "Asset 3"),
         asset_type: AssetType::Physical,
-        category: String::from_str(&env, "Car"),
+        category_id: DEFAULT_CATEGORY,
         branch_id: 1,
-        department_id: 1,
+        department_id: 0,
         status: AssetStatus::Active,
         purchase_date: 1234567890,
         purchase_cost: 20000,
         current_value: 15000,
+        currency: DEFAULT_CURRENCY,
         warranty_expiry: 1234567890,
         stellar_token_id: BytesN::from_array(&env, &[7u8; 32]),
         owner: Address::generate(&env),
@@ -255,7 +315,7 @@ fn test_get_branch_assets_multiple() {
     client.add_asset_to_branch(&branch_id, &asset3_id);
 
     // Get branch assets
-    let assets = client.get_branch_assets(&branch_id);
+    let assets = client.get_branch_assets(&branch_id, &0, &u32::MAX);
     assert_eq!(assets.len(), 3);
 
     // Verify all assets are present
@@ -280,19 +340,19 @@ fn test_get_branch_assets_multiple() {
 #[should_panic]
 fn test_get_branch_assets_nonexistent_branch() {
     let (env, client, admin) = setup_test_environment();
-    client.initialize(&admin);
+    setup_company(&env, &client, &admin);
 
     let branch_id = BytesN::from_array(&env, &[1u8; 32]);
 
     // Try to get assets for non-existent branch - should panic
-    client.get_branch_assets(&branch_id);
+    client.get_branch_assets(&branch_id, &0, &u32::MAX);
 }
 
 #[test]
 #[should_panic]
 fn test_get_branch_nonexistent() {
     let (env, client, admin) = setup_test_environment();
-    client.initialize(&admin);
+    setup_company(&env, &client, &admin);
 
     let branch_id = BytesN::from_array(&env, &[1u8; 32]);
 
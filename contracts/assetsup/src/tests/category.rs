@@ -0,0 +1,180 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{Address, BytesN, Env, String, testutils::Address as _};
+
+use crate::{
+    asset::Asset,
+    category::DepreciationSettings,
+    error::Error,
+    types::{AssetStatus, AssetType, DepreciationMethod},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, make_bytes32, setup_company, setup_test_environment,
+};
+
+fn make_asset(env: &Env, company_id: &BytesN<32>, seed: u32, category_id: u64) -> Asset {
+    Asset {
+        id: make_bytes32(env, seed),
+        company_id: company_id.clone(),
+        name: String::from_str(env, "Laptop"),
+        asset_type: AssetType::Physical,
+        category_id,
+        branch_id: 1,
+        department_id: 0,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost: 3_000,
+        current_value: 2_500,
+        currency: DEFAULT_CURRENCY,
+        warranty_expiry: 1_800_000_000,
+        stellar_token_id: make_bytes32(env, seed + 1),
+        owner: Address::generate(env),
+    }
+}
+
+fn straight_line(months: u32) -> DepreciationSettings {
+    DepreciationSettings {
+        method: DepreciationMethod::StraightLine,
+        useful_life_months: months,
+        salvage_value_bps: 500,
+    }
+}
+
+#[test]
+fn test_create_category_hierarchy() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+
+    let compute = String::from_str(&env, "Compute");
+    client.create_category(&company_id, &10, &compute, &0, &straight_line(48));
+    client.create_category(
+        &company_id,
+        &11,
+        &String::from_str(&env, "Laptops"),
+        &10,
+        &straight_line(36),
+    );
+
+    let category = client.get_category(&company_id, &10);
+    assert_eq!(category.name, compute);
+    assert_eq!(category.parent_id, 0);
+    assert_eq!(category.depreciation, straight_line(48));
+    assert_eq!(client.get_category(&company_id, &11).parent_id, 10);
+
+    let roots = client.get_subcategories(&company_id, &0);
+    assert_eq!(roots.len(), 2);
+    assert_eq!(roots.get(0).unwrap(), DEFAULT_CATEGORY);
+    assert_eq!(roots.get(1).unwrap(), 10);
+
+    let children = client.get_subcategories(&company_id, &10);
+    assert_eq!(children.len(), 1);
+    assert_eq!(children.get(0).unwrap(), 11);
+}
+
+#[test]
+fn test_create_category_duplicate() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+
+    let res = client.try_create_category(
+        &company_id,
+        &DEFAULT_CATEGORY,
+        &String::from_str(&env, "Again"),
+        &0,
+        &straight_line(12),
+    );
+    assert_eq!(res, Err(Ok(Error::CategoryAlreadyExists.into())));
+}
+
+#[test]
+fn test_create_category_unknown_parent() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+
+    let res = client.try_create_category(
+        &company_id,
+        &20,
+        &String::from_str(&env, "Orphan"),
+        &99,
+        &straight_line(12),
+    );
+    assert_eq!(res, Err(Ok(Error::CategoryNotFound.into())));
+}
+
+#[test]
+fn test_create_category_invalid_salvage() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+
+    let settings = DepreciationSettings {
+        method: DepreciationMethod::DecliningBalance,
+        useful_life_months: 12,
+        salvage_value_bps: 10_001,
+    };
+    let res = client.try_create_category(
+        &company_id,
+        &20,
+        &String::from_str(&env, "Vehicles"),
+        &0,
+        &settings,
+    );
+    assert_eq!(res, Err(Ok(Error::InvalidDepreciationSettings.into())));
+}
+
+#[test]
+fn test_set_category_depreciation() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+
+    let settings = DepreciationSettings {
+        method: DepreciationMethod::None,
+        useful_life_months: 0,
+        salvage_value_bps: 10_000,
+    };
+    client.set_category_depreciation(&company_id, &DEFAULT_CATEGORY, &settings);
+    assert_eq!(
+        client
+            .get_category(&company_id, &DEFAULT_CATEGORY)
+            .depreciation,
+        settings
+    );
+}
+
+#[test]
+fn test_register_asset_unknown_category() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+
+    let res = client.try_register_asset(&make_asset(&env, &company_id, 1, 77));
+    assert_eq!(res, Err(Ok(Error::CategoryNotFound.into())));
+}
+
+#[test]
+fn test_get_assets_by_category() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    client.create_category(
+        &company_id,
+        &10,
+        &String::from_str(&env, "Compute"),
+        &0,
+        &straight_line(48),
+    );
+
+    let laptop = make_asset(&env, &company_id, 1, 10);
+    let chair = make_asset(&env, &company_id, 3, DEFAULT_CATEGORY);
+    client.register_asset(&laptop);
+    client.register_asset(&chair);
+
+    let compute_assets = client.get_assets_by_category(&company_id, &10, &0, &u32::MAX);
+    assert_eq!(compute_assets.len(), 1);
+    assert_eq!(compute_assets.get(0).unwrap(), laptop.id);
+
+    let general_assets =
+        client.get_assets_by_category(&company_id, &DEFAULT_CATEGORY, &0, &u32::MAX);
+    assert_eq!(general_assets.len(), 1);
+    assert_eq!(general_assets.get(0).unwrap(), chair.id);
+}
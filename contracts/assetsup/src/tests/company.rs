@@ -0,0 +1,213 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    Address, BytesN, Env, IntoVal, String,
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+};
+
+use crate::{
+    AssetUpContractClient,
+    asset::Asset,
+    error::Error,
+    types::{AssetStatus, AssetType},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, create_branch, make_bytes32, setup_company,
+    setup_test_environment,
+};
+
+fn make_asset(env: &Env, company_id: &BytesN<32>, seed: u32) -> Asset {
+    Asset {
+        id: make_bytes32(env, seed),
+        company_id: company_id.clone(),
+        name: String::from_str(env, "Forklift"),
+        asset_type: AssetType::Physical,
+        category_id: DEFAULT_CATEGORY,
+        branch_id: 1,
+        department_id: 0,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost: 90_000,
+        current_value: 80_000,
+        currency: DEFAULT_CURRENCY,
+        warranty_expiry: 1_800_000_000,
+        stellar_token_id: make_bytes32(env, seed + 1),
+        owner: Address::generate(env),
+    }
+}
+
+/// Create a second tenant next to the one made by `setup_company`
+fn create_second_company(env: &Env, client: &AssetUpContractClient) -> (BytesN<32>, Address) {
+    let company_id = BytesN::from_array(env, &[0xC1; 32]);
+    let admin = Address::generate(env);
+    client.create_company(&company_id, &String::from_str(env, "Globex"), &admin);
+    (company_id, admin)
+}
+
+#[test]
+fn test_create_company() {
+    let (env, client, admin) = setup_test_environment();
+    client.initialize(&admin);
+
+    let company_id = make_bytes32(&env, 1);
+    let company_admin = Address::generate(&env);
+    let name = String::from_str(&env, "Initech");
+    client.create_company(&company_id, &name, &company_admin);
+
+    let company = client.get_company(&company_id);
+    assert_eq!(company.id, company_id);
+    assert_eq!(company.name, name);
+    assert_eq!(company.admin, company_admin);
+    assert_eq!(client.get_company_branches(&company_id).len(), 0);
+}
+
+#[test]
+fn test_create_company_duplicate() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+
+    let res = client.try_create_company(&company_id, &String::from_str(&env, "Again"), &admin);
+    assert_eq!(res, Err(Ok(Error::CompanyAlreadyExists.into())));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_create_company_without_admin_initialized() {
+    let (env, client, admin) = setup_test_environment();
+    client.create_company(
+        &make_bytes32(&env, 1),
+        &String::from_str(&env, "Initech"),
+        &admin,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #29)")]
+fn test_register_asset_unknown_company() {
+    let (env, client, admin) = setup_test_environment();
+    client.initialize(&admin);
+
+    client.register_asset(&make_asset(&env, &make_bytes32(&env, 9), 10));
+}
+
+#[test]
+fn test_set_company_admin() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+
+    let new_admin = Address::generate(&env);
+    client.set_company_admin(&company_id, &new_admin);
+    assert_eq!(client.get_company(&company_id).admin, new_admin);
+}
+
+#[test]
+fn test_company_branches_are_listed_per_company() {
+    let (env, client, admin) = setup_test_environment();
+    let company_a = setup_company(&env, &client, &admin);
+    let (company_b, _) = create_second_company(&env, &client);
+
+    let branch_a = create_branch(&env, &client, &company_a, 20, &admin);
+    let branch_b = create_branch(&env, &client, &company_b, 21, &admin);
+
+    let branches_a = client.get_company_branches(&company_a);
+    assert_eq!(branches_a.len(), 1);
+    assert_eq!(branches_a.get(0).unwrap(), branch_a);
+    assert_eq!(client.get_branch(&branch_b).company_id, company_b);
+}
+
+#[test]
+fn test_department_ids_are_namespaced_per_company() {
+    let (env, client, admin) = setup_test_environment();
+    let company_a = setup_company(&env, &client, &admin);
+    let (company_b, _) = create_second_company(&env, &client);
+
+    let branch_a = create_branch(&env, &client, &company_a, 20, &admin);
+    let branch_b = create_branch(&env, &client, &company_b, 21, &admin);
+
+    let finance = String::from_str(&env, "Finance");
+    let head_a = Address::generate(&env);
+    let head_b = Address::generate(&env);
+    client.create_department(&branch_a, &1, &finance, &head_a);
+    client.create_department(&branch_b, &1, &finance, &head_b);
+
+    assert_eq!(client.get_department(&company_a, &1).head, head_a);
+    assert_eq!(client.get_department(&company_b, &1).head, head_b);
+}
+
+#[test]
+fn test_add_asset_to_branch_of_other_company() {
+    let (env, client, admin) = setup_test_environment();
+    let company_a = setup_company(&env, &client, &admin);
+    let (company_b, _) = create_second_company(&env, &client);
+
+    let branch_b = create_branch(&env, &client, &company_b, 21, &admin);
+
+    let asset = make_asset(&env, &company_a, 30);
+    client.register_asset(&asset);
+
+    let res = client.try_add_asset_to_branch(&branch_b, &asset.id);
+    assert_eq!(res, Err(Ok(Error::CompanyMismatch)));
+    assert_eq!(client.get_branch_assets(&branch_b, &0, &u32::MAX).len(), 0);
+}
+
+#[test]
+fn test_other_company_admin_cannot_tokenize() {
+    let (env, client, admin) = setup_test_environment();
+    let company_a = setup_company(&env, &client, &admin);
+    let (_company_b, admin_b) = create_second_company(&env, &client);
+
+    let asset = make_asset(&env, &company_a, 40);
+    client.register_asset(&asset);
+
+    let token = make_bytes32(&env, 99);
+    let res = client
+        .mock_auths(&[MockAuth {
+            address: &admin_b,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "tokenize_asset",
+                args: (asset.id.clone(), token.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_tokenize_asset(&asset.id, &token);
+    assert!(res.is_err());
+    assert_eq!(
+        client.get_asset(&asset.id).stellar_token_id,
+        asset.stellar_token_id
+    );
+}
+
+#[test]
+fn test_other_company_admin_cannot_create_branch() {
+    let (env, client, admin) = setup_test_environment();
+    let company_a = setup_company(&env, &client, &admin);
+    let (_company_b, admin_b) = create_second_company(&env, &client);
+
+    let branch_id = make_bytes32(&env, 50);
+    let name = String::from_str(&env, "Rogue");
+    let location = String::from_str(&env, "Nowhere");
+    let res = client
+        .mock_auths(&[MockAuth {
+            address: &admin_b,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "create_branch",
+                args: (
+                    company_a.clone(),
+                    branch_id.clone(),
+                    name.clone(),
+                    location.clone(),
+                    admin_b.clone(),
+                )
+                    .into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_create_branch(&company_a, &branch_id, &name, &location, &admin_b);
+    assert!(res.is_err());
+    assert_eq!(client.get_company_branches(&company_a).len(), 0);
+}
@@ -0,0 +1,205 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{Address, BytesN, Env, String, Vec, symbol_short, testutils::Address as _};
+
+use crate::{
+    AssetUpContractClient,
+    asset::Asset,
+    cost_center::{Allocation, CostCenterShare},
+    currency::Currency,
+    error::CostCenterError,
+    stats::ValueTotal,
+    types::{AssetStatus, AssetType},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, make_bytes32, setup_company, setup_test_environment,
+};
+
+fn register_asset(
+    env: &Env,
+    client: &AssetUpContractClient,
+    company_id: &BytesN<32>,
+    seed: u32,
+    purchase_cost: i128,
+    current_value: i128,
+) -> Asset {
+    let asset = Asset {
+        id: make_bytes32(env, seed),
+        company_id: company_id.clone(),
+        name: String::from_str(env, "Vehicle"),
+        asset_type: AssetType::Physical,
+        category_id: DEFAULT_CATEGORY,
+        branch_id: 1,
+        department_id: 0,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost,
+        current_value,
+        currency: DEFAULT_CURRENCY,
+        warranty_expiry: 1_800_000_000,
+        stellar_token_id: make_bytes32(env, seed + 1),
+        owner: Address::generate(env),
+    };
+    client.register_asset(&asset);
+    asset
+}
+
+fn split(env: &Env, shares: &[(u64, u32)]) -> Vec<Allocation> {
+    let mut allocations = Vec::new(env);
+    for (cost_center_id, bps) in shares {
+        allocations.push_back(Allocation {
+            cost_center_id: *cost_center_id,
+            bps: *bps,
+        });
+    }
+    allocations
+}
+
+fn setup_cost_centers(env: &Env, client: &AssetUpContractClient, company_id: &BytesN<32>) {
+    client.create_cost_center(company_id, &100, &String::from_str(env, "Sales"));
+    client.create_cost_center(company_id, &200, &String::from_str(env, "Logistics"));
+}
+
+fn totals(env: &Env, entries: &[(Currency, i128, i128)]) -> Vec<ValueTotal> {
+    let mut totals = Vec::new(env);
+    for (currency, purchase_cost, current_value) in entries {
+        totals.push_back(ValueTotal {
+            currency: currency.clone(),
+            purchase_cost: *purchase_cost,
+            current_value: *current_value,
+        });
+    }
+    totals
+}
+
+#[test]
+fn test_create_cost_center() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    setup_cost_centers(&env, &client, &company_id);
+
+    let cc = client.get_cost_center(&company_id, &100);
+    assert_eq!(cc.name, String::from_str(&env, "Sales"));
+    assert_eq!(client.get_company_cost_centers(&company_id).len(), 2);
+
+    let res = client.try_create_cost_center(&company_id, &100, &String::from_str(&env, "Dup"));
+    assert_eq!(
+        res,
+        Err(Ok(CostCenterError::CostCenterAlreadyExists.into()))
+    );
+}
+
+#[test]
+fn test_cost_center_shares() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    setup_cost_centers(&env, &client, &company_id);
+
+    let van = register_asset(&env, &client, &company_id, 1, 30_000, 20_000);
+    let truck = register_asset(&env, &client, &company_id, 3, 90_000, 80_000);
+    client.set_asset_allocation(&van.id, &split(&env, &[(100, 2_500), (200, 7_500)]));
+    client.set_asset_allocation(&truck.id, &split(&env, &[(200, 10_000)]));
+
+    let shares = client.get_cost_center_shares(&company_id);
+    assert_eq!(
+        shares.get(0).unwrap(),
+        CostCenterShare {
+            cost_center_id: 100,
+            asset_count: 1,
+            totals: totals(&env, &[(DEFAULT_CURRENCY, 7_500, 5_000)]),
+        }
+    );
+    assert_eq!(
+        shares.get(1).unwrap(),
+        CostCenterShare {
+            cost_center_id: 200,
+            asset_count: 2,
+            totals: totals(&env, &[(DEFAULT_CURRENCY, 112_500, 95_000)]),
+        }
+    );
+
+    // Reallocating moves the van out of Sales entirely
+    client.set_asset_allocation(&van.id, &split(&env, &[(200, 10_000)]));
+    let sales = client.get_cost_center_share(&company_id, &100);
+    assert_eq!(sales.asset_count, 0);
+    assert!(sales.totals.is_empty());
+    assert_eq!(
+        client.get_cost_center_share(&company_id, &200).totals,
+        totals(&env, &[(DEFAULT_CURRENCY, 120_000, 100_000)])
+    );
+
+    client.dispose_asset(&truck.id);
+    assert_eq!(
+        client.get_cost_center_share(&company_id, &200).totals,
+        totals(&env, &[(DEFAULT_CURRENCY, 30_000, 20_000)])
+    );
+}
+
+#[test]
+fn test_cost_center_shares_split_by_currency() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    setup_cost_centers(&env, &client, &company_id);
+
+    let ngn = Currency {
+        code: symbol_short!("NGN"),
+        scale: 2,
+    };
+    let van = register_asset(&env, &client, &company_id, 1, 30_000, 20_000);
+    let mut generator = van.clone();
+    generator.id = make_bytes32(&env, 3);
+    generator.currency = ngn.clone();
+    generator.purchase_cost = 5_000_000;
+    generator.current_value = 4_000_000;
+    client.register_asset(&generator);
+
+    client.set_asset_allocation(&van.id, &split(&env, &[(200, 10_000)]));
+    client.set_asset_allocation(&generator.id, &split(&env, &[(200, 5_000), (100, 5_000)]));
+
+    let share = client.get_cost_center_share(&company_id, &200);
+    assert_eq!(share.asset_count, 2);
+    assert_eq!(
+        share.totals,
+        totals(
+            &env,
+            &[
+                (DEFAULT_CURRENCY, 30_000, 20_000),
+                (ngn, 2_500_000, 2_000_000)
+            ]
+        )
+    );
+}
+
+#[test]
+fn test_invalid_allocations() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    setup_cost_centers(&env, &client, &company_id);
+    let van = register_asset(&env, &client, &company_id, 1, 30_000, 20_000);
+
+    for (shares, err) in [
+        (
+            &[(100, 5_000), (200, 4_999)][..],
+            CostCenterError::AllocationIncomplete,
+        ),
+        (
+            &[(100, 5_000), (100, 5_000)][..],
+            CostCenterError::InvalidShare,
+        ),
+        (
+            &[(100, 10_000), (200, 0)][..],
+            CostCenterError::InvalidShare,
+        ),
+        (&[][..], CostCenterError::AllocationIncomplete),
+    ] {
+        let res = client.try_set_asset_allocation(&van.id, &split(&env, shares));
+        assert_eq!(res, Err(Ok(err.into())));
+    }
+
+    let res = client.try_set_asset_allocation(&van.id, &split(&env, &[(300, 10_000)]));
+    assert_eq!(res, Err(Ok(CostCenterError::CostCenterNotFound.into())));
+    assert_eq!(client.get_asset_allocation(&van.id).len(), 0);
+}
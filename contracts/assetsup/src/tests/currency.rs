@@ -0,0 +1,213 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, symbol_short, testutils::Address as _};
+
+use crate::{
+    AssetUpContractClient,
+    asset::Asset,
+    currency::{Currency, FX_RATE_SCALE, Money},
+    error::{CurrencyError, Error, ValidationError},
+    types::{AssetStatus, AssetType},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, create_branch, make_bytes32, setup_company,
+    setup_test_environment,
+};
+
+const NGN: Currency = Currency {
+    code: symbol_short!("NGN"),
+    scale: 2,
+};
+
+fn add_asset(
+    env: &Env,
+    client: &AssetUpContractClient,
+    company_id: &BytesN<32>,
+    branch_id: &BytesN<32>,
+    seed: u32,
+    current_value: i128,
+    currency: &Currency,
+) {
+    let asset = Asset {
+        id: make_bytes32(env, seed),
+        company_id: company_id.clone(),
+        name: String::from_str(env, "Generator"),
+        asset_type: AssetType::Physical,
+        category_id: DEFAULT_CATEGORY,
+        branch_id: 1,
+        department_id: 0,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost: current_value,
+        current_value,
+        currency: currency.clone(),
+        warranty_expiry: 1_800_000_000,
+        stellar_token_id: make_bytes32(env, seed + 1),
+        owner: Address::generate(env),
+    };
+    client.register_asset(&asset);
+    client.add_asset_to_branch(branch_id, &asset.id);
+}
+
+#[test]
+fn test_reporting_currency() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let branch_id = create_branch(&env, &client, &company_id, 100, &Address::generate(&env));
+    client.set_reporting_currency(&branch_id, &NGN);
+
+    assert_eq!(client.get_reporting_currency(&branch_id), NGN);
+
+    let res = client.try_set_reporting_currency(
+        &branch_id,
+        &Currency {
+            code: symbol_short!("NGN"),
+            scale: 19,
+        },
+    );
+    assert_eq!(res, Err(Ok(ValidationError::InvalidCurrency.into())));
+
+    // Codes must be three uppercase ASCII letters
+    for code in ["ngn", "NG", "NGNX", "N1N", "N_N", "NAIRA_NGN_CODE"] {
+        let res = client.try_set_reporting_currency(
+            &branch_id,
+            &Currency {
+                code: Symbol::new(&env, code),
+                scale: 2,
+            },
+        );
+        assert_eq!(res, Err(Ok(ValidationError::InvalidCurrency.into())));
+    }
+    assert_eq!(client.get_reporting_currency(&branch_id), NGN);
+}
+
+#[test]
+fn test_fx_rate_sources() {
+    let (env, client, admin) = setup_test_environment();
+    client.initialize(&admin);
+    let usd = symbol_short!("USD");
+    let ngn = symbol_short!("NGN");
+
+    client.set_fx_rate(&admin, &usd, &ngn, &(1_500 * FX_RATE_SCALE));
+    assert_eq!(client.get_fx_rate(&usd, &ngn).source, admin);
+
+    let feeder = Address::generate(&env);
+    let res = client.try_set_fx_rate(&feeder, &usd, &ngn, &(1_600 * FX_RATE_SCALE));
+    assert_eq!(res, Err(Ok(Error::Unauthorized.into())));
+
+    client.set_fx_feeder(&feeder);
+    client.set_fx_rate(&feeder, &usd, &ngn, &(1_600 * FX_RATE_SCALE));
+    let fx = client.get_fx_rate(&usd, &ngn);
+    assert_eq!(fx.rate, 1_600 * FX_RATE_SCALE);
+    assert_eq!(fx.source, feeder);
+
+    let res = client.try_set_fx_rate(&admin, &usd, &ngn, &0);
+    assert_eq!(res, Err(Ok(CurrencyError::InvalidFxRate.into())));
+}
+
+#[test]
+fn test_convert_amount() {
+    let (env, client, admin) = setup_test_environment();
+    client.initialize(&admin);
+    client.set_fx_rate(
+        &admin,
+        &symbol_short!("USD"),
+        &symbol_short!("NGN"),
+        &(1_500 * FX_RATE_SCALE),
+    );
+
+    assert_eq!(
+        client.convert_amount(&1_000, &DEFAULT_CURRENCY, &NGN),
+        1_500_000
+    );
+    assert_eq!(
+        client.convert_amount(&1_500_000, &NGN, &DEFAULT_CURRENCY),
+        1_000
+    );
+
+    let usd_7dp = Currency {
+        code: symbol_short!("USD"),
+        scale: 7,
+    };
+    assert_eq!(
+        client.convert_amount(&125, &DEFAULT_CURRENCY, &usd_7dp),
+        12_500_000
+    );
+
+    let eur = Currency {
+        code: Symbol::new(&env, "EUR"),
+        scale: 2,
+    };
+    let res = client.try_convert_amount(&100, &eur, &NGN);
+    assert_eq!(res, Err(Ok(CurrencyError::FxRateNotFound.into())));
+
+    let usd_40dp = Currency {
+        code: symbol_short!("USD"),
+        scale: 40,
+    };
+    let res = client.try_convert_amount(&100, &DEFAULT_CURRENCY, &usd_40dp);
+    assert_eq!(res, Err(Ok(ValidationError::InvalidCurrency.into())));
+}
+
+#[test]
+fn test_branch_and_company_values() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    client.set_fx_rate(
+        &admin,
+        &symbol_short!("USD"),
+        &symbol_short!("NGN"),
+        &(1_500 * FX_RATE_SCALE),
+    );
+
+    let lagos = create_branch(&env, &client, &company_id, 100, &Address::generate(&env));
+    client.set_reporting_currency(&lagos, &NGN);
+    let nyc = create_branch(&env, &client, &company_id, 110, &Address::generate(&env));
+    client.set_reporting_currency(&nyc, &DEFAULT_CURRENCY);
+    add_asset(&env, &client, &company_id, &lagos, 1, 300_000_000, &NGN);
+    add_asset(
+        &env,
+        &client,
+        &company_id,
+        &lagos,
+        3,
+        50_000,
+        &DEFAULT_CURRENCY,
+    );
+    add_asset(
+        &env,
+        &client,
+        &company_id,
+        &nyc,
+        5,
+        100_000,
+        &DEFAULT_CURRENCY,
+    );
+
+    assert_eq!(
+        client.get_branch_value(&lagos),
+        Money {
+            amount: 375_000_000,
+            currency: NGN,
+        }
+    );
+    assert_eq!(client.get_branch_value(&nyc).amount, 100_000);
+    assert_eq!(
+        client
+            .get_company_value(&company_id, &DEFAULT_CURRENCY)
+            .amount,
+        350_000
+    );
+    // Linking an asset to a second branch does not count it twice
+    client.add_asset_to_branch(&nyc, &make_bytes32(&env, 3));
+    assert_eq!(client.get_branch_value(&nyc).amount, 150_000);
+    assert_eq!(
+        client
+            .get_company_value(&company_id, &DEFAULT_CURRENCY)
+            .amount,
+        350_000
+    );
+}
@@ -0,0 +1,173 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{Address, BytesN, Env, String, testutils::Address as _};
+
+use crate::{
+    asset::Asset,
+    error::Error,
+    types::{AssetStatus, AssetType},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, create_branch, make_bytes32, setup_company,
+    setup_test_environment,
+};
+
+fn make_asset(env: &Env, company_id: &BytesN<32>, seed: u32, department_id: u64) -> Asset {
+    Asset {
+        id: make_bytes32(env, seed),
+        company_id: company_id.clone(),
+        name: String::from_str(env, "Workstation"),
+        asset_type: AssetType::Physical,
+        category_id: DEFAULT_CATEGORY,
+        branch_id: 1,
+        department_id,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost: 2_000,
+        current_value: 1_500,
+        currency: DEFAULT_CURRENCY,
+        warranty_expiry: 1_800_000_000,
+        stellar_token_id: make_bytes32(env, seed + 1),
+        owner: Address::generate(env),
+    }
+}
+
+#[test]
+fn test_create_department() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let branch_id = create_branch(&env, &client, &company_id, 1, &Address::generate(&env));
+    let head = Address::generate(&env);
+    let name = String::from_str(&env, "Finance");
+
+    client.create_department(&branch_id, &10, &name, &head);
+
+    let dept = client.get_department(&company_id, &10);
+    assert_eq!(dept.id, 10);
+    assert_eq!(dept.company_id, company_id);
+    assert_eq!(dept.branch_id, branch_id);
+    assert_eq!(dept.name, name);
+    assert_eq!(dept.head, head);
+
+    let departments = client.get_branch_departments(&branch_id);
+    assert_eq!(departments.len(), 1);
+    assert_eq!(departments.get(0).unwrap(), 10);
+}
+
+#[test]
+fn test_create_department_duplicate() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let branch_id = create_branch(&env, &client, &company_id, 1, &Address::generate(&env));
+    let head = Address::generate(&env);
+    let name = String::from_str(&env, "Finance");
+
+    client.create_department(&branch_id, &10, &name, &head);
+    let res = client.try_create_department(&branch_id, &10, &name, &head);
+    assert_eq!(res, Err(Ok(Error::DepartmentAlreadyExists.into())));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_create_department_nonexistent_branch() {
+    let (env, client, admin) = setup_test_environment();
+    setup_company(&env, &client, &admin);
+
+    let branch_id = BytesN::from_array(&env, &[9u8; 32]);
+    client.create_department(
+        &branch_id,
+        &10,
+        &String::from_str(&env, "Finance"),
+        &Address::generate(&env),
+    );
+}
+
+#[test]
+fn test_register_asset_indexes_department() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let branch_id = create_branch(&env, &client, &company_id, 1, &Address::generate(&env));
+    client.create_department(
+        &branch_id,
+        &10,
+        &String::from_str(&env, "Finance"),
+        &Address::generate(&env),
+    );
+
+    let asset = make_asset(&env, &company_id, 1, 10);
+    client.register_asset(&asset);
+
+    let assets = client.get_department_assets(&company_id, &10, &0, &u32::MAX);
+    assert_eq!(assets.len(), 1);
+    assert_eq!(assets.get(0).unwrap(), asset.id);
+}
+
+#[test]
+fn test_register_asset_unknown_department() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+
+    let res = client.try_register_asset(&make_asset(&env, &company_id, 1, 42));
+    assert_eq!(res, Err(Ok(Error::DepartmentNotFound.into())));
+}
+
+#[test]
+fn test_assign_asset_to_department() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let branch_id = create_branch(&env, &client, &company_id, 1, &Address::generate(&env));
+    let finance_head = Address::generate(&env);
+    let ops_head = Address::generate(&env);
+    client.create_department(
+        &branch_id,
+        &10,
+        &String::from_str(&env, "Finance"),
+        &finance_head,
+    );
+    client.create_department(
+        &branch_id,
+        &11,
+        &String::from_str(&env, "Operations"),
+        &ops_head,
+    );
+
+    let asset = make_asset(&env, &company_id, 1, 10);
+    client.register_asset(&asset);
+
+    client.assign_asset_to_department(&asset.id, &11);
+
+    assert_eq!(client.get_asset(&asset.id).department_id, 11);
+    assert_eq!(
+        client
+            .get_department_assets(&company_id, &10, &0, &u32::MAX)
+            .len(),
+        0
+    );
+    assert_eq!(
+        client
+            .get_department_assets(&company_id, &11, &0, &u32::MAX)
+            .get(0)
+            .unwrap(),
+        asset.id
+    );
+}
+
+#[test]
+fn test_set_department_head() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let branch_id = create_branch(&env, &client, &company_id, 1, &Address::generate(&env));
+    client.create_department(
+        &branch_id,
+        &10,
+        &String::from_str(&env, "Finance"),
+        &Address::generate(&env),
+    );
+
+    let new_head = Address::generate(&env);
+    client.set_department_head(&company_id, &10, &new_head);
+    assert_eq!(client.get_department(&company_id, &10).head, new_head);
+}
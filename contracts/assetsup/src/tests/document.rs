@@ -0,0 +1,162 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, String,
+    testutils::{Address as _, Ledger},
+};
+
+use crate::{
+    AssetUpContractClient,
+    asset::Asset,
+    document::{DocumentKind, HashType},
+    error::Error,
+    types::{AssetStatus, AssetType},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, make_bytes32, setup_company, setup_test_environment,
+};
+
+fn register_asset(
+    env: &Env,
+    client: &AssetUpContractClient,
+    company_id: &BytesN<32>,
+    seed: u32,
+) -> Asset {
+    let asset = Asset {
+        id: make_bytes32(env, seed),
+        company_id: company_id.clone(),
+        name: String::from_str(env, "Projector"),
+        asset_type: AssetType::Physical,
+        category_id: DEFAULT_CATEGORY,
+        branch_id: 1,
+        department_id: 0,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost: 4_000,
+        current_value: 3_000,
+        currency: DEFAULT_CURRENCY,
+        warranty_expiry: 1_800_000_000,
+        stellar_token_id: make_bytes32(env, seed + 1),
+        owner: Address::generate(env),
+    };
+    client.register_asset(&asset);
+    asset
+}
+
+#[test]
+fn test_attach_and_verify_document() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    env.ledger().set_timestamp(42);
+    let asset = register_asset(&env, &client, &company_id, 1);
+
+    let invoice_hash: Bytes = make_bytes32(&env, 100).into();
+    client.attach_document(
+        &asset.id,
+        &invoice_hash,
+        &HashType::Sha256,
+        &DocumentKind::Invoice,
+        &asset.owner,
+    );
+
+    assert!(client.verify_document(&asset.id, &invoice_hash));
+
+    let tampered: Bytes = make_bytes32(&env, 101).into();
+    assert!(!client.verify_document(&asset.id, &tampered));
+
+    let doc = client.get_document(&asset.id, &invoice_hash);
+    assert_eq!(doc.kind, DocumentKind::Invoice);
+    assert_eq!(doc.hash_type, HashType::Sha256);
+    assert_eq!(doc.uploader, asset.owner);
+    assert_eq!(doc.uploaded_at, 42);
+}
+
+#[test]
+fn test_list_asset_documents() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let asset = register_asset(&env, &client, &company_id, 1);
+
+    let photo: Bytes = make_bytes32(&env, 100).into();
+    let manual_cid = Bytes::from_slice(&env, &[0x01, 0x70, 0x12, 0x20, 0xAA, 0xBB]);
+    client.attach_document(
+        &asset.id,
+        &photo,
+        &HashType::Sha256,
+        &DocumentKind::Photo,
+        &asset.owner,
+    );
+    client.attach_document(
+        &asset.id,
+        &manual_cid,
+        &HashType::IpfsCid,
+        &DocumentKind::Manual,
+        &admin,
+    );
+
+    let docs = client.get_asset_documents(&asset.id);
+    assert_eq!(docs.len(), 2);
+    assert_eq!(docs.get(0).unwrap().hash, photo);
+    assert_eq!(docs.get(1).unwrap().hash, manual_cid);
+    assert_eq!(docs.get(1).unwrap().uploader, admin);
+}
+
+#[test]
+fn test_attach_document_duplicate() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let asset = register_asset(&env, &client, &company_id, 1);
+
+    let hash: Bytes = make_bytes32(&env, 100).into();
+    client.attach_document(
+        &asset.id,
+        &hash,
+        &HashType::Sha256,
+        &DocumentKind::Receipt,
+        &asset.owner,
+    );
+    let res = client.try_attach_document(
+        &asset.id,
+        &hash,
+        &HashType::Sha256,
+        &DocumentKind::Receipt,
+        &asset.owner,
+    );
+    assert_eq!(res, Err(Ok(Error::DocumentAlreadyExists)));
+}
+
+#[test]
+fn test_attach_document_invalid_hash() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let asset = register_asset(&env, &client, &company_id, 1);
+
+    let short = Bytes::from_slice(&env, &[1, 2, 3]);
+    let res = client.try_attach_document(
+        &asset.id,
+        &short,
+        &HashType::Sha256,
+        &DocumentKind::Invoice,
+        &asset.owner,
+    );
+    assert_eq!(res, Err(Ok(Error::InvalidDocumentHash)));
+}
+
+#[test]
+fn test_attach_document_by_stranger() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let asset = register_asset(&env, &client, &company_id, 1);
+
+    let res = client.try_attach_document(
+        &asset.id,
+        &make_bytes32(&env, 100).into(),
+        &HashType::Sha256,
+        &DocumentKind::Other,
+        &Address::generate(&env),
+    );
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+}
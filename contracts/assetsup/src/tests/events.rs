@@ -0,0 +1,159 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    Address, BytesN, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec,
+    testutils::{Address as _, Events},
+};
+
+use crate::{
+    asset::Asset,
+    events::{
+        self, AssetRegistered, AssetStatusChanged, AssetTransferred, BranchCreated,
+        CompanyAdminChanged,
+    },
+    types::{AssetStatus, AssetType},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, make_bytes32, setup_company, setup_test_environment,
+};
+
+/// Name, subject id and data of the events published by the last call
+fn last_events(env: &Env) -> std::vec::Vec<(Symbol, BytesN<32>, Val)> {
+    env.events()
+        .all()
+        .iter()
+        .map(|(_, topics, data)| {
+            let name = Symbol::try_from_val(env, &topics.get_unchecked(0)).unwrap();
+            let id = BytesN::try_from_val(env, &topics.get_unchecked(1)).unwrap();
+            (name, id, data)
+        })
+        .collect()
+}
+
+#[test]
+fn test_asset_lifecycle_events() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let asset = Asset {
+        id: make_bytes32(&env, 1),
+        company_id: company_id.clone(),
+        name: String::from_str(&env, "Projector"),
+        asset_type: AssetType::Physical,
+        category_id: DEFAULT_CATEGORY,
+        branch_id: 1,
+        department_id: 0,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost: 1_000,
+        current_value: 900,
+        currency: DEFAULT_CURRENCY,
+        warranty_expiry: 0,
+        stellar_token_id: BytesN::from_array(&env, &[0u8; 32]),
+        owner: owner.clone(),
+    };
+    client.register_asset(&asset);
+    let published = last_events(&env);
+    assert_eq!(published.len(), 1);
+    let (name, id, data) = &published[0];
+    assert_eq!(*name, Symbol::new(&env, events::ASSET_REGISTERED));
+    assert_eq!(*id, asset.id);
+    let data = AssetRegistered::try_from_val(&env, data).unwrap();
+    // initialize publishes nothing; company creation was event 0
+    assert_eq!(data.seq, 1);
+    assert_eq!(data.owner, owner);
+    assert_eq!(data.current_value, 900);
+    assert_eq!(data.stellar_token_id, asset.stellar_token_id);
+
+    client.transfer_asset(&asset.id, &buyer);
+    let (_, _, data) = &last_events(&env)[0];
+    let data = AssetTransferred::try_from_val(&env, data).unwrap();
+    assert_eq!((data.seq, data.from, data.to), (2, owner, buyer));
+
+    client.dispose_asset(&asset.id);
+    let (name, _, data) = &last_events(&env)[0];
+    assert_eq!(*name, Symbol::new(&env, events::ASSET_STATUS_CHANGED));
+    let data = AssetStatusChanged::try_from_val(&env, data).unwrap();
+    assert_eq!(data.seq, 3);
+    assert_eq!(data.to, AssetStatus::Disposed);
+}
+
+#[test]
+fn test_branch_events_share_the_sequence() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let branch_id = make_bytes32(&env, 100);
+
+    client.create_branch(
+        &company_id,
+        &branch_id,
+        &String::from_str(&env, "Depot"),
+        &String::from_str(&env, "Lagos"),
+        &admin,
+    );
+    let (name, id, data) = &last_events(&env)[0];
+    assert_eq!(*name, Symbol::new(&env, events::BRANCH_CREATED));
+    assert_eq!(*id, branch_id);
+    let data = BranchCreated::try_from_val(&env, data).unwrap();
+    assert_eq!((data.seq, data.company_id), (1, company_id.clone()));
+
+    // Batch registration with a branch: registration then link per asset
+    let mut assets: Vec<Asset> = Vec::new(&env);
+    for seed in [1, 3] {
+        assets.push_back(Asset {
+            id: make_bytes32(&env, seed),
+            company_id: company_id.clone(),
+            name: String::from_str(&env, "Car"),
+            asset_type: AssetType::Physical,
+            category_id: DEFAULT_CATEGORY,
+            branch_id: 1,
+            department_id: 0,
+            status: AssetStatus::Active,
+            purchase_date: 1_700_000_000,
+            purchase_cost: 1_000,
+            current_value: 900,
+            currency: DEFAULT_CURRENCY,
+            warranty_expiry: 0,
+            stellar_token_id: BytesN::from_array(&env, &[0u8; 32]),
+            owner: Address::generate(&env),
+        });
+    }
+    client.register_assets(&assets, &Some(branch_id.clone()));
+
+    let names: std::vec::Vec<Symbol> = last_events(&env).into_iter().map(|e| e.0).collect();
+    let registered = Symbol::new(&env, events::ASSET_REGISTERED);
+    let linked = Symbol::new(&env, events::ASSET_LINKED);
+    assert_eq!(
+        names,
+        [registered.clone(), linked.clone(), registered, linked]
+    );
+    let seqs: std::vec::Vec<u64> = last_events(&env)
+        .iter()
+        .map(|(_, _, data)| {
+            let data: soroban_sdk::Map<Symbol, Val> = data.into_val(&env);
+            u64::try_from_val(&env, &data.get(Symbol::new(&env, "seq")).unwrap()).unwrap()
+        })
+        .collect();
+    assert_eq!(seqs, [2, 3, 4, 5]);
+}
+
+#[test]
+fn test_company_admin_change_event() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let successor = Address::generate(&env);
+
+    client.set_company_admin(&company_id, &successor);
+    let published = last_events(&env);
+    assert_eq!(published.len(), 1);
+    let (name, id, data) = &published[0];
+    assert_eq!(*name, Symbol::new(&env, events::COMPANY_ADMIN_CHANGED));
+    assert_eq!(*id, company_id);
+    let data = CompanyAdminChanged::try_from_val(&env, data).unwrap();
+    assert_eq!((data.seq, data.from, data.to), (1, admin, successor));
+}
@@ -2,8 +2,20 @@
 
 extern crate std;
 
-use crate::{AssetUpContract, AssetUpContractClient};
-use soroban_sdk::{Address, Env, testutils::Address as _};
+use crate::{
+    AssetUpContract, AssetUpContractClient, DepreciationMethod, category::DepreciationSettings,
+    currency::Currency, error::Error,
+};
+use soroban_sdk::{Address, BytesN, Env, String, symbol_short, testutils::Address as _};
+
+/// Deterministic 32-byte id derived from `seed`
+pub fn make_bytes32(env: &Env, seed: u32) -> BytesN<32> {
+    let mut arr = [0u8; 32];
+    for (i, item) in arr.iter_mut().enumerate() {
+        *item = ((seed as usize + i) % 256) as u8;
+    }
+    BytesN::from_array(env, &arr)
+}
 
 /// Setup test environment with contract and addresses
 pub fn setup_test_environment() -> (Env, AssetUpContractClient<'static>, Address) {
@@ -18,6 +30,56 @@ pub fn setup_test_environment() -> (Env, AssetUpContractClient<'static>, Address
     (env, client, admin)
 }
 
+/// Category created for every company made by `setup_company`
+pub const DEFAULT_CATEGORY: u64 = 1;
+
+/// Currency of the assets built by the test helpers
+pub const DEFAULT_CURRENCY: Currency = Currency {
+    code: symbol_short!("USD"),
+    scale: 2,
+};
+
+/// Initialize the contract with `admin` and create a company it administers,
+/// together with a top-level `DEFAULT_CATEGORY`
+pub fn setup_company(env: &Env, client: &AssetUpContractClient, admin: &Address) -> BytesN<32> {
+    client.initialize(admin);
+
+    let company_id = BytesN::from_array(env, &[0xC0; 32]);
+    client.create_company(&company_id, &String::from_str(env, "Acme Ltd"), admin);
+    client.create_category(
+        &company_id,
+        &DEFAULT_CATEGORY,
+        &String::from_str(env, "General"),
+        &0,
+        &DepreciationSettings {
+            method: DepreciationMethod::StraightLine,
+            useful_life_months: 60,
+            salvage_value_bps: 0,
+        },
+    );
+    company_id
+}
+
+/// Create a branch of `company_id` with id `make_bytes32(seed)`, run by
+/// `admin`
+pub fn create_branch(
+    env: &Env,
+    client: &AssetUpContractClient,
+    company_id: &BytesN<32>,
+    seed: u32,
+    admin: &Address,
+) -> BytesN<32> {
+    let branch_id = make_bytes32(env, seed);
+    client.create_branch(
+        company_id,
+        &branch_id,
+        &String::from_str(env, "Branch"),
+        &String::from_str(env, "Lagos"),
+        admin,
+    );
+    branch_id
+}
+
 #[test]
 fn test_initialize() {
     let (_env, client, admin) = setup_test_environment();
@@ -28,16 +90,17 @@ fn test_initialize() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #1)")]
-fn test_initialize_panic() {
+fn test_initialize_twice() {
     let (_env, client, admin) = setup_test_environment();
     client.initialize(&admin);
-    client.initialize(&admin);
+    assert_eq!(
+        client.try_initialize(&admin),
+        Err(Ok(Error::AlreadyInitialized))
+    );
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #2)")]
 fn test_admin_doesnt_exist() {
     let (_env, client, _) = setup_test_environment();
-    client.get_admin();
+    assert_eq!(client.try_get_admin(), Err(Ok(Error::AdminNotFound)));
 }
@@ -0,0 +1,285 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    Address, BytesN, Env, String,
+    testutils::{Address as _, Ledger},
+    token,
+};
+
+use crate::{
+    AssetUpContractClient,
+    asset::Asset,
+    currency::{Currency, MAX_SCALE},
+    error::{Error, ValidationError},
+    insurance::{ClaimStatus, PolicyStatus},
+    types::{AssetStatus, AssetType},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, make_bytes32, setup_company, setup_test_environment,
+};
+
+fn new_token(env: &Env) -> Address {
+    env.register_stellar_asset_contract_v2(Address::generate(env))
+        .address()
+}
+
+fn register_insured_asset(
+    env: &Env,
+    client: &AssetUpContractClient,
+    company_id: &BytesN<32>,
+    seed: u32,
+) -> Asset {
+    let asset = Asset {
+        id: make_bytes32(env, seed),
+        company_id: company_id.clone(),
+        name: String::from_str(env, "Generator"),
+        asset_type: AssetType::Physical,
+        category_id: DEFAULT_CATEGORY,
+        branch_id: 1,
+        department_id: 0,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost: 800_000,
+        current_value: 700_000,
+        currency: DEFAULT_CURRENCY,
+        warranty_expiry: 1_800_000_000,
+        stellar_token_id: make_bytes32(env, seed + 1),
+        owner: Address::generate(env),
+    };
+    client.register_asset(&asset);
+    asset
+}
+
+#[test]
+fn test_create_and_get_policy() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let asset = register_insured_asset(&env, &client, &company_id, 1);
+    let insurer = Address::generate(&env);
+    let payout_token = new_token(&env);
+    let policy_id = make_bytes32(&env, 50);
+    let policy_hash = make_bytes32(&env, 51);
+
+    client.create_policy(
+        &policy_id,
+        &asset.id,
+        &insurer,
+        &policy_hash,
+        &500_000,
+        &12_000,
+        &DEFAULT_CURRENCY,
+        &payout_token,
+        &1_000,
+        &100_000,
+    );
+
+    let policy = client.get_policy(&policy_id);
+    assert_eq!(policy.asset_id, asset.id);
+    assert_eq!(policy.insurer, insurer);
+    assert_eq!(policy.policy_hash, policy_hash);
+    assert_eq!(policy.coverage_amount, 500_000);
+    assert_eq!(policy.premium, 12_000);
+    assert_eq!(policy.currency, DEFAULT_CURRENCY);
+    assert_eq!(policy.payout_token, payout_token);
+    assert_eq!(policy.status, PolicyStatus::Active);
+
+    let policies = client.get_asset_policies(&asset.id);
+    assert_eq!(policies.len(), 1);
+    assert_eq!(policies.get(0).unwrap().id, policy_id);
+
+    let res = client.try_create_policy(
+        &make_bytes32(&env, 52),
+        &asset.id,
+        &insurer,
+        &policy_hash,
+        &500_000,
+        &12_000,
+        &Currency {
+            code: DEFAULT_CURRENCY.code,
+            scale: MAX_SCALE + 1,
+        },
+        &payout_token,
+        &1_000,
+        &100_000,
+    );
+    assert_eq!(res, Err(Ok(ValidationError::InvalidCurrency.into())));
+}
+
+#[test]
+fn test_claim_approve_and_settle() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    env.ledger().set_timestamp(5_000);
+    let asset = register_insured_asset(&env, &client, &company_id, 2);
+    let insurer = Address::generate(&env);
+    let payout_token = new_token(&env);
+    let policy_id = make_bytes32(&env, 60);
+    client.create_policy(
+        &policy_id,
+        &asset.id,
+        &insurer,
+        &make_bytes32(&env, 61),
+        &500_000,
+        &12_000,
+        &DEFAULT_CURRENCY,
+        &payout_token,
+        &1_000,
+        &100_000,
+    );
+
+    token::StellarAssetClient::new(&env, &payout_token).mint(&insurer, &1_000_000);
+
+    let claim_id = make_bytes32(&env, 62);
+    let evidence = make_bytes32(&env, 63);
+    client.file_claim(&claim_id, &policy_id, &200_000, &evidence);
+
+    let claim = client.get_claim(&claim_id);
+    assert_eq!(claim.status, ClaimStatus::Filed);
+    assert_eq!(claim.claimant, asset.owner);
+    assert_eq!(claim.evidence_hash, evidence);
+    assert_eq!(claim.currency, DEFAULT_CURRENCY);
+    assert_eq!(claim.filed_at, 5_000);
+
+    client.decide_claim(&claim_id, &true, &150_000);
+    let claim = client.get_claim(&claim_id);
+    assert_eq!(claim.status, ClaimStatus::Approved);
+    assert_eq!(claim.approved_amount, 150_000);
+    assert_eq!(client.get_policy(&policy_id).approved_total, 150_000);
+
+    client.settle_claim(&claim_id);
+    assert_eq!(client.get_claim(&claim_id).status, ClaimStatus::Paid);
+
+    let balances = token::Client::new(&env, &payout_token);
+    assert_eq!(balances.balance(&asset.owner), 150_000);
+    assert_eq!(balances.balance(&insurer), 850_000);
+}
+
+#[test]
+fn test_claim_history_per_asset() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    env.ledger().set_timestamp(5_000);
+    let asset = register_insured_asset(&env, &client, &company_id, 3);
+    let insurer = Address::generate(&env);
+    let payout_token = new_token(&env);
+    let policy_id = make_bytes32(&env, 70);
+    client.create_policy(
+        &policy_id,
+        &asset.id,
+        &insurer,
+        &make_bytes32(&env, 71),
+        &500_000,
+        &12_000,
+        &DEFAULT_CURRENCY,
+        &payout_token,
+        &1_000,
+        &100_000,
+    );
+
+    let first = make_bytes32(&env, 72);
+    let second = make_bytes32(&env, 73);
+    client.file_claim(&first, &policy_id, &10_000, &make_bytes32(&env, 74));
+    client.decide_claim(&first, &false, &0);
+    client.file_claim(&second, &policy_id, &20_000, &make_bytes32(&env, 75));
+
+    let claims = client.get_asset_claims(&asset.id);
+    assert_eq!(claims.len(), 2);
+    assert_eq!(claims.get(0).unwrap().status, ClaimStatus::Rejected);
+    assert_eq!(claims.get(1).unwrap().status, ClaimStatus::Filed);
+
+    let res = client.try_decide_claim(&first, &true, &10_000);
+    assert_eq!(res, Err(Ok(Error::ClaimAlreadyDecided)));
+}
+
+#[test]
+fn test_claim_outside_policy_window() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    env.ledger().set_timestamp(200_000);
+    let asset = register_insured_asset(&env, &client, &company_id, 4);
+    let insurer = Address::generate(&env);
+    let payout_token = new_token(&env);
+    let policy_id = make_bytes32(&env, 80);
+    client.create_policy(
+        &policy_id,
+        &asset.id,
+        &insurer,
+        &make_bytes32(&env, 81),
+        &500_000,
+        &12_000,
+        &DEFAULT_CURRENCY,
+        &payout_token,
+        &1_000,
+        &100_000,
+    );
+
+    let res = client.try_file_claim(
+        &make_bytes32(&env, 82),
+        &policy_id,
+        &10_000,
+        &make_bytes32(&env, 83),
+    );
+    assert_eq!(res, Err(Ok(Error::PolicyNotActive)));
+}
+
+#[test]
+fn test_claim_exceeding_coverage() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    env.ledger().set_timestamp(5_000);
+    let asset = register_insured_asset(&env, &client, &company_id, 5);
+    let insurer = Address::generate(&env);
+    let payout_token = new_token(&env);
+    let policy_id = make_bytes32(&env, 90);
+    client.create_policy(
+        &policy_id,
+        &asset.id,
+        &insurer,
+        &make_bytes32(&env, 91),
+        &100_000,
+        &2_000,
+        &DEFAULT_CURRENCY,
+        &payout_token,
+        &1_000,
+        &100_000,
+    );
+
+    let res = client.try_file_claim(
+        &make_bytes32(&env, 92),
+        &policy_id,
+        &100_001,
+        &make_bytes32(&env, 93),
+    );
+    assert_eq!(res, Err(Ok(Error::InvalidClaim)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+fn test_settle_unapproved_claim() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    env.ledger().set_timestamp(5_000);
+    let asset = register_insured_asset(&env, &client, &company_id, 6);
+    let insurer = Address::generate(&env);
+    let payout_token = new_token(&env);
+    let policy_id = make_bytes32(&env, 100);
+    client.create_policy(
+        &policy_id,
+        &asset.id,
+        &insurer,
+        &make_bytes32(&env, 101),
+        &100_000,
+        &2_000,
+        &DEFAULT_CURRENCY,
+        &payout_token,
+        &1_000,
+        &100_000,
+    );
+
+    let claim_id = make_bytes32(&env, 102);
+    client.file_claim(&claim_id, &policy_id, &5_000, &make_bytes32(&env, 103));
+    client.settle_claim(&claim_id);
+}
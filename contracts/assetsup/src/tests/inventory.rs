@@ -0,0 +1,151 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    Address, BytesN, Env, String,
+    testutils::{Address as _, Ledger},
+};
+
+use crate::{AssetUpContractClient, error::Error, inventory::MovementKind};
+
+use super::initialize::{create_branch, setup_company, setup_test_environment};
+
+fn create_toner(env: &Env, client: &AssetUpContractClient, branch_id: &BytesN<32>) -> String {
+    let sku = String::from_str(env, "TONER-26A");
+    client.create_inventory_item(branch_id, &sku, &String::from_str(env, "HP 26A toner"));
+    sku
+}
+
+#[test]
+fn test_create_inventory_item() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let branch_id = create_branch(&env, &client, &company_id, 1, &Address::generate(&env));
+    let sku = create_toner(&env, &client, &branch_id);
+
+    let item = client.get_inventory_item(&branch_id, &sku);
+    assert_eq!(item.quantity, 0);
+    assert_eq!(item.movement_count, 0);
+    assert_eq!(client.get_branch_inventory(&branch_id).get(0).unwrap(), sku);
+
+    let res = client.try_create_inventory_item(&branch_id, &sku, &String::from_str(&env, "Dup"));
+    assert_eq!(res, Err(Ok(Error::InventoryItemAlreadyExists.into())));
+}
+
+#[test]
+fn test_receive_issue_and_adjust() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let branch_id = create_branch(&env, &client, &company_id, 1, &Address::generate(&env));
+    let sku = create_toner(&env, &client, &branch_id);
+
+    env.ledger().set_timestamp(1_000);
+    client.receive_stock(&branch_id, &sku, &10, &String::from_str(&env, "PO-17"));
+    client.issue_stock(
+        &branch_id,
+        &sku,
+        &4,
+        &String::from_str(&env, "Finance printer"),
+    );
+    client.adjust_stock(&branch_id, &sku, &-1, &String::from_str(&env, "Damaged"));
+
+    assert_eq!(client.get_inventory_item(&branch_id, &sku).quantity, 5);
+
+    let history = client.get_stock_movements(&branch_id, &sku, &0, &10);
+    assert_eq!(history.len(), 3);
+    let issue = history.get(1).unwrap();
+    assert_eq!(issue.kind, MovementKind::Issue);
+    assert_eq!(issue.change, -4);
+    assert_eq!(issue.balance_after, 6);
+    assert_eq!(issue.reason, String::from_str(&env, "Finance printer"));
+    assert_eq!(issue.timestamp, 1_000);
+    assert_eq!(history.get(2).unwrap().kind, MovementKind::Adjust);
+}
+
+#[test]
+fn test_stock_never_goes_negative() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let branch_id = create_branch(&env, &client, &company_id, 1, &Address::generate(&env));
+    let sku = create_toner(&env, &client, &branch_id);
+    let reason = String::from_str(&env, "Count");
+
+    client.receive_stock(&branch_id, &sku, &3, &reason);
+
+    let res = client.try_issue_stock(&branch_id, &sku, &4, &reason);
+    assert_eq!(res, Err(Ok(Error::InsufficientStock)));
+    let res = client.try_adjust_stock(&branch_id, &sku, &-4, &reason);
+    assert_eq!(res, Err(Ok(Error::InsufficientStock)));
+    let res = client.try_receive_stock(&branch_id, &sku, &0, &reason);
+    assert_eq!(res, Err(Ok(Error::InvalidQuantity)));
+
+    let item = client.get_inventory_item(&branch_id, &sku);
+    assert_eq!(item.quantity, 3);
+    assert_eq!(item.movement_count, 1);
+}
+
+#[test]
+fn test_transfer_between_branches() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let main = create_branch(&env, &client, &company_id, 1, &Address::generate(&env));
+    let annex = create_branch(&env, &client, &company_id, 2, &Address::generate(&env));
+    let sku = create_toner(&env, &client, &main);
+    create_toner(&env, &client, &annex);
+    let reason = String::from_str(&env, "Rebalance");
+
+    client.receive_stock(&main, &sku, &8, &reason);
+    client.transfer_stock(&main, &annex, &sku, &5, &reason);
+
+    assert_eq!(client.get_inventory_item(&main, &sku).quantity, 3);
+    assert_eq!(client.get_inventory_item(&annex, &sku).quantity, 5);
+
+    let out = client
+        .get_stock_movements(&main, &sku, &1, &1)
+        .get(0)
+        .unwrap();
+    assert_eq!(out.kind, MovementKind::TransferOut);
+    assert_eq!(out.counterpart, Some(annex.clone()));
+    let incoming = client
+        .get_stock_movements(&annex, &sku, &0, &1)
+        .get(0)
+        .unwrap();
+    assert_eq!(incoming.kind, MovementKind::TransferIn);
+    assert_eq!(incoming.counterpart, Some(main.clone()));
+
+    let res = client.try_transfer_stock(&main, &annex, &sku, &4, &reason);
+    assert_eq!(res, Err(Ok(Error::InsufficientStock)));
+}
+
+#[test]
+fn test_transfer_to_other_company() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let other_company = BytesN::from_array(&env, &[0xC1; 32]);
+    client.create_company(&other_company, &String::from_str(&env, "Globex"), &admin);
+    let main = create_branch(&env, &client, &company_id, 1, &Address::generate(&env));
+    let foreign = create_branch(&env, &client, &other_company, 2, &Address::generate(&env));
+    let sku = create_toner(&env, &client, &main);
+    create_toner(&env, &client, &foreign);
+    let reason = String::from_str(&env, "Loan");
+
+    client.receive_stock(&main, &sku, &8, &reason);
+    let res = client.try_transfer_stock(&main, &foreign, &sku, &1, &reason);
+    assert_eq!(res, Err(Ok(Error::CompanyMismatch)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #43)")]
+fn test_receive_unknown_sku() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let branch_id = create_branch(&env, &client, &company_id, 1, &Address::generate(&env));
+
+    client.receive_stock(
+        &branch_id,
+        &String::from_str(&env, "CABLE-HDMI"),
+        &1,
+        &String::from_str(&env, "Delivery"),
+    );
+}
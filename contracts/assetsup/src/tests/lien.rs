@@ -0,0 +1,256 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    Address, BytesN, Env, String, Symbol,
+    testutils::{Address as _, Ledger},
+};
+
+use crate::{
+    AssetUpContractClient,
+    asset::Asset,
+    currency::Currency,
+    error::{Error, ValidationError},
+    lien::LienStatus,
+    types::{AssetStatus, AssetType},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, make_bytes32, setup_company, setup_test_environment,
+};
+
+fn register_financed_asset(
+    env: &Env,
+    client: &AssetUpContractClient,
+    company_id: &BytesN<32>,
+    seed: u32,
+) -> Asset {
+    let asset = Asset {
+        id: make_bytes32(env, seed),
+        company_id: company_id.clone(),
+        name: String::from_str(env, "Delivery Van"),
+        asset_type: AssetType::Physical,
+        category_id: DEFAULT_CATEGORY,
+        branch_id: 1,
+        department_id: 0,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost: 5_000_000,
+        current_value: 4_500_000,
+        currency: DEFAULT_CURRENCY,
+        warranty_expiry: 1_800_000_000,
+        stellar_token_id: make_bytes32(env, seed + 1),
+        owner: Address::generate(env),
+    };
+    client.register_asset(&asset);
+    asset
+}
+
+#[test]
+fn test_register_and_get_liens() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    env.ledger().set_timestamp(1_000);
+    let asset = register_financed_asset(&env, &client, &company_id, 1);
+
+    let senior = Address::generate(&env);
+    let junior = Address::generate(&env);
+    let lien_a = make_bytes32(&env, 100);
+    let lien_b = make_bytes32(&env, 101);
+
+    client.register_lien(
+        &lien_a,
+        &asset.id,
+        &senior,
+        &3_000_000,
+        &DEFAULT_CURRENCY,
+        &1,
+        &50_000,
+    );
+    client.register_lien(
+        &lien_b,
+        &asset.id,
+        &junior,
+        &1_000_000,
+        &DEFAULT_CURRENCY,
+        &2,
+        &60_000,
+    );
+
+    let liens = client.get_liens(&asset.id);
+    assert_eq!(liens.len(), 2);
+
+    let first = liens.get(0).unwrap();
+    assert_eq!(first.id, lien_a);
+    assert_eq!(first.lender, senior);
+    assert_eq!(first.amount, 3_000_000);
+    assert_eq!(first.currency, DEFAULT_CURRENCY);
+    assert_eq!(first.priority, 1);
+    assert_eq!(first.expiry, 50_000);
+    assert_eq!(first.status, LienStatus::Active);
+    assert_eq!(first.created_at, 1_000);
+
+    assert_eq!(liens.get(1).unwrap().lender, junior);
+}
+
+#[test]
+fn test_lien_blocks_transfer_until_released() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    env.ledger().set_timestamp(1_000);
+    let asset = register_financed_asset(&env, &client, &company_id, 2);
+
+    let lender = Address::generate(&env);
+    let lien_id = make_bytes32(&env, 200);
+    client.register_lien(
+        &lien_id,
+        &asset.id,
+        &lender,
+        &1_000,
+        &DEFAULT_CURRENCY,
+        &1,
+        &10_000,
+    );
+
+    let buyer = Address::generate(&env);
+    let res = client.try_transfer_asset(&asset.id, &buyer);
+    assert_eq!(res, Err(Ok(Error::AssetEncumbered)));
+
+    client.release_lien(&lien_id);
+    assert_eq!(client.get_lien(&lien_id).status, LienStatus::Released);
+
+    client.transfer_asset(&asset.id, &buyer);
+    assert_eq!(client.get_asset(&asset.id).owner, buyer);
+}
+
+#[test]
+fn test_lien_blocks_disposal() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    env.ledger().set_timestamp(1_000);
+    let asset = register_financed_asset(&env, &client, &company_id, 3);
+
+    let lender = Address::generate(&env);
+    client.register_lien(
+        &make_bytes32(&env, 300),
+        &asset.id,
+        &lender,
+        &1_000,
+        &DEFAULT_CURRENCY,
+        &1,
+        &10_000,
+    );
+
+    let res = client.try_dispose_asset(&asset.id);
+    assert_eq!(res, Err(Ok(Error::AssetEncumbered)));
+    assert_eq!(client.get_asset(&asset.id).status, AssetStatus::Active);
+}
+
+#[test]
+fn test_expired_lien_no_longer_blocks() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    env.ledger().set_timestamp(1_000);
+    let asset = register_financed_asset(&env, &client, &company_id, 4);
+
+    let lender = Address::generate(&env);
+    client.register_lien(
+        &make_bytes32(&env, 400),
+        &asset.id,
+        &lender,
+        &1_000,
+        &DEFAULT_CURRENCY,
+        &1,
+        &2_000,
+    );
+
+    env.ledger().set_timestamp(2_000);
+    client.dispose_asset(&asset.id);
+    assert_eq!(client.get_asset(&asset.id).status, AssetStatus::Disposed);
+}
+
+#[test]
+fn test_register_lien_priority_taken() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    env.ledger().set_timestamp(1_000);
+    let asset = register_financed_asset(&env, &client, &company_id, 5);
+
+    let lender = Address::generate(&env);
+    client.register_lien(
+        &make_bytes32(&env, 500),
+        &asset.id,
+        &lender,
+        &1_000,
+        &DEFAULT_CURRENCY,
+        &1,
+        &10_000,
+    );
+
+    let other = Address::generate(&env);
+    let res = client.try_register_lien(
+        &make_bytes32(&env, 501),
+        &asset.id,
+        &other,
+        &500,
+        &DEFAULT_CURRENCY,
+        &1,
+        &10_000,
+    );
+    assert_eq!(res, Err(Ok(Error::LienPriorityTaken.into())));
+}
+
+#[test]
+fn test_register_lien_invalid_terms() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    env.ledger().set_timestamp(1_000);
+    let asset = register_financed_asset(&env, &client, &company_id, 6);
+    let lender = Address::generate(&env);
+
+    let res = client.try_register_lien(
+        &make_bytes32(&env, 600),
+        &asset.id,
+        &lender,
+        &0,
+        &DEFAULT_CURRENCY,
+        &1,
+        &10_000,
+    );
+    assert_eq!(res, Err(Ok(Error::InvalidLien.into())));
+
+    let res = client.try_register_lien(
+        &make_bytes32(&env, 601),
+        &asset.id,
+        &lender,
+        &1_000,
+        &DEFAULT_CURRENCY,
+        &1,
+        &1_000,
+    );
+    assert_eq!(res, Err(Ok(Error::InvalidLien.into())));
+
+    let res = client.try_register_lien(
+        &make_bytes32(&env, 602),
+        &asset.id,
+        &lender,
+        &1_000,
+        &Currency {
+            code: Symbol::new(&env, "usd"),
+            scale: 2,
+        },
+        &1,
+        &10_000,
+    );
+    assert_eq!(res, Err(Ok(ValidationError::InvalidCurrency.into())));
+    assert_eq!(client.get_liens(&asset.id).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+fn test_release_nonexistent_lien() {
+    let (env, client, admin) = setup_test_environment();
+    setup_company(&env, &client, &admin);
+    client.release_lien(&make_bytes32(&env, 700));
+}
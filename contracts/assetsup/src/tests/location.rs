@@ -0,0 +1,129 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    Address, BytesN, Env, String,
+    testutils::{Address as _, Ledger},
+};
+
+use crate::{
+    AssetUpContractClient,
+    asset::Asset,
+    error::{Error, LocationError},
+    location::{COORD_SCALE, GeoData},
+    types::{AssetStatus, AssetType},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, make_bytes32, setup_company, setup_test_environment,
+};
+
+fn register_asset(env: &Env, client: &AssetUpContractClient, company_id: &BytesN<32>) -> Asset {
+    let asset = Asset {
+        id: make_bytes32(env, 1),
+        company_id: company_id.clone(),
+        name: String::from_str(env, "MRI scanner"),
+        asset_type: AssetType::Physical,
+        category_id: DEFAULT_CATEGORY,
+        branch_id: 1,
+        department_id: 0,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost: 2_000_000,
+        current_value: 1_800_000,
+        currency: DEFAULT_CURRENCY,
+        warranty_expiry: 1_800_000_000,
+        stellar_token_id: make_bytes32(env, 2),
+        owner: Address::generate(env),
+    };
+    client.register_asset(&asset);
+    asset
+}
+
+#[test]
+fn test_record_and_query_locations() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let asset = register_asset(&env, &client, &company_id);
+    let building = String::from_str(&env, "Block A");
+
+    assert_eq!(
+        client.try_get_current_location(&asset.id),
+        Err(Ok(LocationError::LocationNotFound.into()))
+    );
+
+    env.ledger().set_timestamp(1_000);
+    client.record_location(
+        &asset.id,
+        &building,
+        &String::from_str(&env, "Radiology 1"),
+        &GeoData::Geohash(String::from_str(&env, "s14u7")),
+        &asset.owner,
+    );
+    env.ledger().set_timestamp(2_000);
+    let lagos = GeoData::LatLong(6_524_379 * 10, 3_379_206 * 10);
+    client.record_location(
+        &asset.id,
+        &building,
+        &String::from_str(&env, "Theatre 2"),
+        &lagos,
+        &admin,
+    );
+    client.record_location(
+        &asset.id,
+        &building,
+        &String::from_str(&env, "Storage"),
+        &GeoData::None,
+        &asset.owner,
+    );
+
+    let current = client.get_current_location(&asset.id);
+    assert_eq!(current.seq, 2);
+    assert_eq!(current.room, String::from_str(&env, "Storage"));
+    assert_eq!(current.geo, GeoData::None);
+
+    let page = client.get_location_history(&asset.id, &1, &5);
+    assert_eq!(page.len(), 2);
+    let theatre = page.get(0).unwrap();
+    assert_eq!(theatre.geo, lagos);
+    assert_eq!(theatre.reporter, admin);
+    assert_eq!(theatre.reported_at, 2_000);
+    assert_eq!(client.get_location_history(&asset.id, &0, &1).len(), 1);
+    assert_eq!(client.get_location_history(&asset.id, &3, &1).len(), 0);
+}
+
+#[test]
+fn test_record_location_invalid_geo() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let asset = register_asset(&env, &client, &company_id);
+    let building = String::from_str(&env, "Block A");
+    let room = String::from_str(&env, "Lab");
+
+    for geo in [
+        GeoData::Geohash(String::from_str(&env, "s14a")),
+        GeoData::Geohash(String::from_str(&env, "s14u7s14u7s14u7")),
+        GeoData::LatLong(91 * COORD_SCALE, 0),
+        GeoData::LatLong(0, -181 * COORD_SCALE),
+    ] {
+        let res = client.try_record_location(&asset.id, &building, &room, &geo, &admin);
+        assert_eq!(res, Err(Ok(LocationError::InvalidLocation.into())));
+    }
+}
+
+#[test]
+fn test_record_location_by_stranger() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let asset = register_asset(&env, &client, &company_id);
+
+    let res = client.try_record_location(
+        &asset.id,
+        &String::from_str(&env, "Block A"),
+        &String::from_str(&env, "Lab"),
+        &GeoData::None,
+        &Address::generate(&env),
+    );
+    assert_eq!(res, Err(Ok(Error::Unauthorized.into())));
+}
@@ -1,5 +1,27 @@
 mod asset;
+mod batch;
 mod branch;
+mod category;
+mod company;
+mod cost_center;
+mod currency;
+mod department;
+mod document;
+mod events;
 mod initialize;
+mod insurance;
+mod inventory;
+mod lien;
+mod location;
+mod oracle;
+mod owner;
+mod procurement;
+mod reservation;
+mod shipment;
+mod stats;
+mod supplier;
+mod tag;
 mod tokenize;
 mod types;
+mod validation;
+mod vendor_contract;
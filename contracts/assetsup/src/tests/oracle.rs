@@ -0,0 +1,206 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    Address, Env, String, Symbol, contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger},
+};
+
+use crate::{
+    AssetUpContractClient,
+    asset::Asset,
+    currency::FX_RATE_SCALE,
+    error::{CurrencyError, OracleError, ValidationError},
+    oracle::{OracleAsset, PriceData, PriceFeed},
+    types::{AssetStatus, AssetType},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, make_bytes32, setup_company, setup_test_environment,
+};
+
+/// SEP-40 style oracle returning whatever price the test last set
+#[contract]
+pub struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set_price(env: Env, price: i128, timestamp: u64) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("price"), &PriceData { price, timestamp });
+    }
+
+    pub fn lastprice(env: Env, _asset: OracleAsset) -> Option<PriceData> {
+        env.storage().instance().get(&symbol_short!("price"))
+    }
+
+    pub fn set_decimals(env: Env, decimals: u32) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("decimals"), &decimals);
+    }
+
+    pub fn decimals(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("decimals"))
+            .unwrap_or(14)
+    }
+}
+
+const ONE: i128 = 100_000_000_000_000;
+
+/// Register a vehicle worth 20_000.00 USD whose category is priced in
+/// `price_code` by a fresh mock oracle, allowing 10% deviation and one hour
+/// of staleness
+fn setup(
+    env: &Env,
+    client: &AssetUpContractClient,
+    admin: &Address,
+    price_code: Symbol,
+) -> (Asset, MockOracleClient<'static>) {
+    let company_id = setup_company(env, client, admin);
+    let oracle = MockOracleClient::new(env, &env.register(MockOracle, ()));
+    client.set_category_price_feed(
+        &company_id,
+        &DEFAULT_CATEGORY,
+        &PriceFeed {
+            oracle: oracle.address.clone(),
+            asset: OracleAsset::Other(symbol_short!("COROLLA")),
+            price_code,
+            max_age_secs: 3_600,
+            max_deviation_bps: 1_000,
+        },
+    );
+
+    let asset = Asset {
+        id: make_bytes32(env, 1),
+        company_id,
+        name: String::from_str(env, "Toyota Corolla"),
+        asset_type: AssetType::Physical,
+        category_id: DEFAULT_CATEGORY,
+        branch_id: 1,
+        department_id: 0,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost: 2_500_000,
+        current_value: 2_000_000,
+        currency: DEFAULT_CURRENCY,
+        warranty_expiry: 1_800_000_000,
+        stellar_token_id: make_bytes32(env, 2),
+        owner: Address::generate(env),
+    };
+    client.register_asset(&asset);
+    env.ledger().set_timestamp(10_000);
+    (asset, oracle)
+}
+
+#[test]
+fn test_revalue_from_oracle() {
+    let (env, client, admin) = setup_test_environment();
+    let (asset, oracle) = setup(&env, &client, &admin, symbol_short!("USD"));
+
+    oracle.set_price(&(19_000 * ONE), &9_000);
+    assert_eq!(client.revalue_asset(&asset.id), 1_900_000);
+    assert_eq!(client.get_asset(&asset.id).current_value, 1_900_000);
+}
+
+#[test]
+fn test_revalue_converts_price_currency() {
+    let (env, client, admin) = setup_test_environment();
+    let (asset, oracle) = setup(&env, &client, &admin, symbol_short!("NGN"));
+    client.set_fx_rate(
+        &admin,
+        &symbol_short!("USD"),
+        &symbol_short!("NGN"),
+        &(1_500 * FX_RATE_SCALE),
+    );
+
+    oracle.set_price(&(31_500_000 * ONE), &10_000);
+    assert_eq!(client.revalue_asset(&asset.id), 2_100_000);
+}
+
+#[test]
+fn test_revalue_rejects_stale_price() {
+    let (env, client, admin) = setup_test_environment();
+    let (asset, oracle) = setup(&env, &client, &admin, symbol_short!("USD"));
+
+    oracle.set_price(&(19_000 * ONE), &6_399);
+    let res = client.try_revalue_asset(&asset.id);
+    assert_eq!(res, Err(Ok(OracleError::StalePrice.into())));
+    assert_eq!(client.get_asset(&asset.id).current_value, 2_000_000);
+}
+
+#[test]
+fn test_revalue_rejects_large_deviation() {
+    let (env, client, admin) = setup_test_environment();
+    let (asset, oracle) = setup(&env, &client, &admin, symbol_short!("USD"));
+
+    oracle.set_price(&(22_001 * ONE), &10_000);
+    let res = client.try_revalue_asset(&asset.id);
+    assert_eq!(res, Err(Ok(OracleError::DeviationTooLarge.into())));
+
+    oracle.set_price(&(22_000 * ONE), &10_000);
+    assert_eq!(client.revalue_asset(&asset.id), 2_200_000);
+}
+
+#[test]
+fn test_revalue_written_off_asset_against_purchase_cost() {
+    let (env, client, admin) = setup_test_environment();
+    let (mut asset, oracle) = setup(&env, &client, &admin, symbol_short!("USD"));
+    asset.id = make_bytes32(&env, 5);
+    asset.current_value = 0;
+    client.register_asset(&asset);
+
+    // Within 10% of the 25_000.00 purchase cost
+    oracle.set_price(&(22_499 * ONE), &10_000);
+    let res = client.try_revalue_asset(&asset.id);
+    assert_eq!(res, Err(Ok(OracleError::DeviationTooLarge.into())));
+    oracle.set_price(&(22_500 * ONE), &10_000);
+    assert_eq!(client.revalue_asset(&asset.id), 2_250_000);
+
+    // With no cost either, the first price is taken as is
+    asset.id = make_bytes32(&env, 7);
+    asset.purchase_cost = 0;
+    client.register_asset(&asset);
+    oracle.set_price(&(100 * ONE), &10_000);
+    assert_eq!(client.revalue_asset(&asset.id), 10_000);
+}
+
+#[test]
+fn test_revalue_reports_overflow() {
+    let (env, client, admin) = setup_test_environment();
+    let (asset, oracle) = setup(&env, &client, &admin, symbol_short!("USD"));
+    oracle.set_decimals(&0);
+    oracle.set_price(&(i128::MAX / 1_000), &10_000);
+
+    let res = client.try_revalue_asset(&asset.id);
+    assert_eq!(res, Err(Ok(CurrencyError::AmountOverflow.into())));
+    assert_eq!(client.get_asset(&asset.id).current_value, 2_000_000);
+}
+
+#[test]
+fn test_revalue_without_feed_or_price() {
+    let (env, client, admin) = setup_test_environment();
+    let (asset, _oracle) = setup(&env, &client, &admin, symbol_short!("USD"));
+
+    let res = client.try_revalue_asset(&asset.id);
+    assert_eq!(res, Err(Ok(OracleError::PriceNotFound.into())));
+
+    let res = client.try_get_category_price_feed(&asset.company_id, &99);
+    assert_eq!(res, Err(Ok(OracleError::PriceFeedNotFound.into())));
+}
+
+#[test]
+fn test_revalue_rejects_oracle_scale() {
+    let (env, client, admin) = setup_test_environment();
+    let (asset, oracle) = setup(&env, &client, &admin, symbol_short!("USD"));
+    oracle.set_price(&(20_000 * ONE), &10_000);
+    oracle.set_decimals(&60);
+
+    let res = client.try_revalue_asset(&asset.id);
+    assert_eq!(res, Err(Ok(ValidationError::InvalidCurrency.into())));
+    assert_eq!(client.get_asset(&asset.id).current_value, 2_000_000);
+}
@@ -0,0 +1,138 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{Address, BytesN, Env, String, testutils::Address as _, vec};
+
+use crate::{
+    asset::{Asset, MAX_BATCH_SIZE},
+    index::PAGE_SIZE,
+    types::{AssetStatus, AssetType},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, make_bytes32, setup_company, setup_test_environment,
+};
+
+fn make_asset(env: &Env, seed: u32, company_id: &BytesN<32>, owner: &Address) -> Asset {
+    Asset {
+        id: make_bytes32(env, seed),
+        company_id: company_id.clone(),
+        name: String::from_str(env, "Laptop"),
+        asset_type: AssetType::Physical,
+        category_id: DEFAULT_CATEGORY,
+        branch_id: 1,
+        department_id: 0,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost: 1_000,
+        current_value: 800,
+        currency: DEFAULT_CURRENCY,
+        warranty_expiry: 1_800_000_000,
+        stellar_token_id: BytesN::from_array(env, &[0u8; 32]),
+        owner: owner.clone(),
+    }
+}
+
+#[test]
+fn test_owner_index_follows_registration_transfer_and_disposal() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let laptop = make_asset(&env, 1, &company_id, &alice);
+    let phone = make_asset(&env, 3, &company_id, &alice);
+    client.register_asset(&laptop);
+    client.register_asset(&phone);
+    assert_eq!(client.count_assets_by_owner(&alice), 2);
+    assert_eq!(
+        client.get_assets_by_owner(&alice, &0, &10),
+        vec![&env, laptop.id.clone(), phone.id.clone()]
+    );
+
+    client.transfer_asset(&laptop.id, &bob);
+    assert_eq!(
+        client.get_assets_by_owner(&alice, &0, &10),
+        vec![&env, phone.id.clone()]
+    );
+    assert_eq!(
+        client.get_assets_by_owner(&bob, &0, &10),
+        vec![&env, laptop.id.clone()]
+    );
+
+    client.dispose_asset(&laptop.id);
+    assert_eq!(client.count_assets_by_owner(&bob), 0);
+    assert_eq!(client.count_assets_by_owner(&alice), 1);
+}
+
+#[test]
+fn test_get_assets_by_owner_pagination() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let owner = Address::generate(&env);
+
+    let mut ids = vec![&env];
+    for seed in 10..15 {
+        let asset = make_asset(&env, seed, &company_id, &owner);
+        client.register_asset(&asset);
+        ids.push_back(asset.id);
+    }
+
+    assert_eq!(client.get_assets_by_owner(&owner, &0, &2), ids.slice(0..2));
+    assert_eq!(client.get_assets_by_owner(&owner, &2, &2), ids.slice(2..4));
+    assert_eq!(client.get_assets_by_owner(&owner, &4, &2), ids.slice(4..5));
+    assert_eq!(client.get_assets_by_owner(&owner, &9, &2).len(), 0);
+    assert_eq!(
+        client
+            .get_assets_by_owner(&owner, &u32::MAX, &u32::MAX)
+            .len(),
+        0
+    );
+    assert_eq!(client.count_assets_by_owner(&Address::generate(&env)), 0);
+}
+
+#[test]
+fn test_owner_index_spans_pages() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let owner = Address::generate(&env);
+
+    let count = PAGE_SIZE + 3;
+    let mut ids = std::vec::Vec::new();
+    let mut batch = vec![&env];
+    for seed in 0..count {
+        let asset = make_asset(&env, seed * 2 + 1, &company_id, &owner);
+        ids.push(asset.id.clone());
+        batch.push_back(asset);
+        if batch.len() == MAX_BATCH_SIZE || seed == count - 1 {
+            client.register_assets(&batch, &None);
+            batch = vec![&env];
+        }
+    }
+    assert_eq!(client.count_assets_by_owner(&owner), count);
+    let page = client.get_assets_by_owner(&owner, &(PAGE_SIZE - 1), &3);
+    assert_eq!(
+        page,
+        vec![
+            &env,
+            ids[PAGE_SIZE as usize - 1].clone(),
+            ids[PAGE_SIZE as usize].clone(),
+            ids[PAGE_SIZE as usize + 1].clone()
+        ]
+    );
+
+    // The last asset takes the place of one that leaves
+    client.transfer_asset(&ids[1], &Address::generate(&env));
+    assert_eq!(client.count_assets_by_owner(&owner), count - 1);
+    assert_eq!(
+        client.get_assets_by_owner(&owner, &1, &1),
+        vec![&env, ids[count as usize - 1].clone()]
+    );
+    client.transfer_asset(&ids[count as usize - 1], &Address::generate(&env));
+    assert_eq!(
+        client.get_assets_by_owner(&owner, &1, &1),
+        vec![&env, ids[count as usize - 2].clone()]
+    );
+    assert_eq!(client.count_assets_by_owner(&owner), count - 2);
+}
@@ -0,0 +1,520 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    Address, BytesN, Env, IntoVal, String, Vec, symbol_short,
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+    token,
+};
+
+use crate::{
+    AssetUpContractClient,
+    asset::{MAX_BATCH_SIZE, MAX_WRITE_ENTRIES},
+    currency::{Currency, Money},
+    error::{Error, ProcurementError, SupplierError},
+    procurement::PurchaseOrderStatus,
+    types::AssetStatus,
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, create_branch, make_bytes32, setup_company,
+    setup_test_environment,
+};
+
+fn usd(amount: i128) -> Money {
+    Money {
+        amount,
+        currency: DEFAULT_CURRENCY,
+    }
+}
+
+struct Setup {
+    company_id: BytesN<32>,
+    branch_id: BytesN<32>,
+    manager: Address,
+    requester: Address,
+    supplier: Address,
+    token: Address,
+}
+
+/// Branch with a funded manager, a 10_000 procurement budget and a
+/// verified supplier
+fn setup_branch(env: &Env, client: &AssetUpContractClient, admin: &Address) -> Setup {
+    let company_id = setup_company(env, client, admin);
+    let manager = Address::generate(env);
+    let branch_id = create_branch(env, client, &company_id, 200, &manager);
+    client.set_branch_budget(&branch_id, &usd(10_000));
+
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+    token::StellarAssetClient::new(env, &sac.address()).mint(&manager, &50_000);
+
+    let supplier = Address::generate(env);
+    client.register_supplier(
+        &company_id,
+        &supplier,
+        &String::from_str(env, "Dell"),
+        &make_bytes32(env, 100),
+    );
+    client.set_supplier_verified(&company_id, &supplier, &true);
+
+    Setup {
+        company_id,
+        branch_id,
+        manager,
+        requester: Address::generate(env),
+        supplier,
+        token: sac.address(),
+    }
+}
+
+fn create_order(
+    env: &Env,
+    client: &AssetUpContractClient,
+    s: &Setup,
+    seed: u32,
+    quantity: u32,
+    unit_cost: i128,
+) -> BytesN<32> {
+    let po_id = make_bytes32(env, seed);
+    client.create_purchase_order(
+        &po_id,
+        &s.branch_id,
+        &s.requester,
+        &s.supplier,
+        &String::from_str(env, "Laptop"),
+        &DEFAULT_CATEGORY,
+        &quantity,
+        &unit_cost,
+        &DEFAULT_CURRENCY,
+        &s.token,
+    );
+    po_id
+}
+
+#[test]
+fn test_purchase_order_lifecycle() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_branch(&env, &client, &admin);
+    let po_id = create_order(&env, &client, &s, 1, 2, 1_500);
+
+    let po = client.get_purchase_order(&po_id);
+    assert_eq!(po.status, PurchaseOrderStatus::Requested);
+    assert_eq!(po.total, 3_000);
+    assert_eq!(client.get_branch_purchase_orders(&s.branch_id).len(), 1);
+
+    env.ledger().set_timestamp(1_000);
+    client.decide_purchase_order(&po_id, &true);
+
+    let balances = token::Client::new(&env, &s.token);
+    assert_eq!(balances.balance(&client.address), 3_000);
+    assert_eq!(balances.balance(&s.manager), 47_000);
+    assert_eq!(client.get_branch_budget(&s.branch_id), usd(7_000));
+
+    env.ledger().set_timestamp(2_000);
+    let mut asset_ids = Vec::new(&env);
+    asset_ids.push_back(make_bytes32(&env, 50));
+    asset_ids.push_back(make_bytes32(&env, 51));
+    client.receive_purchase_order(&po_id, &asset_ids);
+
+    assert_eq!(balances.balance(&client.address), 0);
+    assert_eq!(balances.balance(&s.supplier), 3_000);
+
+    let po = client.get_purchase_order(&po_id);
+    assert_eq!(po.status, PurchaseOrderStatus::Received);
+    assert_eq!(po.received_at, 2_000);
+
+    let laptop = client.get_asset(&make_bytes32(&env, 51));
+    assert_eq!(laptop.name, String::from_str(&env, "Laptop"));
+    assert_eq!(laptop.owner, s.requester);
+    assert_eq!(laptop.purchase_cost, 1_500);
+    assert_eq!(laptop.currency, DEFAULT_CURRENCY);
+    assert_eq!(laptop.purchase_date, 1_000);
+    assert_eq!(laptop.status, AssetStatus::Active);
+    assert_eq!(
+        client.get_branch_assets(&s.branch_id, &0, &u32::MAX),
+        asset_ids
+    );
+    assert_eq!(client.get_asset_supplier(&laptop.id), s.supplier);
+    assert_eq!(
+        client.get_supplier_assets(&s.company_id, &s.supplier, &0, &u32::MAX),
+        asset_ids
+    );
+}
+
+#[test]
+fn test_approval_over_budget() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_branch(&env, &client, &admin);
+    let po_id = create_order(&env, &client, &s, 1, 3, 3_334);
+
+    let res = client.try_decide_purchase_order(&po_id, &true);
+    assert_eq!(res, Err(Ok(ProcurementError::OverBudget.into())));
+    assert_eq!(client.get_branch_budget(&s.branch_id), usd(10_000));
+    assert_eq!(
+        client.get_purchase_order(&po_id).status,
+        PurchaseOrderStatus::Requested
+    );
+}
+
+#[test]
+fn test_order_currency_must_match_budget() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_branch(&env, &client, &admin);
+    let ngn = Currency {
+        code: symbol_short!("NGN"),
+        scale: 2,
+    };
+
+    let order = |po_id: &BytesN<32>, currency: &Currency| {
+        client.try_create_purchase_order(
+            po_id,
+            &s.branch_id,
+            &s.requester,
+            &s.supplier,
+            &String::from_str(&env, "Laptop"),
+            &DEFAULT_CATEGORY,
+            &1,
+            &500,
+            currency,
+            &s.token,
+        )
+    };
+    let res = order(&make_bytes32(&env, 1), &ngn);
+    assert_eq!(res, Err(Ok(ProcurementError::CurrencyMismatch.into())));
+
+    // An order placed before the budget moved to another currency cannot
+    // be approved against it
+    let po_id = make_bytes32(&env, 2);
+    order(&po_id, &DEFAULT_CURRENCY).unwrap().unwrap();
+    client.set_branch_budget(
+        &s.branch_id,
+        &Money {
+            amount: 10_000,
+            currency: ngn.clone(),
+        },
+    );
+    let res = client.try_decide_purchase_order(&po_id, &true);
+    assert_eq!(res, Err(Ok(ProcurementError::CurrencyMismatch.into())));
+    assert_eq!(client.get_branch_budget(&s.branch_id).currency, ngn);
+}
+
+#[test]
+fn test_branch_without_budget_cannot_approve() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_branch(&env, &client, &admin);
+    let other = create_branch(&env, &client, &s.company_id, 210, &s.manager);
+    assert_eq!(
+        client.try_get_branch_budget(&other),
+        Err(Ok(ProcurementError::BudgetNotSet.into()))
+    );
+
+    let po_id = make_bytes32(&env, 1);
+    client.create_purchase_order(
+        &po_id,
+        &other,
+        &s.requester,
+        &s.supplier,
+        &String::from_str(&env, "Laptop"),
+        &DEFAULT_CATEGORY,
+        &1,
+        &500,
+        &DEFAULT_CURRENCY,
+        &s.token,
+    );
+    let res = client.try_decide_purchase_order(&po_id, &true);
+    assert_eq!(res, Err(Ok(ProcurementError::BudgetNotSet.into())));
+}
+
+#[test]
+fn test_rejected_order_cannot_be_received() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_branch(&env, &client, &admin);
+    let po_id = create_order(&env, &client, &s, 1, 1, 500);
+
+    client.decide_purchase_order(&po_id, &false);
+    assert_eq!(
+        client.get_purchase_order(&po_id).status,
+        PurchaseOrderStatus::Rejected
+    );
+
+    let res = client.try_decide_purchase_order(&po_id, &true);
+    assert_eq!(res, Err(Ok(ProcurementError::OrderNotRequested.into())));
+
+    let mut asset_ids = Vec::new(&env);
+    asset_ids.push_back(make_bytes32(&env, 50));
+    let res = client.try_receive_purchase_order(&po_id, &asset_ids);
+    assert_eq!(res, Err(Ok(ProcurementError::OrderNotApproved.into())));
+}
+
+#[test]
+fn test_receipt_within_outstanding_quantity() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_branch(&env, &client, &admin);
+    let po_id = create_order(&env, &client, &s, 1, 2, 500);
+    client.decide_purchase_order(&po_id, &true);
+
+    let mut asset_ids = Vec::new(&env);
+    let res = client.try_receive_purchase_order(&po_id, &asset_ids);
+    assert_eq!(res, Err(Ok(ProcurementError::InvalidReceipt.into())));
+
+    for seed in [50, 51, 52] {
+        asset_ids.push_back(make_bytes32(&env, seed));
+    }
+    let res = client.try_receive_purchase_order(&po_id, &asset_ids);
+    assert_eq!(res, Err(Ok(ProcurementError::InvalidReceipt.into())));
+
+    let mut asset_ids = Vec::new(&env);
+    asset_ids.push_back(make_bytes32(&env, 50));
+    asset_ids.push_back(make_bytes32(&env, 50));
+    let res = client.try_receive_purchase_order(&po_id, &asset_ids);
+    assert_eq!(res, Err(Ok(Error::AssetAlreadyExists.into())));
+    assert_eq!(
+        token::Client::new(&env, &s.token).balance(&client.address),
+        1_000
+    );
+}
+
+#[test]
+fn test_partial_receipts_release_escrow_pro_rata() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_branch(&env, &client, &admin);
+    let balances = token::Client::new(&env, &s.token);
+    let receive = |po_id: &BytesN<32>, seeds: &[u32]| {
+        let mut asset_ids = Vec::new(&env);
+        for seed in seeds {
+            asset_ids.push_back(make_bytes32(&env, *seed));
+        }
+        client.receive_purchase_order(po_id, &asset_ids);
+    };
+
+    let po_id = create_order(&env, &client, &s, 1, 3, 1_000);
+    client.decide_purchase_order(&po_id, &true);
+
+    env.ledger().set_timestamp(1_000);
+    receive(&po_id, &[50]);
+    let po = client.get_purchase_order(&po_id);
+    assert_eq!(po.received, 1);
+    assert_eq!(po.status, PurchaseOrderStatus::Approved);
+    assert_eq!(po.received_at, 0);
+    assert_eq!(balances.balance(&s.supplier), 1_000);
+    assert_eq!(balances.balance(&client.address), 2_000);
+
+    env.ledger().set_timestamp(2_000);
+    receive(&po_id, &[51, 52]);
+    let po = client.get_purchase_order(&po_id);
+    assert_eq!(po.received, 3);
+    assert_eq!(po.status, PurchaseOrderStatus::Received);
+    assert_eq!(po.received_at, 2_000);
+    assert_eq!(balances.balance(&s.supplier), 3_000);
+    assert_eq!(balances.balance(&client.address), 0);
+
+    // Cancelling after a partial receipt refunds only what is left
+    let po_id = create_order(&env, &client, &s, 2, 3, 1_000);
+    client.decide_purchase_order(&po_id, &true);
+    receive(&po_id, &[60]);
+    client.cancel_purchase_order(&po_id);
+
+    assert_eq!(balances.balance(&s.supplier), 4_000);
+    assert_eq!(balances.balance(&client.address), 0);
+    assert_eq!(balances.balance(&s.manager), 46_000);
+    assert_eq!(client.get_branch_budget(&s.branch_id), usd(6_000));
+    assert_eq!(
+        client.get_purchase_order(&po_id).status,
+        PurchaseOrderStatus::Cancelled
+    );
+    assert!(client.try_get_asset(&make_bytes32(&env, 60)).is_ok());
+    assert_eq!(
+        client.get_branch_assets(&s.branch_id, &0, &u32::MAX).len(),
+        4
+    );
+}
+
+#[test]
+fn test_create_purchase_order_invalid() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_branch(&env, &client, &admin);
+    let name = String::from_str(&env, "Laptop");
+
+    let res = client.try_create_purchase_order(
+        &make_bytes32(&env, 1),
+        &s.branch_id,
+        &s.requester,
+        &s.supplier,
+        &name,
+        &DEFAULT_CATEGORY,
+        &0,
+        &500,
+        &DEFAULT_CURRENCY,
+        &s.token,
+    );
+    assert_eq!(res, Err(Ok(ProcurementError::InvalidOrder.into())));
+
+    let res = client.try_create_purchase_order(
+        &make_bytes32(&env, 1),
+        &s.branch_id,
+        &s.requester,
+        &s.supplier,
+        &name,
+        &99,
+        &1,
+        &500,
+        &DEFAULT_CURRENCY,
+        &s.token,
+    );
+    assert_eq!(res, Err(Ok(Error::CategoryNotFound.into())));
+
+    let po_id = create_order(&env, &client, &s, 1, 1, 500);
+    let res = client.try_create_purchase_order(
+        &po_id,
+        &s.branch_id,
+        &s.requester,
+        &s.supplier,
+        &name,
+        &DEFAULT_CATEGORY,
+        &1,
+        &500,
+        &DEFAULT_CURRENCY,
+        &s.token,
+    );
+    assert_eq!(res, Err(Ok(ProcurementError::OrderAlreadyExists.into())));
+}
+
+#[test]
+fn test_unverified_supplier_is_not_payable() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_branch(&env, &client, &admin);
+    let po_id = create_order(&env, &client, &s, 1, 1, 500);
+
+    client.set_supplier_verified(&s.company_id, &s.supplier, &false);
+    let res = client.try_decide_purchase_order(&po_id, &true);
+    assert_eq!(res, Err(Ok(SupplierError::SupplierNotVerified.into())));
+
+    client.set_supplier_verified(&s.company_id, &s.supplier, &true);
+    client.decide_purchase_order(&po_id, &true);
+    client.set_supplier_verified(&s.company_id, &s.supplier, &false);
+
+    let mut asset_ids = Vec::new(&env);
+    asset_ids.push_back(make_bytes32(&env, 50));
+    let res = client.try_receive_purchase_order(&po_id, &asset_ids);
+    assert_eq!(res, Err(Ok(SupplierError::SupplierNotVerified.into())));
+
+    // Cancelling releases the escrow back to the branch
+    let balances = token::Client::new(&env, &s.token);
+    assert_eq!(balances.balance(&client.address), 500);
+    assert_eq!(client.get_branch_budget(&s.branch_id), usd(9_500));
+    client.cancel_purchase_order(&po_id);
+
+    assert_eq!(balances.balance(&client.address), 0);
+    assert_eq!(balances.balance(&s.manager), 50_000);
+    assert_eq!(client.get_branch_budget(&s.branch_id), usd(10_000));
+    assert_eq!(
+        client.get_purchase_order(&po_id).status,
+        PurchaseOrderStatus::Cancelled
+    );
+    let res = client.try_receive_purchase_order(&po_id, &asset_ids);
+    assert_eq!(res, Err(Ok(ProcurementError::OrderNotApproved.into())));
+    let res = client.try_cancel_purchase_order(&po_id);
+    assert_eq!(res, Err(Ok(ProcurementError::OrderNotApproved.into())));
+}
+
+#[test]
+fn test_only_approved_orders_can_be_cancelled() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_branch(&env, &client, &admin);
+    let po_id = create_order(&env, &client, &s, 1, 1, 500);
+
+    let res = client.try_cancel_purchase_order(&po_id);
+    assert_eq!(res, Err(Ok(ProcurementError::OrderNotApproved.into())));
+
+    client.decide_purchase_order(&po_id, &true);
+    let res = client
+        .mock_auths(&[MockAuth {
+            address: &s.requester,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "cancel_purchase_order",
+                args: (po_id.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_cancel_purchase_order(&po_id);
+    assert!(res.is_err());
+    assert_eq!(
+        client.get_purchase_order(&po_id).status,
+        PurchaseOrderStatus::Approved
+    );
+}
+
+#[test]
+fn test_unregistered_supplier() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_branch(&env, &client, &admin);
+
+    let res = client.try_create_purchase_order(
+        &make_bytes32(&env, 1),
+        &s.branch_id,
+        &s.requester,
+        &Address::generate(&env),
+        &String::from_str(&env, "Laptop"),
+        &DEFAULT_CATEGORY,
+        &1,
+        &500,
+        &DEFAULT_CURRENCY,
+        &s.token,
+    );
+    assert_eq!(res, Err(Ok(SupplierError::SupplierNotFound.into())));
+}
+
+#[test]
+fn test_rate_received_order() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_branch(&env, &client, &admin);
+
+    for (seed, delivery, quality) in [(1, 5, 4), (2, 3, 2)] {
+        let po_id = create_order(&env, &client, &s, seed, 1, 500);
+        let res = client.try_rate_purchase_order(&po_id, &5, &5);
+        assert_eq!(res, Err(Ok(ProcurementError::OrderNotReceived.into())));
+
+        client.decide_purchase_order(&po_id, &true);
+        let mut asset_ids = Vec::new(&env);
+        asset_ids.push_back(make_bytes32(&env, 50 + seed));
+        client.receive_purchase_order(&po_id, &asset_ids);
+
+        let res = client.try_rate_purchase_order(&po_id, &6, &1);
+        assert_eq!(res, Err(Ok(ProcurementError::InvalidRating.into())));
+        client.rate_purchase_order(&po_id, &delivery, &quality);
+        let res = client.try_rate_purchase_order(&po_id, &1, &1);
+        assert_eq!(res, Err(Ok(ProcurementError::OrderAlreadyRated.into())));
+    }
+
+    let supplier = client.get_supplier(&s.company_id, &s.supplier);
+    assert_eq!(supplier.rating_count, 2);
+    assert_eq!(supplier.delivery_rating_total, 8);
+    assert_eq!(supplier.quality_rating_total, 6);
+}
+
+#[test]
+fn test_large_order_received_in_batches() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_branch(&env, &client, &admin);
+    let po_id = create_order(&env, &client, &s, 1, MAX_BATCH_SIZE + 1, 100);
+    client.decide_purchase_order(&po_id, &true);
+
+    let mut asset_ids = Vec::new(&env);
+    for i in 0..=MAX_BATCH_SIZE {
+        asset_ids.push_back(make_bytes32(&env, 50 + i));
+    }
+    let res = client.try_receive_purchase_order(&po_id, &asset_ids);
+    assert_eq!(res, Err(Ok(Error::BatchTooLarge.into())));
+
+    let last = asset_ids.pop_back_unchecked();
+    client.receive_purchase_order(&po_id, &asset_ids);
+    let resources = env.cost_estimate().resources();
+    assert!(resources.write_entries <= MAX_WRITE_ENTRIES);
+
+    client.receive_purchase_order(&po_id, &Vec::from_array(&env, [last]));
+    let po = client.get_purchase_order(&po_id);
+    assert_eq!(po.received, MAX_BATCH_SIZE + 1);
+    assert_eq!(po.status, PurchaseOrderStatus::Received);
+}
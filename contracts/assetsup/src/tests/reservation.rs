@@ -0,0 +1,436 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    Address, BytesN, Env, IntoVal, String, Vec,
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+};
+
+use crate::{
+    AssetUpContractClient,
+    asset::Asset,
+    error::{Error, ReservationError},
+    reservation::{ReservationStatus, Window},
+    types::{AssetStatus, AssetType},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, create_branch, make_bytes32, setup_company,
+    setup_test_environment,
+};
+
+struct Setup {
+    branch: BytesN<32>,
+    branch_admin: Address,
+    asset: BytesN<32>,
+}
+
+/// A branch with one pool car linked to it
+fn setup_pool_car(env: &Env, client: &AssetUpContractClient, admin: &Address) -> Setup {
+    let company_id = setup_company(env, client, admin);
+    let branch_admin = Address::generate(env);
+    let branch = create_branch(env, client, &company_id, 200, &branch_admin);
+
+    let asset = Asset {
+        id: make_bytes32(env, 1),
+        company_id,
+        name: String::from_str(env, "Pool car"),
+        asset_type: AssetType::Physical,
+        category_id: DEFAULT_CATEGORY,
+        branch_id: 1,
+        department_id: 0,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost: 9_000,
+        current_value: 8_000,
+        currency: DEFAULT_CURRENCY,
+        warranty_expiry: 1_800_000_000,
+        stellar_token_id: make_bytes32(env, 2),
+        owner: Address::generate(env),
+    };
+    client.register_asset(&asset);
+    client.add_asset_to_branch(&branch, &asset.id);
+
+    Setup {
+        branch,
+        branch_admin,
+        asset: asset.id,
+    }
+}
+
+fn window(from: u64, to: u64) -> Window {
+    Window { from, to }
+}
+
+#[test]
+fn test_reserve_rejects_overlaps() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_pool_car(&env, &client, &admin);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    env.ledger().set_timestamp(50);
+
+    let first = client.reserve(&s.asset, &100, &200, &alice);
+    let r = client.get_reservation(&first);
+    assert_eq!(r.status, ReservationStatus::Approved);
+    assert_eq!(r.window, window(100, 200));
+
+    for (from, to) in [(150, 250), (50, 101), (100, 200), (120, 130)] {
+        let res = client.try_reserve(&s.asset, &from, &to, &bob);
+        assert_eq!(res, Err(Ok(ReservationError::WindowTaken.into())));
+    }
+
+    // Windows are half-open, so back-to-back bookings do not clash
+    let second = client.reserve(&s.asset, &200, &300, &bob);
+    let third = client.reserve(&s.asset, &0, &100, &bob);
+    assert_ne!(first, second);
+
+    let ids: std::vec::Vec<u64> = client
+        .get_reservations(&s.asset, &window(150, 250))
+        .iter()
+        .map(|r| r.id)
+        .collect();
+    assert_eq!(ids, [first, second]);
+    assert_eq!(
+        client.get_reservations(&s.asset, &window(0, 1_000)).len(),
+        3
+    );
+    assert_eq!(client.get_reservation(&third).window, window(0, 100));
+}
+
+#[test]
+fn test_reserve_rejects_invalid_windows() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_pool_car(&env, &client, &admin);
+    let alice = Address::generate(&env);
+    env.ledger().set_timestamp(500);
+
+    for (from, to) in [(600, 600), (700, 600), (100, 500)] {
+        let res = client.try_reserve(&s.asset, &from, &to, &alice);
+        assert_eq!(res, Err(Ok(ReservationError::InvalidWindow.into())));
+    }
+    let res = client.try_reserve(&make_bytes32(&env, 9), &600, &700, &alice);
+    assert_eq!(res, Err(Ok(Error::AssetNotFound.into())));
+
+    client.dispose_asset(&s.asset);
+    let res = client.try_reserve(&s.asset, &600, &700, &alice);
+    assert_eq!(res, Err(Ok(Error::AssetDisposed.into())));
+}
+
+#[test]
+fn test_cancel_frees_window() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_pool_car(&env, &client, &admin);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let id = client.reserve(&s.asset, &100, &200, &alice);
+    client.cancel_reservation(&id);
+    assert_eq!(
+        client.get_reservation(&id).status,
+        ReservationStatus::Cancelled
+    );
+    assert!(
+        client
+            .get_reservations(&s.asset, &window(0, 1_000))
+            .is_empty()
+    );
+
+    let res = client.try_cancel_reservation(&id);
+    assert_eq!(res, Err(Ok(ReservationError::ReservationNotActive.into())));
+    client.reserve(&s.asset, &100, &200, &bob);
+}
+
+#[test]
+fn test_only_requester_can_cancel() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_pool_car(&env, &client, &admin);
+    let alice = Address::generate(&env);
+    let id = client.reserve(&s.asset, &100, &200, &alice);
+
+    let res = client
+        .mock_auths(&[MockAuth {
+            address: &s.branch_admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "cancel_reservation",
+                args: (id,).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_cancel_reservation(&id);
+    assert!(res.is_err());
+    assert_eq!(
+        client.get_reservation(&id).status,
+        ReservationStatus::Approved
+    );
+}
+
+#[test]
+fn test_branch_approval_mode() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_pool_car(&env, &client, &admin);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    assert!(!client.get_reservation_approval(&s.branch));
+    client.set_reservation_approval(&s.branch, &true);
+    assert!(client.get_reservation_approval(&s.branch));
+
+    let first = client.reserve(&s.asset, &100, &200, &alice);
+    let r = client.get_reservation(&first);
+    assert_eq!(r.status, ReservationStatus::Pending);
+    assert_eq!(r.approval_branch, Some(s.branch.clone()));
+
+    // Pending bookings already hold their window
+    let res = client.try_reserve(&s.asset, &150, &250, &bob);
+    assert_eq!(res, Err(Ok(ReservationError::WindowTaken.into())));
+
+    env.ledger().set_timestamp(120);
+    let res = client.try_check_out(&s.asset, &alice);
+    assert_eq!(
+        res,
+        Err(Ok(ReservationError::ReservationNotApproved.into()))
+    );
+
+    client.decide_reservation(&first, &true);
+    assert_eq!(
+        client.get_reservation(&first).status,
+        ReservationStatus::Approved
+    );
+    let res = client.try_decide_reservation(&first, &false);
+    assert_eq!(res, Err(Ok(ReservationError::ReservationNotPending.into())));
+
+    let second = client.reserve(&s.asset, &200, &300, &bob);
+    client.decide_reservation(&second, &false);
+    assert_eq!(
+        client.get_reservation(&second).status,
+        ReservationStatus::Rejected
+    );
+    assert_eq!(
+        client.get_reservations(&s.asset, &window(0, 1_000)).len(),
+        1
+    );
+    client.reserve(&s.asset, &200, &300, &bob);
+
+    // Bookings approved at once leave nothing to decide
+    client.set_reservation_approval(&s.branch, &false);
+    let third = client.reserve(&s.asset, &300, &400, &bob);
+    let res = client.try_decide_reservation(&third, &true);
+    assert_eq!(res, Err(Ok(ReservationError::ReservationNotPending.into())));
+}
+
+#[test]
+fn test_only_branch_admin_decides() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_pool_car(&env, &client, &admin);
+    let alice = Address::generate(&env);
+    client.set_reservation_approval(&s.branch, &true);
+    let id = client.reserve(&s.asset, &100, &200, &alice);
+
+    let res = client
+        .mock_auths(&[MockAuth {
+            address: &alice,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "decide_reservation",
+                args: (id, true).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_decide_reservation(&id, &true);
+    assert!(res.is_err());
+    assert_eq!(
+        client.get_reservation(&id).status,
+        ReservationStatus::Pending
+    );
+}
+
+#[test]
+fn test_check_out_requires_current_holder() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_pool_car(&env, &client, &admin);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let first = client.reserve(&s.asset, &100, &200, &alice);
+    let second = client.reserve(&s.asset, &200, &300, &bob);
+
+    env.ledger().set_timestamp(50);
+    let res = client.try_check_out(&s.asset, &alice);
+    assert_eq!(res, Err(Ok(ReservationError::NoCurrentReservation.into())));
+
+    env.ledger().set_timestamp(150);
+    let res = client.try_check_out(&s.asset, &bob);
+    assert_eq!(res, Err(Ok(Error::Unauthorized.into())));
+
+    assert_eq!(client.check_out(&s.asset, &alice), first);
+    assert_eq!(
+        client.get_reservation(&first).status,
+        ReservationStatus::CheckedOut
+    );
+    let res = client.try_check_out(&s.asset, &alice);
+    assert_eq!(res, Err(Ok(Error::AssetCheckedOut.into())));
+    let res = client.try_cancel_reservation(&first);
+    assert_eq!(res, Err(Ok(ReservationError::ReservationNotActive.into())));
+    let res = client.try_dispose_asset(&s.asset);
+    assert_eq!(res, Err(Ok(Error::AssetCheckedOut)));
+
+    // An overdue return blocks the next holder
+    env.ledger().set_timestamp(210);
+    let res = client.try_check_out(&s.asset, &bob);
+    assert_eq!(res, Err(Ok(Error::AssetCheckedOut.into())));
+
+    client.check_in(&s.asset);
+    assert_eq!(
+        client.get_reservation(&first).status,
+        ReservationStatus::Returned
+    );
+    assert_eq!(client.check_out(&s.asset, &bob), second);
+
+    let res = client.try_check_in(&make_bytes32(&env, 9));
+    assert_eq!(res, Err(Ok(ReservationError::AssetNotCheckedOut.into())));
+}
+
+#[test]
+fn test_only_holder_can_check_in() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_pool_car(&env, &client, &admin);
+    let alice = Address::generate(&env);
+    client.reserve(&s.asset, &100, &200, &alice);
+    env.ledger().set_timestamp(150);
+    client.check_out(&s.asset, &alice);
+
+    let res = client
+        .mock_auths(&[MockAuth {
+            address: &s.branch_admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "check_in",
+                args: (s.asset.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_check_in(&s.asset);
+    assert!(res.is_err());
+    assert!(
+        client
+            .get_reservations(&s.asset, &window(150, 151))
+            .iter()
+            .all(|r| r.status == ReservationStatus::CheckedOut)
+    );
+}
+
+#[test]
+fn test_checked_out_asset_is_locked() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_pool_car(&env, &client, &admin);
+    let company_id = client.get_asset(&s.asset).company_id;
+    let depot = create_branch(&env, &client, &company_id, 210, &s.branch_admin);
+    let alice = Address::generate(&env);
+    client.reserve(&s.asset, &100, &200, &alice);
+    env.ledger().set_timestamp(150);
+    client.check_out(&s.asset, &alice);
+
+    let mut assets = Vec::new(&env);
+    assets.push_back(s.asset.clone());
+    let ship = |seed| {
+        client.try_create_shipment(
+            &make_bytes32(&env, seed),
+            &assets,
+            &s.branch,
+            &depot,
+            &Address::generate(&env),
+        )
+    };
+    assert_eq!(ship(100), Err(Ok(Error::AssetCheckedOut.into())));
+    let res = client.try_transfer_asset(&s.asset, &Address::generate(&env));
+    assert_eq!(res, Err(Ok(Error::AssetCheckedOut)));
+
+    client.check_in(&s.asset);
+    client.transfer_asset(&s.asset, &alice);
+    assert!(ship(101).is_ok());
+}
+
+#[test]
+fn test_branch_admin_can_force_check_in() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_pool_car(&env, &client, &admin);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let first = client.reserve(&s.asset, &100, &200, &alice);
+    let second = client.reserve(&s.asset, &200, &300, &bob);
+    env.ledger().set_timestamp(150);
+    client.check_out(&s.asset, &alice);
+
+    // Only the admin of a branch holding the asset may step in
+    let company_id = client.get_asset(&s.asset).company_id;
+    let other = create_branch(&env, &client, &company_id, 210, &Address::generate(&env));
+    let res = client.try_force_check_in(&s.asset, &other);
+    assert_eq!(res, Err(Ok(Error::Unauthorized.into())));
+    let res = client
+        .mock_auths(&[MockAuth {
+            address: &alice,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "force_check_in",
+                args: (s.asset.clone(), s.branch.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_force_check_in(&s.asset, &s.branch);
+    assert!(res.is_err());
+
+    // The overdue holder no longer blocks the next booking
+    env.ledger().set_timestamp(210);
+    client.force_check_in(&s.asset, &s.branch);
+    assert_eq!(
+        client.get_reservation(&first).status,
+        ReservationStatus::Returned
+    );
+    assert_eq!(client.check_out(&s.asset, &bob), second);
+
+    client.check_in(&s.asset);
+    let res = client.try_force_check_in(&s.asset, &s.branch);
+    assert_eq!(res, Err(Ok(ReservationError::AssetNotCheckedOut.into())));
+}
+
+#[test]
+fn test_unused_bookings_expire() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_pool_car(&env, &client, &admin);
+    let alice = Address::generate(&env);
+    let approved = client.reserve(&s.asset, &100, &200, &alice);
+    client.set_reservation_approval(&s.branch, &true);
+    let pending = client.reserve(&s.asset, &200, &300, &alice);
+
+    env.ledger().set_timestamp(199);
+    assert_eq!(
+        client.get_reservation(&approved).status,
+        ReservationStatus::Approved
+    );
+
+    env.ledger().set_timestamp(300);
+    for id in [approved, pending] {
+        assert_eq!(
+            client.get_reservation(&id).status,
+            ReservationStatus::Expired
+        );
+    }
+    assert!(
+        client
+            .get_reservations(&s.asset, &window(0, 400))
+            .iter()
+            .all(|r| r.status == ReservationStatus::Expired)
+    );
+
+    let res = client.try_cancel_reservation(&approved);
+    assert_eq!(res, Err(Ok(ReservationError::ReservationNotActive.into())));
+    let res = client.try_decide_reservation(&pending, &true);
+    assert_eq!(res, Err(Ok(ReservationError::ReservationNotPending.into())));
+
+    // Expired windows no longer block bookings that reach back over them
+    client.set_reservation_approval(&s.branch, &false);
+    let id = client.reserve(&s.asset, &150, &400, &alice);
+    assert_eq!(client.check_out(&s.asset, &alice), id);
+}
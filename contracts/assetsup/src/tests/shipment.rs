@@ -0,0 +1,263 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    Address, BytesN, Env, IntoVal, String, Vec,
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+};
+
+use crate::{
+    AssetUpContractClient,
+    asset::Asset,
+    error::{Error, ShipmentError},
+    shipment::ShipmentStatus,
+    types::{AssetStatus, AssetType},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, create_branch, make_bytes32, setup_company,
+    setup_test_environment,
+};
+
+struct Setup {
+    origin: BytesN<32>,
+    destination: BytesN<32>,
+    origin_admin: Address,
+    destination_admin: Address,
+    assets: Vec<BytesN<32>>,
+}
+
+/// Two branches of one company with two assets linked to the first
+fn setup_branches(env: &Env, client: &AssetUpContractClient, admin: &Address) -> Setup {
+    let company_id = setup_company(env, client, admin);
+    let origin_admin = Address::generate(env);
+    let destination_admin = Address::generate(env);
+    let origin = create_branch(env, client, &company_id, 200, &origin_admin);
+    let destination = create_branch(env, client, &company_id, 210, &destination_admin);
+
+    let mut assets = Vec::new(env);
+    for seed in [1, 3] {
+        let asset = Asset {
+            id: make_bytes32(env, seed),
+            company_id: company_id.clone(),
+            name: String::from_str(env, "Server"),
+            asset_type: AssetType::Physical,
+            category_id: DEFAULT_CATEGORY,
+            branch_id: 1,
+            department_id: 0,
+            status: AssetStatus::Active,
+            purchase_date: 1_700_000_000,
+            purchase_cost: 9_000,
+            current_value: 8_000,
+            currency: DEFAULT_CURRENCY,
+            warranty_expiry: 1_800_000_000,
+            stellar_token_id: make_bytes32(env, seed + 1),
+            owner: Address::generate(env),
+        };
+        client.register_asset(&asset);
+        client.add_asset_to_branch(&origin, &asset.id);
+        assets.push_back(asset.id);
+    }
+
+    Setup {
+        origin,
+        destination,
+        origin_admin,
+        destination_admin,
+        assets,
+    }
+}
+
+#[test]
+fn test_shipment_chain_of_custody() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_branches(&env, &client, &admin);
+    let carrier = Address::generate(&env);
+    let depot = Address::generate(&env);
+    let shipment_id = make_bytes32(&env, 100);
+
+    env.ledger().set_timestamp(1_000);
+    client.create_shipment(&shipment_id, &s.assets, &s.origin, &s.destination, &carrier);
+
+    let asset_id = s.assets.get(0).unwrap();
+    assert_eq!(client.get_asset(&asset_id).status, AssetStatus::InTransit);
+    assert_eq!(client.get_asset_shipment(&asset_id).id, shipment_id);
+
+    env.ledger().set_timestamp(2_000);
+    client.handoff_shipment(&shipment_id, &depot);
+    env.ledger().set_timestamp(3_000);
+    client.confirm_delivery(&shipment_id);
+
+    let shipment = client.get_shipment(&shipment_id);
+    assert_eq!(shipment.status, ShipmentStatus::Delivered);
+    assert_eq!(shipment.delivered_at, 3_000);
+    assert_eq!(shipment.holder, s.destination_admin);
+
+    let chain: std::vec::Vec<(Address, Address, u64)> = shipment
+        .handoffs
+        .iter()
+        .map(|h| (h.from, h.to, h.at))
+        .collect();
+    assert_eq!(
+        chain,
+        [
+            (s.origin_admin, carrier.clone(), 1_000),
+            (carrier, depot.clone(), 2_000),
+            (depot, s.destination_admin, 3_000),
+        ]
+    );
+
+    assert_eq!(client.get_asset(&asset_id).status, AssetStatus::Active);
+    assert_eq!(client.get_branch_assets(&s.origin, &0, &u32::MAX).len(), 0);
+    assert_eq!(
+        client.get_branch_assets(&s.destination, &0, &u32::MAX),
+        s.assets
+    );
+    assert_eq!(client.get_branch_stats(&s.origin).asset_count, 0);
+    assert_eq!(client.get_branch_stats(&s.destination).asset_count, 2);
+    assert_eq!(
+        client.try_get_asset_shipment(&asset_id),
+        Err(Ok(ShipmentError::ShipmentNotFound.into()))
+    );
+
+    let res = client.try_handoff_shipment(&shipment_id, &Address::generate(&env));
+    assert_eq!(res, Err(Ok(ShipmentError::ShipmentNotInTransit.into())));
+}
+
+#[test]
+fn test_only_holder_can_hand_off() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_branches(&env, &client, &admin);
+    let carrier = Address::generate(&env);
+    let shipment_id = make_bytes32(&env, 100);
+    client.create_shipment(&shipment_id, &s.assets, &s.origin, &s.destination, &carrier);
+
+    let thief = Address::generate(&env);
+    let res = client
+        .mock_auths(&[MockAuth {
+            address: &s.origin_admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "handoff_shipment",
+                args: (shipment_id.clone(), thief.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_handoff_shipment(&shipment_id, &thief);
+    assert!(res.is_err());
+    assert_eq!(client.get_shipment(&shipment_id).holder, carrier);
+}
+
+#[test]
+fn test_in_transit_assets_are_locked() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_branches(&env, &client, &admin);
+    let carrier = Address::generate(&env);
+    client.create_shipment(
+        &make_bytes32(&env, 100),
+        &s.assets,
+        &s.origin,
+        &s.destination,
+        &carrier,
+    );
+
+    let res = client.try_create_shipment(
+        &make_bytes32(&env, 101),
+        &s.assets,
+        &s.origin,
+        &s.destination,
+        &carrier,
+    );
+    assert_eq!(res, Err(Ok(Error::AssetNotActive.into())));
+
+    let res = client.try_dispose_asset(&s.assets.get(0).unwrap());
+    assert_eq!(res, Err(Ok(Error::AssetInTransit)));
+
+    let res = client.try_transfer_asset(&s.assets.get(0).unwrap(), &carrier);
+    assert_eq!(res, Err(Ok(Error::AssetInTransit)));
+}
+
+#[test]
+fn test_shipment_requires_assets_at_origin() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_branches(&env, &client, &admin);
+
+    let res = client.try_create_shipment(
+        &make_bytes32(&env, 100),
+        &s.assets,
+        &s.destination,
+        &s.origin,
+        &Address::generate(&env),
+    );
+    assert_eq!(res, Err(Ok(ShipmentError::AssetNotAtOrigin.into())));
+    assert_eq!(
+        client.get_asset(&s.assets.get(0).unwrap()).status,
+        AssetStatus::Active
+    );
+}
+
+#[test]
+fn test_cancel_returns_assets_to_origin() {
+    let (env, client, admin) = setup_test_environment();
+    let s = setup_branches(&env, &client, &admin);
+    let carrier = Address::generate(&env);
+    let shipment_id = make_bytes32(&env, 100);
+
+    env.ledger().set_timestamp(1_000);
+    client.create_shipment(&shipment_id, &s.assets, &s.origin, &s.destination, &carrier);
+
+    // Only the origin branch admin can call a shipment back
+    let res = client
+        .mock_auths(&[MockAuth {
+            address: &s.destination_admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "cancel_shipment",
+                args: (shipment_id.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_cancel_shipment(&shipment_id);
+    assert!(res.is_err());
+
+    env.ledger().set_timestamp(2_000);
+    client.cancel_shipment(&shipment_id);
+
+    let shipment = client.get_shipment(&shipment_id);
+    assert_eq!(shipment.status, ShipmentStatus::Cancelled);
+    assert_eq!(shipment.holder, s.origin_admin);
+    let last = shipment.handoffs.last().unwrap();
+    assert_eq!(
+        (last.from, last.to, last.at),
+        (carrier, s.origin_admin, 2_000)
+    );
+
+    for asset_id in s.assets.iter() {
+        assert_eq!(client.get_asset(&asset_id).status, AssetStatus::Active);
+        assert_eq!(
+            client.try_get_asset_shipment(&asset_id),
+            Err(Ok(ShipmentError::ShipmentNotFound.into()))
+        );
+    }
+    assert_eq!(client.get_branch_assets(&s.origin, &0, &u32::MAX), s.assets);
+    assert_eq!(client.get_branch_stats(&s.destination).asset_count, 0);
+
+    assert_eq!(
+        client.try_confirm_delivery(&shipment_id),
+        Err(Ok(ShipmentError::ShipmentNotInTransit.into()))
+    );
+    assert_eq!(
+        client.try_cancel_shipment(&shipment_id),
+        Err(Ok(ShipmentError::ShipmentNotInTransit.into()))
+    );
+
+    // The returned assets can be shipped again
+    client.create_shipment(
+        &make_bytes32(&env, 101),
+        &s.assets,
+        &s.origin,
+        &s.destination,
+        &Address::generate(&env),
+    );
+}
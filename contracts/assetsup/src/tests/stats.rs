@@ -0,0 +1,150 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{Address, BytesN, Env, String, symbol_short, testutils::Address as _};
+
+use crate::{
+    asset::Asset,
+    currency::Currency,
+    stats::ValueTotal,
+    types::{AssetStatus, AssetType},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, create_branch, make_bytes32, setup_company,
+    setup_test_environment,
+};
+
+fn make_asset(
+    env: &Env,
+    seed: u32,
+    company_id: &BytesN<32>,
+    asset_type: AssetType,
+    cost: i128,
+) -> Asset {
+    Asset {
+        id: make_bytes32(env, seed),
+        company_id: company_id.clone(),
+        name: String::from_str(env, "Laptop"),
+        asset_type,
+        category_id: DEFAULT_CATEGORY,
+        branch_id: 1,
+        department_id: 0,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost: cost,
+        current_value: cost / 2,
+        currency: DEFAULT_CURRENCY,
+        warranty_expiry: 1_800_000_000,
+        stellar_token_id: BytesN::from_array(env, &[0u8; 32]),
+        owner: Address::generate(env),
+    }
+}
+
+#[test]
+fn test_global_stats_track_lifecycle() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+
+    let stats = client.get_global_stats();
+    assert_eq!(stats.asset_count, 0);
+    assert_eq!(stats.totals.len(), 0);
+
+    let laptop = make_asset(&env, 1, &company_id, AssetType::Physical, 1_000);
+    let license = make_asset(&env, 3, &company_id, AssetType::Digital, 400);
+    client.register_asset(&laptop);
+    client.register_asset(&license);
+
+    let stats = client.get_global_stats();
+    assert_eq!(stats.asset_count, 2);
+    assert_eq!(stats.active_count, 2);
+    assert_eq!(stats.physical_count, 1);
+    assert_eq!(stats.digital_count, 1);
+    assert_eq!(stats.tokenized_count, 0);
+    assert_eq!(
+        stats.totals.get(0).unwrap(),
+        ValueTotal {
+            currency: DEFAULT_CURRENCY,
+            purchase_cost: 1_400,
+            current_value: 700,
+        }
+    );
+
+    client.tokenize_asset(&laptop.id, &make_bytes32(&env, 90));
+    assert_eq!(client.get_global_stats().tokenized_count, 1);
+
+    // Disposed assets stay counted but drop out of the value totals
+    client.dispose_asset(&license.id);
+    let stats = client.get_global_stats();
+    assert_eq!(stats.asset_count, 2);
+    assert_eq!(stats.active_count, 1);
+    assert_eq!(stats.disposed_count, 1);
+    assert_eq!(stats.totals.get(0).unwrap().purchase_cost, 1_000);
+    assert_eq!(stats.totals.get(0).unwrap().current_value, 500);
+}
+
+#[test]
+fn test_global_stats_split_totals_by_currency() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+
+    let mut euro_asset = make_asset(&env, 5, &company_id, AssetType::Physical, 3_000);
+    euro_asset.currency = Currency {
+        code: symbol_short!("EUR"),
+        scale: 2,
+    };
+    client.register_asset(&make_asset(
+        &env,
+        7,
+        &company_id,
+        AssetType::Physical,
+        1_000,
+    ));
+    client.register_asset(&euro_asset);
+
+    let totals = client.get_global_stats().totals;
+    assert_eq!(totals.len(), 2);
+    assert_eq!(totals.get(0).unwrap().purchase_cost, 1_000);
+    assert_eq!(totals.get(1).unwrap().currency, euro_asset.currency);
+    assert_eq!(totals.get(1).unwrap().purchase_cost, 3_000);
+}
+
+#[test]
+fn test_branch_stats_follow_linked_assets() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let branch_id = create_branch(&env, &client, &company_id, 100, &Address::generate(&env));
+    let other_branch = create_branch(&env, &client, &company_id, 110, &Address::generate(&env));
+
+    let linked = make_asset(&env, 9, &company_id, AssetType::Physical, 1_000);
+    let unlinked = make_asset(&env, 11, &company_id, AssetType::Physical, 5_000);
+    client.register_asset(&linked);
+    client.register_asset(&unlinked);
+    client.add_asset_to_branch(&branch_id, &linked.id);
+    // Linking twice must not double count
+    client.add_asset_to_branch(&branch_id, &linked.id);
+
+    let stats = client.get_branch_stats(&branch_id);
+    assert_eq!(stats.asset_count, 1);
+    assert_eq!(stats.totals.get(0).unwrap().purchase_cost, 1_000);
+    assert_eq!(client.get_branch_stats(&other_branch).asset_count, 0);
+
+    // Changes to a linked asset reach its branch
+    client.tokenize_asset(&linked.id, &make_bytes32(&env, 90));
+    client.dispose_asset(&linked.id);
+    let stats = client.get_branch_stats(&branch_id);
+    assert_eq!(stats.tokenized_count, 1);
+    assert_eq!(stats.disposed_count, 1);
+    assert_eq!(stats.totals.get(0).unwrap().purchase_cost, 0);
+}
+
+#[test]
+fn test_get_branch_stats_unknown_branch() {
+    let (env, client, _admin) = setup_test_environment();
+    assert!(
+        client
+            .try_get_branch_stats(&make_bytes32(&env, 90))
+            .is_err()
+    );
+}
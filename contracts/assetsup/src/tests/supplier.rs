@@ -0,0 +1,133 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{Address, BytesN, Env, String, testutils::Address as _};
+
+use crate::{
+    asset::Asset,
+    error::SupplierError,
+    types::{AssetStatus, AssetType},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, make_bytes32, setup_company, setup_test_environment,
+};
+
+fn make_asset(env: &Env, company_id: &BytesN<32>, seed: u32) -> Asset {
+    Asset {
+        id: make_bytes32(env, seed),
+        company_id: company_id.clone(),
+        name: String::from_str(env, "Router"),
+        asset_type: AssetType::Physical,
+        category_id: DEFAULT_CATEGORY,
+        branch_id: 1,
+        department_id: 0,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost: 400,
+        current_value: 350,
+        currency: DEFAULT_CURRENCY,
+        warranty_expiry: 1_800_000_000,
+        stellar_token_id: make_bytes32(env, seed + 1),
+        owner: Address::generate(env),
+    }
+}
+
+#[test]
+fn test_register_and_verify_supplier() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let supplier = Address::generate(&env);
+    let tax_id_hash = make_bytes32(&env, 7);
+
+    client.register_supplier(
+        &company_id,
+        &supplier,
+        &String::from_str(&env, "Cisco"),
+        &tax_id_hash,
+    );
+
+    let s = client.get_supplier(&company_id, &supplier);
+    assert_eq!(s.tax_id_hash, tax_id_hash);
+    assert!(!s.verified);
+    assert_eq!(s.rating_count, 0);
+    assert_eq!(
+        client.get_company_suppliers(&company_id).get(0).unwrap(),
+        supplier
+    );
+
+    client.set_supplier_verified(&company_id, &supplier, &true);
+    assert!(client.get_supplier(&company_id, &supplier).verified);
+
+    let res = client.try_register_supplier(
+        &company_id,
+        &supplier,
+        &String::from_str(&env, "Cisco"),
+        &tax_id_hash,
+    );
+    assert_eq!(res, Err(Ok(SupplierError::SupplierAlreadyExists.into())));
+}
+
+#[test]
+fn test_suppliers_are_per_company() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let other_company = make_bytes32(&env, 250);
+    client.create_company(&other_company, &String::from_str(&env, "Globex"), &admin);
+
+    let supplier = Address::generate(&env);
+    client.register_supplier(
+        &company_id,
+        &supplier,
+        &String::from_str(&env, "Cisco"),
+        &make_bytes32(&env, 7),
+    );
+
+    let res = client.try_get_supplier(&other_company, &supplier);
+    assert_eq!(res, Err(Ok(SupplierError::SupplierNotFound)));
+}
+
+#[test]
+fn test_set_asset_supplier() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    for supplier in [&first, &second] {
+        client.register_supplier(
+            &company_id,
+            supplier,
+            &String::from_str(&env, "Vendor"),
+            &make_bytes32(&env, 7),
+        );
+    }
+
+    let asset = make_asset(&env, &company_id, 1);
+    client.register_asset(&asset);
+    assert_eq!(
+        client.try_get_asset_supplier(&asset.id),
+        Err(Ok(SupplierError::AssetSupplierNotFound.into()))
+    );
+
+    client.set_asset_supplier(&asset.id, &first);
+    client.set_asset_supplier(&asset.id, &second);
+
+    assert_eq!(client.get_asset_supplier(&asset.id), second);
+    assert_eq!(
+        client
+            .get_supplier_assets(&company_id, &first, &0, &u32::MAX)
+            .len(),
+        0
+    );
+    assert_eq!(
+        client
+            .get_supplier_assets(&company_id, &second, &0, &u32::MAX)
+            .get(0)
+            .unwrap(),
+        asset.id
+    );
+
+    let res = client.try_set_asset_supplier(&asset.id, &Address::generate(&env));
+    assert_eq!(res, Err(Ok(SupplierError::SupplierNotFound.into())));
+}
@@ -0,0 +1,113 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{Address, BytesN, Env, String, testutils::Address as _};
+
+use crate::{
+    AssetUpContractClient,
+    asset::Asset,
+    error::Error,
+    types::{AssetStatus, AssetType},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, make_bytes32, setup_company, setup_test_environment,
+};
+
+fn register_asset(
+    env: &Env,
+    client: &AssetUpContractClient,
+    company_id: &BytesN<32>,
+    seed: u32,
+) -> Asset {
+    let asset = Asset {
+        id: make_bytes32(env, seed),
+        company_id: company_id.clone(),
+        name: String::from_str(env, "Pallet Jack"),
+        asset_type: AssetType::Physical,
+        category_id: DEFAULT_CATEGORY,
+        branch_id: 1,
+        department_id: 0,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost: 1_200,
+        current_value: 1_000,
+        currency: DEFAULT_CURRENCY,
+        warranty_expiry: 1_800_000_000,
+        stellar_token_id: make_bytes32(env, seed + 1),
+        owner: Address::generate(env),
+    };
+    client.register_asset(&asset);
+    asset
+}
+
+#[test]
+fn test_bind_tag_and_lookup() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let asset = register_asset(&env, &client, &company_id, 1);
+    let tag = make_bytes32(&env, 100);
+
+    client.bind_tag(&asset.id, &tag);
+
+    assert_eq!(client.get_asset_by_tag(&tag), asset);
+    assert_eq!(client.get_asset_tag(&asset.id), tag);
+}
+
+#[test]
+fn test_bind_tag_already_bound_to_other_asset() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let first = register_asset(&env, &client, &company_id, 1);
+    let second = register_asset(&env, &client, &company_id, 3);
+    let tag = make_bytes32(&env, 100);
+
+    client.bind_tag(&first.id, &tag);
+    let res = client.try_bind_tag(&second.id, &tag);
+    assert_eq!(res, Err(Ok(Error::TagAlreadyBound)));
+}
+
+#[test]
+fn test_bind_tag_asset_already_tagged() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let asset = register_asset(&env, &client, &company_id, 1);
+
+    client.bind_tag(&asset.id, &make_bytes32(&env, 100));
+    let res = client.try_bind_tag(&asset.id, &make_bytes32(&env, 101));
+    assert_eq!(res, Err(Ok(Error::AssetAlreadyTagged)));
+}
+
+#[test]
+fn test_retag_retires_old_label() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let asset = register_asset(&env, &client, &company_id, 1);
+    let damaged = make_bytes32(&env, 100);
+    let replacement = make_bytes32(&env, 101);
+
+    client.bind_tag(&asset.id, &damaged);
+    client.retag_asset(&asset.id, &replacement);
+
+    assert_eq!(client.get_asset_by_tag(&replacement).id, asset.id);
+    assert_eq!(client.get_asset_tag(&asset.id), replacement);
+    assert_eq!(
+        client.try_get_asset_by_tag(&damaged),
+        Err(Ok(Error::TagRetired))
+    );
+
+    // A retired label can never be bound again
+    let other = register_asset(&env, &client, &company_id, 3);
+    assert_eq!(
+        client.try_bind_tag(&other.id, &damaged),
+        Err(Ok(Error::TagAlreadyBound))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #38)")]
+fn test_get_asset_by_unknown_tag() {
+    let (env, client, _admin) = setup_test_environment();
+    client.get_asset_by_tag(&make_bytes32(&env, 100));
+}
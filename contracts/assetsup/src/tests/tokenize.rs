@@ -2,28 +2,22 @@
 
 extern crate std;
 
-use soroban_sdk::{Address, BytesN, Env, String, testutils::Address as _};
+use soroban_sdk::{Address, String, testutils::Address as _};
 
 use crate::{
     asset::Asset,
     types::{AssetStatus, AssetType},
 };
 
-use super::initialize::setup_test_environment;
-
-fn make_bytes32(env: &Env, seed: u32) -> BytesN<32> {
-    let mut arr = [0u8; 32];
-    for (i, item) in arr.iter_mut().enumerate() {
-        *item = ((seed as usize + i) % 256) as u8;
-    }
-    BytesN::from_array(env, &arr)
-}
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, make_bytes32, setup_company, setup_test_environment,
+};
 
 #[test]
 fn test_tokenize_asset_success() {
     let (env, client, admin) = setup_test_environment();
     // initialize admin
-    client.initialize(&admin);
+    let company_id = setup_company(&env, &client, &admin);
 
     // prepare an asset and register
     let owner = Address::generate(&env);
@@ -32,15 +26,17 @@ fn test_tokenize_asset_success() {
 
     let asset = Asset {
         id: id.clone(),
+        company_id: company_id.clone(),
         name: String::from_str(&env, "Server X"),
         asset_type: AssetType::Digital,
-        category: String::from_str(&env, "Compute"),
+        category_id: DEFAULT_CATEGORY,
         branch_id: 99,
-        department_id: 7,
+        department_id: 0,
         status: AssetStatus::Active,
         purchase_date: 1_725_000_100,
         purchase_cost: 1_000_000,
         current_value: 900_000,
+        currency: DEFAULT_CURRENCY,
         warranty_expiry: 1_826_000_000,
         stellar_token_id: initial_token,
         owner: owner.clone(),
@@ -61,33 +57,14 @@ fn test_tokenize_asset_success() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #2)")]
-fn test_tokenize_asset_without_admin_initialized() {
-    let (env, client, _admin) = setup_test_environment();
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_tokenize_asset_not_found() {
+    let (env, client, admin) = setup_test_environment();
+    setup_company(&env, &client, &admin);
 
-    // prepare an asset and register
-    let owner = Address::generate(&env);
     let id = make_bytes32(&env, 21);
     let token = make_bytes32(&env, 22);
 
-    let asset = Asset {
-        id: id.clone(),
-        name: String::from_str(&env, "Router Y"),
-        asset_type: AssetType::Physical,
-        category: String::from_str(&env, "Network"),
-        branch_id: 1,
-        department_id: 2,
-        status: AssetStatus::Active,
-        purchase_date: 1_700_000_001,
-        purchase_cost: 50_000,
-        current_value: 45_000,
-        warranty_expiry: 1_760_000_000,
-        stellar_token_id: make_bytes32(&env, 23),
-        owner,
-    };
-
-    client.register_asset(&asset);
-
-    // calling tokenize without initialize should panic with AdminNotFound (Error #2)
+    // tokenizing an unregistered asset should panic with AssetNotFound (Error #4)
     client.tokenize_asset(&id, &token);
 }
@@ -36,6 +36,19 @@ fn test_asset_status_variants() {
     assert_ne!(in_maintenance, disposed);
 }
 
+#[test]
+fn test_depreciation_method_variants() {
+    // Test that all DepreciationMethod variants can be created
+    let none = DepreciationMethod::None;
+    let straight_line = DepreciationMethod::StraightLine;
+    let declining = DepreciationMethod::DecliningBalance;
+
+    // Test that they are different
+    assert_ne!(none, straight_line);
+    assert_ne!(none, declining);
+    assert_ne!(straight_line, declining);
+}
+
 #[test]
 fn test_action_type_variants() {
     // Test that all ActionType variants can be created
@@ -0,0 +1,157 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{Address, BytesN, Env, String, testutils::Address as _};
+
+use crate::{
+    AssetUpContractClient,
+    asset::{Asset, RegistrationStatus},
+    error::ValidationError,
+    types::{AssetStatus, AssetType},
+    validation::{self, MAX_NAME_LEN},
+};
+
+use super::initialize::make_bytes32;
+
+use super::{
+    batch::register_in_batches,
+    initialize::{DEFAULT_CATEGORY, DEFAULT_CURRENCY, setup_company, setup_test_environment},
+};
+
+fn make_asset(env: &Env, seed: u32, company_id: &BytesN<32>) -> Asset {
+    Asset {
+        id: make_bytes32(env, seed),
+        company_id: company_id.clone(),
+        name: String::from_str(env, "Laptop"),
+        asset_type: AssetType::Physical,
+        category_id: DEFAULT_CATEGORY,
+        branch_id: 1,
+        department_id: 0,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost: 1_000,
+        current_value: 800,
+        currency: DEFAULT_CURRENCY,
+        warranty_expiry: 1_800_000_000,
+        stellar_token_id: BytesN::from_array(env, &[0u8; 32]),
+        owner: Address::generate(env),
+    }
+}
+
+fn long_name(env: &Env) -> String {
+    let bytes = [b'x'; MAX_NAME_LEN as usize + 1];
+    String::from_bytes(env, &bytes)
+}
+
+fn assert_rejected(client: &AssetUpContractClient, asset: &Asset, expected: ValidationError) {
+    assert_eq!(validation::check_asset(&client.env, asset), Err(expected));
+    assert_eq!(client.try_register_asset(asset), Err(Ok(expected.into())));
+}
+
+#[test]
+fn test_register_asset_rejects_invalid_fields() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+
+    let mut a = make_asset(&env, 1, &company_id);
+    a.name = String::from_str(&env, "");
+    assert_rejected(&client, &a, ValidationError::EmptyName);
+
+    let mut a = make_asset(&env, 3, &company_id);
+    a.name = long_name(&env);
+    assert_rejected(&client, &a, ValidationError::NameTooLong);
+
+    let mut a = make_asset(&env, 5, &company_id);
+    a.purchase_cost = -1;
+    assert_rejected(&client, &a, ValidationError::NegativeCost);
+
+    let mut a = make_asset(&env, 7, &company_id);
+    a.current_value = a.purchase_cost + 1;
+    assert_rejected(&client, &a, ValidationError::InvalidCurrentValue);
+
+    let mut a = make_asset(&env, 9, &company_id);
+    a.warranty_expiry = a.purchase_date - 1;
+    assert_rejected(&client, &a, ValidationError::WarrantyBeforePurchase);
+
+    let mut a = make_asset(&env, 11, &company_id);
+    a.id = BytesN::from_array(&env, &[0u8; 32]);
+    assert_rejected(&client, &a, ValidationError::ZeroId);
+
+    let mut a = make_asset(&env, 12, &company_id);
+    a.currency.scale = 19;
+    assert_rejected(&client, &a, ValidationError::InvalidCurrency);
+
+    // No warranty and a name of exactly the maximum length are fine
+    let mut a = make_asset(&env, 13, &company_id);
+    a.warranty_expiry = 0;
+    a.name = String::from_bytes(&env, &[b'x'; MAX_NAME_LEN as usize]);
+    client.register_asset(&a);
+}
+
+#[test]
+fn test_register_assets_reports_invalid_fields() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+
+    let mut negative = make_asset(&env, 15, &company_id);
+    negative.purchase_cost = -5;
+    let mut overvalued = make_asset(&env, 17, &company_id);
+    overvalued.current_value = 2_000;
+    let mut early_warranty = make_asset(&env, 19, &company_id);
+    early_warranty.warranty_expiry = 1;
+    let mut zeroed = make_asset(&env, 21, &company_id);
+    zeroed.id = BytesN::from_array(&env, &[0u8; 32]);
+    let mut long = make_asset(&env, 23, &company_id);
+    long.name = long_name(&env);
+
+    let statuses = register_in_batches(
+        &env,
+        &client,
+        &[negative, overvalued, early_warranty, zeroed, long],
+    );
+    assert_eq!(
+        statuses,
+        [
+            RegistrationStatus::NegativeCost,
+            RegistrationStatus::InvalidCurrentValue,
+            RegistrationStatus::WarrantyBeforePurchase,
+            RegistrationStatus::ZeroId,
+            RegistrationStatus::InvalidName,
+        ]
+    );
+}
+
+#[test]
+fn test_create_branch_zero_id() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let res = client.try_create_branch(
+        &company_id,
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &String::from_str(&env, "Main"),
+        &String::from_str(&env, "Lagos"),
+        &admin,
+    );
+    assert_eq!(res, Err(Ok(ValidationError::ZeroId.into())));
+}
+
+#[test]
+fn test_create_cost_center_name_too_long() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let res = client.try_create_cost_center(&company_id, &7, &long_name(&env));
+    assert_eq!(res, Err(Ok(ValidationError::NameTooLong.into())));
+}
+
+#[test]
+fn test_create_company_rejects_empty_name() {
+    let (env, client, admin) = setup_test_environment();
+    client.initialize(&admin);
+    let res = client.try_create_company(
+        &BytesN::from_array(&env, &[0xC1; 32]),
+        &String::from_str(&env, ""),
+        &admin,
+    );
+    assert_eq!(res, Err(Ok(ValidationError::EmptyName.into())));
+}
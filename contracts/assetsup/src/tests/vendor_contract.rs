@@ -0,0 +1,265 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    Address, BytesN, Env, IntoVal, String, Vec,
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+};
+
+use crate::{
+    AssetUpContractClient,
+    asset::Asset,
+    error::{Error, VendorContractError},
+    types::{AssetStatus, AssetType},
+    vendor_contract::{Renewal, VendorContractStatus},
+};
+
+use super::initialize::{
+    DEFAULT_CATEGORY, DEFAULT_CURRENCY, make_bytes32, setup_company, setup_test_environment,
+};
+
+const DAY: u64 = 86_400;
+
+fn register_asset(env: &Env, client: &AssetUpContractClient, company_id: &BytesN<32>) -> Asset {
+    let asset = Asset {
+        id: make_bytes32(env, 1),
+        company_id: company_id.clone(),
+        name: String::from_str(env, "Chiller"),
+        asset_type: AssetType::Physical,
+        category_id: DEFAULT_CATEGORY,
+        branch_id: 1,
+        department_id: 0,
+        status: AssetStatus::Active,
+        purchase_date: 1_700_000_000,
+        purchase_cost: 40_000,
+        current_value: 35_000,
+        currency: DEFAULT_CURRENCY,
+        warranty_expiry: 1_800_000_000,
+        stellar_token_id: make_bytes32(env, 2),
+        owner: Address::generate(env),
+    };
+    client.register_asset(&asset);
+    asset
+}
+
+fn create_contract(
+    env: &Env,
+    client: &AssetUpContractClient,
+    company_id: &BytesN<32>,
+    seed: u32,
+    vendor: &Address,
+    end_date: u64,
+    auto_renew: bool,
+) -> BytesN<32> {
+    let contract_id = make_bytes32(env, seed);
+    client.create_vendor_contract(
+        &contract_id,
+        company_id,
+        vendor,
+        &make_bytes32(env, seed + 1),
+        &Vec::new(env),
+        &(4 * 3_600),
+        &0,
+        &end_date,
+        &auto_renew,
+    );
+    contract_id
+}
+
+#[test]
+fn test_create_vendor_contract() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let asset = register_asset(&env, &client, &company_id);
+    let vendor = Address::generate(&env);
+    let contract_id = make_bytes32(&env, 10);
+    let mut covered = Vec::new(&env);
+    covered.push_back(asset.id.clone());
+
+    client.create_vendor_contract(
+        &contract_id,
+        &company_id,
+        &vendor,
+        &make_bytes32(&env, 11),
+        &covered,
+        &3_600,
+        &DAY,
+        &(366 * DAY),
+        &false,
+    );
+
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(a, _)| a).collect();
+    assert_eq!(signers, [admin, vendor.clone()]);
+
+    let c = client.get_vendor_contract(&contract_id);
+    assert_eq!(c.vendor, vendor);
+    assert_eq!(c.covered_assets, covered);
+    assert_eq!(c.sla_response_secs, 3_600);
+    assert_eq!(c.status, VendorContractStatus::Active);
+    assert_eq!(
+        client
+            .get_company_vendor_contracts(&company_id)
+            .get(0)
+            .unwrap(),
+        contract_id
+    );
+}
+
+#[test]
+fn test_create_vendor_contract_invalid() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let vendor = Address::generate(&env);
+
+    let res = client.try_create_vendor_contract(
+        &make_bytes32(&env, 10),
+        &company_id,
+        &vendor,
+        &make_bytes32(&env, 11),
+        &Vec::new(&env),
+        &3_600,
+        &DAY,
+        &DAY,
+        &false,
+    );
+    assert_eq!(res, Err(Ok(VendorContractError::InvalidTerm.into())));
+
+    let mut covered = Vec::new(&env);
+    covered.push_back(make_bytes32(&env, 99));
+    let res = client.try_create_vendor_contract(
+        &make_bytes32(&env, 10),
+        &company_id,
+        &vendor,
+        &make_bytes32(&env, 11),
+        &covered,
+        &3_600,
+        &0,
+        &DAY,
+        &false,
+    );
+    assert_eq!(res, Err(Ok(Error::AssetNotFound.into())));
+}
+
+#[test]
+fn test_expiring_contracts_within_window() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let vendor = Address::generate(&env);
+
+    let soon = create_contract(&env, &client, &company_id, 10, &vendor, 30 * DAY, false);
+    create_contract(&env, &client, &company_id, 20, &vendor, 365 * DAY, false);
+    let rolling = create_contract(&env, &client, &company_id, 30, &vendor, 100 * DAY, true);
+    let ended = create_contract(&env, &client, &company_id, 40, &vendor, 10 * DAY, false);
+
+    env.ledger().set_timestamp(195 * DAY);
+    let expiring = client.get_expiring_vendor_contracts(&company_id, &(10 * DAY));
+    assert_eq!(expiring.len(), 1);
+    assert_eq!(expiring.get(0).unwrap().id, rolling);
+    assert_eq!(expiring.get(0).unwrap().end_at(195 * DAY), 200 * DAY);
+
+    env.ledger().set_timestamp(25 * DAY);
+    let ids: std::vec::Vec<BytesN<32>> = client
+        .get_expiring_vendor_contracts(&company_id, &(10 * DAY))
+        .iter()
+        .map(|c| c.id)
+        .collect();
+    assert_eq!(ids, [soon]);
+    assert!(!ids.contains(&ended));
+}
+
+#[test]
+fn test_renew_and_terminate() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let vendor = Address::generate(&env);
+    let contract_id = create_contract(&env, &client, &company_id, 10, &vendor, 30 * DAY, false);
+
+    let res = client.try_renew_vendor_contract(&contract_id, &(30 * DAY), &make_bytes32(&env, 50));
+    assert_eq!(res, Err(Ok(VendorContractError::InvalidTerm.into())));
+
+    env.ledger().set_timestamp(20 * DAY);
+    let renewed_doc = make_bytes32(&env, 50);
+    client.renew_vendor_contract(&contract_id, &(60 * DAY), &renewed_doc);
+    let c = client.get_vendor_contract(&contract_id);
+    // The signed term stays as it was; the renewal is recorded after it
+    assert_eq!((c.start_date, c.end_date), (0, 30 * DAY));
+    assert_eq!(c.document_hash, make_bytes32(&env, 11));
+    assert_eq!(
+        c.renewals.get(0).unwrap(),
+        Renewal {
+            document_hash: renewed_doc,
+            start_date: 30 * DAY,
+            end_date: 60 * DAY,
+            renewed_at: 20 * DAY,
+        }
+    );
+    assert_eq!(c.renewals.len(), 1);
+    assert_eq!(c.end_at(20 * DAY), 60 * DAY);
+
+    client.terminate_vendor_contract(&contract_id);
+    assert_eq!(
+        client.get_vendor_contract(&contract_id).status,
+        VendorContractStatus::Terminated
+    );
+    assert_eq!(
+        client
+            .get_expiring_vendor_contracts(&company_id, &(100 * DAY))
+            .len(),
+        0
+    );
+
+    let res = client.try_terminate_vendor_contract(&contract_id);
+    assert_eq!(res, Err(Ok(VendorContractError::ContractNotActive.into())));
+}
+
+#[test]
+fn test_renewed_contract_rolls_over_by_renewed_term() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let vendor = Address::generate(&env);
+    let contract_id = create_contract(&env, &client, &company_id, 10, &vendor, 100 * DAY, true);
+
+    // Rolled once, into [100, 200); renew for a 60-day term
+    env.ledger().set_timestamp(150 * DAY);
+    let res = client.try_renew_vendor_contract(&contract_id, &(200 * DAY), &make_bytes32(&env, 50));
+    assert_eq!(res, Err(Ok(VendorContractError::InvalidTerm.into())));
+    client.renew_vendor_contract(&contract_id, &(260 * DAY), &make_bytes32(&env, 50));
+    let c = client.get_vendor_contract(&contract_id);
+    assert_eq!((c.start_date, c.end_date), (0, 100 * DAY));
+    assert_eq!(c.last_term(), (200 * DAY, 260 * DAY));
+
+    // Then rolls over in 60-day terms, not the 260 days since creation
+    assert_eq!(c.end_at(259 * DAY), 260 * DAY);
+    assert_eq!(c.end_at(270 * DAY), 320 * DAY);
+    assert_eq!(c.end_at(390 * DAY), 440 * DAY);
+    env.ledger().set_timestamp(315 * DAY);
+    let expiring = client.get_expiring_vendor_contracts(&company_id, &(10 * DAY));
+    assert_eq!(expiring.len(), 1);
+    assert_eq!(expiring.get(0).unwrap().end_at(315 * DAY), 320 * DAY);
+}
+
+#[test]
+fn test_termination_needs_vendor_signature() {
+    let (env, client, admin) = setup_test_environment();
+    let company_id = setup_company(&env, &client, &admin);
+    let vendor = Address::generate(&env);
+    let contract_id = create_contract(&env, &client, &company_id, 10, &vendor, 30 * DAY, false);
+
+    let res = client
+        .mock_auths(&[MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "terminate_vendor_contract",
+                args: (contract_id.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_terminate_vendor_contract(&contract_id);
+    assert!(res.is_err());
+    assert_eq!(
+        client.get_vendor_contract(&contract_id).status,
+        VendorContractStatus::Active
+    );
+}
@@ -17,9 +17,20 @@ pub enum AssetType {
 pub enum AssetStatus {
     Active,
     InMaintenance,
+    InTransit,
     Disposed,
 }
 
+/// Represents how an asset's value is written down over its useful life
+/// Used as the default for assets registered under a category
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DepreciationMethod {
+    None,
+    StraightLine,
+    DecliningBalance,
+}
+
 /// Represents different types of actions that can be performed on assets
 /// Used for audit trails and tracking asset lifecycle events
 #[contracttype]
@@ -0,0 +1,43 @@
+use soroban_sdk::{BytesN, Env, String};
+
+use crate::{asset::Asset, currency, error::ValidationError};
+
+/// Longest accepted name, in bytes.
+pub const MAX_NAME_LEN: u32 = 64;
+
+pub(crate) fn check_name(name: &String) -> Result<(), ValidationError> {
+    if name.is_empty() {
+        return Err(ValidationError::EmptyName);
+    }
+    if name.len() > MAX_NAME_LEN {
+        return Err(ValidationError::NameTooLong);
+    }
+    Ok(())
+}
+
+pub(crate) fn check_id(env: &Env, id: &BytesN<32>) -> Result<(), ValidationError> {
+    if *id == BytesN::from_array(env, &[0u8; 32]) {
+        return Err(ValidationError::ZeroId);
+    }
+    Ok(())
+}
+
+/// Check the caller-supplied fields of a new asset. A `warranty_expiry` of
+/// zero means the asset has no warranty.
+pub(crate) fn check_asset(env: &Env, a: &Asset) -> Result<(), ValidationError> {
+    check_id(env, &a.id)?;
+    check_name(&a.name)?;
+    if a.purchase_cost < 0 {
+        return Err(ValidationError::NegativeCost);
+    }
+    if a.current_value < 0 || a.current_value > a.purchase_cost {
+        return Err(ValidationError::InvalidCurrentValue);
+    }
+    if a.warranty_expiry != 0 && a.warranty_expiry < a.purchase_date {
+        return Err(ValidationError::WarrantyBeforePurchase);
+    }
+    if !currency::is_valid(&a.currency) {
+        return Err(ValidationError::InvalidCurrency);
+    }
+    Ok(())
+}
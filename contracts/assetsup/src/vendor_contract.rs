@@ -0,0 +1,73 @@
+use soroban_sdk::{Address, BytesN, Vec, contracttype};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    VendorContract(BytesN<32>),
+    // company_id -> Vec<contract_id>
+    CompanyContracts(BytesN<32>),
+}
+
+/// Represents whether a vendor contract is still binding
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VendorContractStatus {
+    Active,
+    Terminated,
+}
+
+/// A term added to a vendor contract under a newly signed document.
+/// It runs from `start_date`, where the term before it ended, to `end_date`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Renewal {
+    pub document_hash: BytesN<32>,
+    pub start_date: u64,
+    pub end_date: u64,
+    pub renewed_at: u64,
+}
+
+/// Service agreement between a company and a vendor.
+/// `document_hash`, `start_date` and `end_date` record the originally signed
+/// term and never change; each renewal is appended to `renewals` in order.
+/// `sla_response_secs` is the agreed maximum response time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VendorContract {
+    pub id: BytesN<32>,
+    pub company_id: BytesN<32>,
+    pub vendor: Address,
+    pub document_hash: BytesN<32>,
+    pub covered_assets: Vec<BytesN<32>>,
+    pub sla_response_secs: u64,
+    pub start_date: u64,
+    pub end_date: u64,
+    pub auto_renew: bool,
+    pub renewals: Vec<Renewal>,
+    pub status: VendorContractStatus,
+}
+
+impl VendorContract {
+    /// Start and end of the last signed term: the latest renewal, or the
+    /// original term if the contract was never renewed.
+    pub fn last_term(&self) -> (u64, u64) {
+        match self.renewals.last() {
+            Some(r) => (r.start_date, r.end_date),
+            None => (self.start_date, self.end_date),
+        }
+    }
+
+    /// End of the term running at `now`. Auto-renewing contracts roll over
+    /// into back-to-back terms of the last signed term's length.
+    pub fn end_at(&self, now: u64) -> u64 {
+        let (start, end) = self.last_term();
+        if !self.auto_renew || now < end {
+            return end;
+        }
+        let term = end - start;
+        let elapsed_terms = (now - end) / term + 1;
+        end + elapsed_terms * term
+    }
+}
+
+// Note: Contract methods implemented in lib.rs